@@ -1,5 +1,7 @@
-use clap::{builder::styling, Arg, ArgAction, ArgMatches, Command};
+use crate::operations::build::utils::parse_min_contrast;
+use clap::{builder::styling, value_parser, Arg, ArgAction, ArgGroup, ArgMatches, Command};
 
+#[allow(clippy::too_many_lines)]
 fn build_cli() -> Command {
     Command::new(env!("CARGO_PKG_NAME"))
         .version(env!("CARGO_PKG_VERSION"))
@@ -32,6 +34,29 @@ fn build_cli() -> Command {
                 .short('s')
                 .value_name("DIRECTORY"),
         )
+        .subcommand(
+            Command::new("audit")
+                .about("Audits every scheme file in a schemes directory and prints a report")
+                .arg(
+                    Arg::new("schemes-path")
+                        .help("Directory containing the scheme files, defaults to the schemes directory"),
+                )
+                .arg(
+                    Arg::new("cvd")
+                        .long("cvd")
+                        .help("Report color pairs that become indistinguishable with protanopia, deuteranopia or tritanopia")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("min-delta-e")
+                        .long("min-delta-e")
+                        .help("CIEDE2000 distance below which two colors are indistinguishable, defaults to 10")
+                        .value_name("DELTA_E")
+                        .value_parser(value_parser!(f64))
+                        .action(ArgAction::Set),
+                )
+                .group(ArgGroup::new("audits").args(["cvd"]).required(true).multiple(true)),
+        )
         .subcommand(
             Command::new("build")
                 .about("Builds the target theme template")
@@ -52,6 +77,184 @@ fn build_cli() -> Command {
                         .long("sync")
                         .help("Sync with latest schemes before build")
                         .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("min-contrast")
+                        .long("min-contrast")
+                        .help("Minimum WCAG contrast ratio of each scheme's text/background pairs, between 1 and 21, overrides the template config")
+                        .value_name("RATIO")
+                        .value_parser(parse_min_contrast)
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("min-contrast-warn")
+                        .long("min-contrast-warn")
+                        .help("Warn instead of failing when a scheme is below --min-contrast")
+                        .requires("min-contrast")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("convert")
+                .about("Converts a scheme file into another scheme system")
+                .arg(
+                    Arg::new("scheme-file")
+                        .help("Path to the scheme file you want to convert")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("to")
+                        .long("to")
+                        .short('t')
+                        .help("Scheme system to convert the scheme into")
+                        .value_name("SYSTEM")
+                        .value_parser(["base16", "base24", "tinted8"])
+                        .required(true)
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("out")
+                        .long("out")
+                        .short('o')
+                        .help("Path to write the converted scheme to, defaults to stdout")
+                        .value_name("FILE")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("quiet")
+                        .long("quiet")
+                        .short('q')
+                        .help("Silence stdout")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("derive-variant")
+                .about("Derives the opposite light/dark variant of a scheme file")
+                .arg(
+                    Arg::new("scheme-file")
+                        .help("Path to the scheme file you want to derive the variant from")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("out")
+                        .long("out")
+                        .short('o')
+                        .help("Path to write the derived scheme to, defaults to <slug>.yaml next to the scheme file")
+                        .value_name("FILE")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("quiet")
+                        .long("quiet")
+                        .short('q')
+                        .help("Silence stdout")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("fmt")
+                .about("Rewrites scheme files in their canonical form")
+                .arg(
+                    Arg::new("paths")
+                        .help("Scheme files or directories containing scheme files")
+                        .action(ArgAction::Append)
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("check")
+                        .long("check")
+                        .help("Print a diff and exit with an error instead of rewriting unformatted files")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("quiet")
+                        .long("quiet")
+                        .short('q')
+                        .help("Silence stdout")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("generate")
+                .about("Generates a scheme from the colors of an image or from seed colors")
+                .arg(
+                    Arg::new("from-image")
+                        .long("from-image")
+                        .help("Path to the PNG or JPEG image to generate the scheme from")
+                        .value_name("IMAGE")
+                        .required_unless_present("seed")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("seed")
+                        .long("seed")
+                        .help("Seed color to generate the scheme from, repeat for more seeds")
+                        .value_name("COLOR")
+                        .conflicts_with("from-image")
+                        .action(ArgAction::Append),
+                )
+                .arg(
+                    Arg::new("system")
+                        .long("system")
+                        .help("Scheme system of the generated scheme")
+                        .value_name("SYSTEM")
+                        .value_parser(["base16", "base24", "tinted8"])
+                        .default_value("base16")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("variant")
+                        .long("variant")
+                        .help("Scheme variant, inferred from the image or first seed when omitted")
+                        .value_name("VARIANT")
+                        .value_parser(["dark", "light"])
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("name")
+                        .long("name")
+                        .help("Scheme name, defaults to the image file name or \"Generated\"")
+                        .value_name("NAME")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("author")
+                        .long("author")
+                        .help("Scheme author")
+                        .value_name("AUTHOR")
+                        .default_value(env!("CARGO_PKG_NAME"))
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("out")
+                        .long("out")
+                        .short('o')
+                        .help("Path to write the generated scheme to, defaults to stdout")
+                        .value_name("FILE")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("quiet")
+                        .long("quiet")
+                        .short('q')
+                        .help("Silence stdout")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("lint")
+                .about("Checks every scheme file in a schemes directory and reports all problems")
+                .arg(
+                    Arg::new("schemes-path")
+                        .help("Directory containing the scheme files, defaults to the schemes directory"),
+                )
+                .arg(
+                    Arg::new("quiet")
+                        .long("quiet")
+                        .short('q')
+                        .help("Silence stdout when no problems are found")
+                        .action(ArgAction::SetTrue),
                 ),
         )
         .subcommand(
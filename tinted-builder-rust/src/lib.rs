@@ -13,4 +13,4 @@ pub mod utils {
 pub use crate::operations::build as operation_build;
 
 // For tests
-pub use operations::build::build;
+pub use operations::build::{build, build_with_options, BuildOptions};
@@ -1,16 +1,25 @@
 mod cli;
 mod operations {
+    pub mod audit;
     pub mod build;
+    pub mod convert;
+    pub mod derive_variant;
+    pub mod fmt;
+    pub mod generate;
+    pub mod lint;
     pub mod sync;
 }
 mod helpers;
 
 use crate::cli::get_matches;
 use anyhow::{anyhow, Result};
-use std::{borrow, path::PathBuf};
+use clap::ArgMatches;
+use std::{borrow, path::PathBuf, str::FromStr};
+use tinted_builder::{GenerateOptions, SchemeSystem, SchemeVariant, CVD_MIN_DELTA_E};
 
 const REPO_NAME: &str = env!("CARGO_PKG_NAME");
 
+#[allow(clippy::too_many_lines)]
 fn main() -> Result<()> {
     let matches = get_matches();
     let data_path_result: Result<PathBuf> =
@@ -40,25 +49,29 @@ fn main() -> Result<()> {
     let schemes_path = schemes_path_result?;
 
     match matches.subcommand() {
-        Some(("build", sub_matches)) => {
-            let ignores = {
-                let mut matches = sub_matches
-                    .get_many::<String>("ignore")
-                    .unwrap_or_default()
-                    .cloned()
-                    .collect::<Vec<String>>();
-
-                // Ignore common repo files by default
-                if matches.is_empty() {
-                    matches = vec![
-                        "**/*.md".to_string(),
-                        "**/.*".to_string(),
-                        "**/LICENSE".to_string(),
-                    ];
-                }
+        Some(("audit", sub_matches)) => {
+            let is_cvd = sub_matches
+                .get_one::<bool>("cvd")
+                .is_some_and(ToOwned::to_owned);
+            let min_delta_e = sub_matches
+                .get_one::<f64>("min-delta-e")
+                .copied()
+                .unwrap_or(CVD_MIN_DELTA_E);
+            let audit_schemes_path = sub_matches
+                .get_one::<String>("schemes-path")
+                .map(|path| replace_tilde_slash_with_home(path))
+                .transpose()?
+                .unwrap_or_else(|| schemes_path.clone());
 
-                matches
-            };
+            operations::audit::audit(
+                &audit_schemes_path,
+                &get_ignores(sub_matches),
+                is_cvd,
+                min_delta_e,
+            )?;
+        }
+        Some(("build", sub_matches)) => {
+            let ignores = get_ignores(sub_matches);
             let sync = sub_matches
                 .get_one::<bool>("sync")
                 .is_some_and(ToOwned::to_owned);
@@ -76,7 +89,153 @@ fn main() -> Result<()> {
                 operations::sync::sync(&data_schemes_path, is_quiet)?;
             }
 
-            operations::build::build(&template_path, &schemes_path, &ignores, is_quiet)?;
+            let min_contrast = sub_matches.get_one::<f64>("min-contrast").map(|ratio| {
+                operations::build::MinContrast {
+                    ratio: *ratio,
+                    is_warning: sub_matches
+                        .get_one::<bool>("min-contrast-warn")
+                        .is_some_and(ToOwned::to_owned),
+                }
+            });
+
+            operations::build::build_with_options(
+                &template_path,
+                &schemes_path,
+                &ignores,
+                is_quiet,
+                &operations::build::BuildOptions { min_contrast },
+            )?;
+        }
+        Some(("convert", sub_matches)) => {
+            let is_quiet = sub_matches
+                .get_one::<bool>("quiet")
+                .is_some_and(ToOwned::to_owned);
+            let scheme_file = sub_matches
+                .get_one::<String>("scheme-file")
+                .ok_or_else(|| anyhow!("scheme-file is required"))?;
+            let system = sub_matches
+                .get_one::<String>("to")
+                .ok_or_else(|| anyhow!("--to is required"))
+                .and_then(|system| Ok(SchemeSystem::from_str(system)?))?;
+            let out_path = sub_matches
+                .get_one::<String>("out")
+                .map(|out| replace_tilde_slash_with_home(out))
+                .transpose()?;
+
+            operations::convert::convert(
+                replace_tilde_slash_with_home(scheme_file)?,
+                &system,
+                out_path.as_deref(),
+                is_quiet,
+            )?;
+        }
+        Some(("derive-variant", sub_matches)) => {
+            let is_quiet = sub_matches
+                .get_one::<bool>("quiet")
+                .is_some_and(ToOwned::to_owned);
+            let scheme_file = sub_matches
+                .get_one::<String>("scheme-file")
+                .ok_or_else(|| anyhow!("scheme-file is required"))?;
+            let out_path = sub_matches
+                .get_one::<String>("out")
+                .map(|out| replace_tilde_slash_with_home(out))
+                .transpose()?;
+
+            operations::derive_variant::derive_variant(
+                replace_tilde_slash_with_home(scheme_file)?,
+                out_path.as_deref(),
+                is_quiet,
+            )?;
+        }
+        Some(("fmt", sub_matches)) => {
+            let is_check = sub_matches
+                .get_one::<bool>("check")
+                .is_some_and(ToOwned::to_owned);
+            let is_quiet = sub_matches
+                .get_one::<bool>("quiet")
+                .is_some_and(ToOwned::to_owned);
+            let paths = sub_matches
+                .get_many::<String>("paths")
+                .unwrap_or_default()
+                .map(|path| replace_tilde_slash_with_home(path))
+                .collect::<Result<Vec<PathBuf>>>()?;
+
+            operations::fmt::fmt(&paths, &get_ignores(sub_matches), is_check, is_quiet)?;
+        }
+        Some(("generate", sub_matches)) => {
+            let is_quiet = sub_matches
+                .get_one::<bool>("quiet")
+                .is_some_and(ToOwned::to_owned);
+            let image_path = sub_matches
+                .get_one::<String>("from-image")
+                .map(|path| replace_tilde_slash_with_home(path))
+                .transpose()?;
+            let seeds = sub_matches
+                .get_many::<String>("seed")
+                .unwrap_or_default()
+                .cloned()
+                .collect::<Vec<String>>();
+            let system = sub_matches
+                .get_one::<String>("system")
+                .ok_or_else(|| anyhow!("--system is required"))
+                .and_then(|system| Ok(SchemeSystem::from_str(system)?))?;
+            let variant = sub_matches
+                .get_one::<String>("variant")
+                .map(|variant| SchemeVariant::from_str(variant))
+                .transpose()?;
+            let name = sub_matches
+                .get_one::<String>("name")
+                .cloned()
+                .unwrap_or_else(|| {
+                    image_path
+                        .as_ref()
+                        .and_then(|path| path.file_stem())
+                        .map_or_else(
+                            || "Generated".to_string(),
+                            |stem| stem.to_string_lossy().to_string(),
+                        )
+                });
+            let author = sub_matches
+                .get_one::<String>("author")
+                .cloned()
+                .unwrap_or_default();
+            let out_path = sub_matches
+                .get_one::<String>("out")
+                .map(|out| replace_tilde_slash_with_home(out))
+                .transpose()?;
+            let options = GenerateOptions {
+                name,
+                author,
+                system,
+                variant,
+            };
+
+            match image_path {
+                Some(image_path) => operations::generate::generate_from_image(
+                    &image_path,
+                    &options,
+                    out_path.as_deref(),
+                    is_quiet,
+                )?,
+                None => operations::generate::generate_from_seeds(
+                    &seeds,
+                    &options,
+                    out_path.as_deref(),
+                    is_quiet,
+                )?,
+            }
+        }
+        Some(("lint", sub_matches)) => {
+            let is_quiet = sub_matches
+                .get_one::<bool>("quiet")
+                .is_some_and(ToOwned::to_owned);
+            let lint_schemes_path = sub_matches
+                .get_one::<String>("schemes-path")
+                .map(|path| replace_tilde_slash_with_home(path))
+                .transpose()?
+                .unwrap_or_else(|| schemes_path.clone());
+
+            operations::lint::lint(&lint_schemes_path, &get_ignores(sub_matches), is_quiet)?;
         }
         Some(("sync", sub_matches)) => {
             let is_quiet: bool = sub_matches
@@ -94,6 +253,25 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Returns the `--ignore` glob patterns, ignoring common repo files when none are provided.
+fn get_ignores(matches: &ArgMatches) -> Vec<String> {
+    let ignores = matches
+        .get_many::<String>("ignore")
+        .unwrap_or_default()
+        .cloned()
+        .collect::<Vec<String>>();
+
+    if ignores.is_empty() {
+        vec![
+            "**/*.md".to_string(),
+            "**/.*".to_string(),
+            "**/LICENSE".to_string(),
+        ]
+    } else {
+        ignores
+    }
+}
+
 /// Expands a leading `~/` to the current user's home directory.
 ///
 /// Returns the original input as a `PathBuf` if not prefixed with `~/`.
@@ -0,0 +1,89 @@
+use crate::operations::build::utils::get_scheme_files;
+use anyhow::{anyhow, Result};
+use std::path::Path;
+
+/// Audits every scheme file in a schemes directory and prints a report.
+///
+/// With `is_cvd`, each scheme is checked for color pairs that become indistinguishable with
+/// protanopia, deuteranopia or tritanopia (see `Scheme::cvd_report`). Each scheme with
+/// conflicts is printed with one conflict per line, followed by a summary. Conflicts are
+/// reported, but do not fail the audit.
+///
+/// # Arguments
+///
+/// * `schemes_path` - Directory containing the scheme files, searched recursively.
+/// * `ignores` - Glob patterns to skip when searching `schemes_path`.
+/// * `is_cvd` - A boolean flag that, when set to `true`, runs the color vision deficiency audit.
+/// * `min_delta_e` - The CIEDE2000 distance below which two colors are indistinguishable.
+///
+/// # Errors
+///
+/// Returns an error if `schemes_path` cannot be read or a scheme file cannot be parsed.
+///
+/// # Usage
+///
+/// ```sh
+/// tinted-builder-rust audit --cvd path/to/schemes
+/// ```
+pub fn audit(
+    schemes_path: impl AsRef<Path>,
+    ignores: &[String],
+    is_cvd: bool,
+    min_delta_e: f64,
+) -> Result<()> {
+    let schemes_path = schemes_path.as_ref();
+
+    if !schemes_path.is_dir() {
+        return Err(anyhow!(
+            "The provided schemes path does not exist or is not a directory: {}",
+            schemes_path.display()
+        ));
+    }
+
+    let scheme_files = get_scheme_files(schemes_path, ignores, true)?;
+
+    if !is_cvd {
+        return Ok(());
+    }
+
+    let mut conflict_count = 0;
+    let mut conflicting_scheme_count = 0;
+
+    for scheme_file in &scheme_files {
+        let scheme_path = scheme_file.get_path();
+        let scheme = scheme_file.get_scheme().map_err(|err| {
+            anyhow!(
+                "Unable to deserialize scheme \"{}\": {err}",
+                scheme_path.display()
+            )
+        })?;
+        let report = scheme.cvd_report(min_delta_e);
+
+        if report.conflicts.is_empty() {
+            continue;
+        }
+
+        println!("{}", scheme_path.display());
+
+        for conflict in &report.conflicts {
+            println!("  {conflict}");
+        }
+
+        conflict_count += report.conflicts.len();
+        conflicting_scheme_count += 1;
+    }
+
+    if conflict_count == 0 {
+        println!(
+            "✔ No color vision deficiency conflicts found in {} scheme file(s)",
+            scheme_files.len()
+        );
+    } else {
+        println!(
+            "Found {conflict_count} color vision deficiency conflict(s) in {conflicting_scheme_count} of {} scheme file(s)",
+            scheme_files.len()
+        );
+    }
+
+    Ok(())
+}
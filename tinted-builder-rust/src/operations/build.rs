@@ -6,13 +6,11 @@ use semver::{Version, VersionReq};
 use std::collections::HashMap;
 use std::fs::{self, create_dir_all, read_to_string};
 use std::path::{Path, PathBuf};
-use tinted_builder::tinted8::{
-    Scheme as Tinted8Scheme, SUPPORTED_BUILDER_SPEC_VERSION, SUPPORTED_STYLING_SPEC_VERSION,
-};
-use tinted_builder::{Scheme, SchemeSystem, Template};
+use tinted_builder::tinted8::{SUPPORTED_BUILDER_SPEC_VERSION, SUPPORTED_STYLING_SPEC_VERSION};
+use tinted_builder::{Derivation, Scheme, SchemeFamily, SchemePair, SchemeSystem, Template};
 use utils::{get_scheme_files, parse_filename, ParsedFilename, TemplateConfig};
 
-pub use utils::SchemeFile;
+pub use utils::{BuildOptions, MinContrast, SchemeFile};
 
 const REPO_NAME: &str = env!("CARGO_PKG_NAME");
 
@@ -30,6 +28,7 @@ const REPO_NAME: &str = env!("CARGO_PKG_NAME");
 ///   directory or file.
 /// * `user_schemes_path` - A `impl AsRef<Path>` representing the directory where user schemes are
 ///   stored.
+/// * `ignores` - Glob patterns of scheme files to skip.
 /// * `is_quiet` - A boolean flag that, when set to `true`, suppresses most of the output,
 ///   making the build process quieter.
 ///
@@ -46,6 +45,7 @@ const REPO_NAME: &str = env!("CARGO_PKG_NAME");
 ///   first. * If the theme template configuration file is missing or invalid (e.g., not a valid YAML
 ///   file). * If there are issues reading the template configuration or parsing it as a YAML file. *
 ///   If there are errors during the theme generation process for any configuration.
+/// * If a scheme is below the `min-contrast` of a template config entry and it is not a warning.
 ///
 /// # Usage
 ///
@@ -57,13 +57,39 @@ const REPO_NAME: &str = env!("CARGO_PKG_NAME");
 ///
 /// The function will read the configuration from the specified paths and generate the
 /// corresponding themes.
-#[allow(clippy::too_many_lines)]
+// Only used through the library; the binary calls `build_with_options`.
+#[allow(dead_code)]
 pub fn build(
     theme_template_path: impl AsRef<Path>,
     user_schemes_path: impl AsRef<Path>,
     ignores: &[String],
     is_quiet: bool,
 ) -> Result<()> {
+    build_with_options(
+        theme_template_path,
+        user_schemes_path,
+        ignores,
+        is_quiet,
+        &BuildOptions::default(),
+    )
+}
+
+/// Builds themes like [`build`], with the [`BuildOptions`] of `tinted-builder-rust build`.
+///
+/// # Errors
+///
+/// Returns the errors of [`build`], and an error if a scheme is below `options.min_contrast` and
+/// it is not a warning.
+#[allow(clippy::too_many_lines)]
+pub fn build_with_options(
+    theme_template_path: impl AsRef<Path>,
+    user_schemes_path: impl AsRef<Path>,
+    ignores: &[String],
+    is_quiet: bool,
+    options: &BuildOptions,
+) -> Result<()> {
+    let min_contrast = options.min_contrast.as_ref();
+
     if !user_schemes_path.as_ref().exists() {
         return Err(anyhow!(
             "Schemes don't exist locally. First run `{REPO_NAME} sync` and try again",
@@ -96,9 +122,9 @@ pub fn build(
         )
     })?;
     let template_config: HashMap<String, TemplateConfig> =
-        serde_yaml::from_str(&template_config_content).map_err(|_| {
+        serde_yaml::from_str(&template_config_content).map_err(|err| {
             anyhow!(
-                "E305: Template config missing or invalid: {}",
+                "E305: Template config missing or invalid: {}: {err}",
                 template_config_path.display()
             )
         })?;
@@ -180,7 +206,12 @@ pub fn build(
                         &theme_template_path,
                         &supported_systems,
                         (template_item_config_name, template_item_config_value),
-                        &all_scheme_files,
+                        &get_template_scheme_files(
+                            template_item_config_value,
+                            &supported_systems,
+                            &all_scheme_files,
+                        )?,
+                        min_contrast,
                         is_quiet,
                     )?;
 
@@ -190,36 +221,233 @@ pub fn build(
         }
 
         // If no list exists generate
-        let template_item_scheme_files: Vec<(PathBuf, Scheme)> = all_scheme_files
-            .iter()
-            .filter_map(|(path, scheme)| {
-                if supported_systems.contains(&scheme.get_scheme_system()) {
-                    Some((path.clone(), scheme.clone()))
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        generate_themes_for_config(
-            template_item_config_name,
+        let template_item_scheme_files = get_template_scheme_files(
             template_item_config_value,
-            &theme_template_path,
-            &template_item_scheme_files,
-            is_quiet,
+            &supported_systems,
+            &all_scheme_files,
         )?;
+
+        if template_item_config_value.pairs.unwrap_or_default() {
+            generate_pair_themes_for_config(
+                template_item_config_name,
+                template_item_config_value,
+                &theme_template_path,
+                &template_item_scheme_files,
+                min_contrast,
+                is_quiet,
+            )?;
+        } else {
+            generate_themes_for_config(
+                template_item_config_name,
+                template_item_config_value,
+                &theme_template_path,
+                &template_item_scheme_files,
+                min_contrast,
+                is_quiet,
+            )?;
+        }
     }
 
     Ok(())
 }
 
+/// Returns the schemes a template config entry is rendered with: the schemes of
+/// `supported_systems` followed by the schemes converted from its `convert-from` systems, all
+/// re-derived with its `derivation`.
+fn get_template_scheme_files(
+    config_value: &TemplateConfig,
+    supported_systems: &[SchemeSystem],
+    all_scheme_files: &[(PathBuf, Scheme)],
+) -> Result<Vec<(PathBuf, Scheme)>> {
+    let mut scheme_files: Vec<(PathBuf, Scheme)> = all_scheme_files
+        .iter()
+        .filter(|(_, scheme)| supported_systems.contains(&scheme.get_scheme_system()))
+        .cloned()
+        .collect();
+
+    if let Some(convert_from) = &config_value.convert_from {
+        let converted_scheme_files = convert_scheme_files(
+            all_scheme_files,
+            convert_from,
+            supported_systems,
+            &scheme_files,
+        )?;
+
+        scheme_files.extend(converted_scheme_files);
+    }
+
+    derive_scheme_files(config_value.derivation, &scheme_files)
+}
+
+/// Re-derives the Tinted8 schemes with the `derivation` of a template config entry, if it has one.
+///
+/// Base16 and Base24 schemes are returned unchanged.
+fn derive_scheme_files(
+    derivation: Option<Derivation>,
+    scheme_files: &[(PathBuf, Scheme)],
+) -> Result<Vec<(PathBuf, Scheme)>> {
+    let Some(derivation) = derivation else {
+        return Ok(scheme_files.to_vec());
+    };
+
+    scheme_files
+        .iter()
+        .map(|(path, scheme)| match scheme {
+            Scheme::Tinted8(tinted8_scheme) => tinted8_scheme
+                .with_derivation(derivation)
+                .map(|derived| (path.clone(), Scheme::Tinted8(Box::new(derived))))
+                .map_err(|err| {
+                    anyhow!(
+                        "Unable to derive scheme \"{}\" with {derivation}: {err}",
+                        path.display()
+                    )
+                }),
+            _ => Ok((path.clone(), scheme.clone())),
+        })
+        .collect()
+}
+
+/// Checks the contrast of every scheme against the minimum of a template config entry.
+///
+/// Failing schemes are printed as `W002` warnings when the minimum is a warning, otherwise they
+/// are all returned in a single `E401` error.
+fn check_min_contrast<'a>(
+    config_name: &str,
+    min_contrast: Option<MinContrast>,
+    scheme_files: impl IntoIterator<Item = &'a (PathBuf, Scheme)>,
+) -> Result<()> {
+    let Some(min_contrast) = min_contrast else {
+        return Ok(());
+    };
+    let mut failures: Vec<String> = vec![];
+
+    for (scheme_path, scheme) in scheme_files {
+        let report = scheme.contrast_report();
+        let failing_checks = report.failing(min_contrast.ratio);
+
+        if failing_checks.is_empty() {
+            continue;
+        }
+
+        let checks = failing_checks
+            .iter()
+            .map(|check| format!("  {check}"))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        failures.push(format!(
+            "Scheme \"{}\" ({}) is below the minimum contrast ratio {}:1 for \"{config_name}\":\n{checks}",
+            scheme.get_scheme_slug(),
+            scheme_path.display(),
+            min_contrast.ratio,
+        ));
+    }
+
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    if min_contrast.is_warning {
+        for failure in failures {
+            eprintln!("W002: {failure}");
+        }
+
+        return Ok(());
+    }
+
+    Err(anyhow!("E401: {}", failures.join("\n")))
+}
+
+/// Converts schemes from the `convert-from` systems into the first supported system they can be
+/// converted to.
+///
+/// Schemes whose slug and target system already exist natively in `native_scheme_files` are
+/// skipped so that hand-written schemes are never overwritten by converted ones. When schemes of
+/// several `convert-from` systems convert to the same slug and system, the one of the system
+/// listed first is kept, so that every output path is rendered once. The errors of
+/// systems a scheme could not be converted to are printed as a `W003` warning when it was
+/// converted to a later one, and returned in the `E113` error when it could not be converted at
+/// all.
+fn convert_scheme_files(
+    all_scheme_files: &[(PathBuf, Scheme)],
+    convert_from: &[SchemeSystem],
+    supported_systems: &[SchemeSystem],
+    native_scheme_files: &[(PathBuf, Scheme)],
+) -> Result<Vec<(PathBuf, Scheme)>> {
+    let mut converted_scheme_files: Vec<(PathBuf, Scheme)> = vec![];
+    let source_scheme_files = convert_from
+        .iter()
+        .filter(|system| !supported_systems.contains(system))
+        .flat_map(|system| {
+            all_scheme_files
+                .iter()
+                .filter(move |(_, scheme)| scheme.get_scheme_system() == *system)
+        });
+
+    for (path, scheme) in source_scheme_files {
+        let scheme_system = scheme.get_scheme_system();
+
+        let mut conversion_errors: Vec<String> = vec![];
+        let converted_scheme =
+            supported_systems
+                .iter()
+                .find_map(|system| match scheme.to_system(system) {
+                    Ok(converted_scheme) => Some(converted_scheme),
+                    Err(err) => {
+                        conversion_errors.push(format!("  {system}: {err}"));
+                        None
+                    }
+                });
+        let Some(converted_scheme) = converted_scheme else {
+            return Err(anyhow!(
+                "E113: Unable to convert scheme \"{}\" from {} to any of the supported systems:\n{}",
+                path.display(),
+                scheme_system,
+                conversion_errors.join("\n"),
+            ));
+        };
+
+        if !conversion_errors.is_empty() {
+            eprintln!(
+                "W003: Scheme \"{}\" was converted to {} since it could not be converted to the preferred systems:\n{}",
+                path.display(),
+                converted_scheme.get_scheme_system(),
+                conversion_errors.join("\n"),
+            );
+        }
+
+        let is_duplicate = native_scheme_files
+            .iter()
+            .chain(&converted_scheme_files)
+            .any(|(_, other_scheme)| {
+                other_scheme.get_scheme_slug() == converted_scheme.get_scheme_slug()
+                    && other_scheme.get_scheme_system() == converted_scheme.get_scheme_system()
+            });
+
+        if !is_duplicate {
+            converted_scheme_files.push((path.clone(), converted_scheme));
+        }
+    }
+
+    Ok(converted_scheme_files)
+}
+
 fn render_list(
     template_path: impl AsRef<Path>,
     supported_systems: &[SchemeSystem],
     (config_name, config_value): (&str, &TemplateConfig),
     all_scheme_files: &[(PathBuf, Scheme)],
+    min_contrast: Option<&MinContrast>,
     is_quiet: bool,
 ) -> Result<()> {
+    check_min_contrast(
+        config_name,
+        config_value.get_min_contrast(min_contrast),
+        all_scheme_files
+            .iter()
+            .filter(|(_, scheme)| supported_systems.contains(&scheme.get_scheme_system())),
+    )?;
+
     let filename = get_filename(config_value, is_quiet)?;
     let mustache_template_path = template_path
         .as_ref()
@@ -241,42 +469,29 @@ fn render_list(
     }
 
     for scheme_system in supported_systems {
-        match &scheme_system {
-            SchemeSystem::Base16 | SchemeSystem::Base24 => {
-                let schemes: Vec<serde_yaml::Value> = all_scheme_files
-                    .iter()
-                    .filter_map(|(_, scheme)| match scheme {
-                        Scheme::Base16(s) => serde_yaml::to_value(s).ok(),
-                        Scheme::Base24(s) => serde_yaml::to_value(s).ok(),
-                        _ => None,
-                    })
-                    .collect();
-
-                let mut data: HashMap<&str, Vec<serde_yaml::Value>> = HashMap::new();
-                data.insert("schemes", schemes);
-
-                *data_yaml = serde_yaml::to_string(&data).unwrap_or_default();
-            }
-            SchemeSystem::Tinted8 => {
-                let mut data: HashMap<&str, Vec<Box<Tinted8Scheme>>> = HashMap::new();
-
-                data.insert(
-                    "schemes",
-                    all_scheme_files
-                        .iter()
-                        .cloned()
-                        .filter_map(|(_, scheme)| match scheme {
-                            Scheme::Tinted8(scheme) => Some(scheme),
-                            _ => None,
-                        })
-                        .collect::<Vec<Box<Tinted8Scheme>>>(),
-                );
-
-                *data_yaml = serde_yaml::to_string(&data).unwrap_or_default();
-            }
+        let schemes: Vec<&Scheme> = match &scheme_system {
+            SchemeSystem::Base16 | SchemeSystem::Base24 => all_scheme_files
+                .iter()
+                .filter(|(_, scheme)| matches!(scheme, Scheme::Base16(_) | Scheme::Base24(_)))
+                .map(|(_, scheme)| scheme)
+                .collect(),
+            SchemeSystem::Tinted8 => all_scheme_files
+                .iter()
+                .filter(|(_, scheme)| matches!(scheme, Scheme::Tinted8(_)))
+                .map(|(_, scheme)| scheme)
+                .collect(),
 
             _ => return Err(anyhow!("E110: Unknown or unsupported scheme system")),
-        }
+        };
+
+        let mut data: HashMap<&str, serde_yaml::Value> = HashMap::new();
+        data.insert("schemes", serde_yaml::to_value(&schemes)?);
+        data.insert(
+            "families",
+            serde_yaml::to_value(SchemeFamily::from_schemes(schemes))?,
+        );
+
+        *data_yaml = serde_yaml::to_string(&data).unwrap_or_default();
     }
 
     let supported_systems_str = &supported_systems
@@ -357,6 +572,7 @@ fn generate_themes_for_config(
     config_value: &TemplateConfig,
     theme_template_path: impl AsRef<Path>,
     scheme_files: &Vec<(PathBuf, Scheme)>,
+    min_contrast: Option<&MinContrast>,
     is_quiet: bool,
 ) -> Result<()> {
     if scheme_files.is_empty() {
@@ -365,6 +581,12 @@ fn generate_themes_for_config(
         return Ok(());
     }
 
+    check_min_contrast(
+        config_name,
+        config_value.get_min_contrast(min_contrast),
+        scheme_files,
+    )?;
+
     let filename = get_filename(config_value, is_quiet)?;
     let mustache_template_path = theme_template_path
         .as_ref()
@@ -399,40 +621,14 @@ fn generate_themes_for_config(
         }?;
 
         // Enforce tinted8 styling version compliance if requested by config
-        if let (Scheme::Tinted8(s), Some(req)) = (scheme, tinted8_styling_req.clone()) {
-            // Print system line (per example output)
-            if !is_quiet {
-                println!("→ system: {}", s.scheme.system);
-            }
-
-            let scheme_styling_version = Version::parse(&s.scheme.supports.styling_spec)?;
-            if !req.matches(&scheme_styling_version) {
-                return Err(anyhow!(
-                    "E002: Scheme requires Styling v{scheme_styling_version} but tinted8-builder supports only {req}",
-                ));
-            }
-            if !is_quiet {
-                println!("→ tinted8-styling: v{scheme_styling_version} (supported range {req})");
-            }
-        }
+        check_tinted8_styling(scheme, tinted8_styling_req.as_ref(), is_quiet)?;
 
         // Early system validation (defensive): ensure scheme matches supported systems
         if !supported_systems.contains(scheme_system) {
             return Err(anyhow!("E001: Invalid system"));
         }
 
-        // Replace string variables. Use lazy replace instead of running through mustache template
-        // rendering engine for performace
-        let filepath = filename
-            .replace("{{ scheme-slug }}", &scheme_slug.clone())
-            .replace("{{scheme-slug}}", &scheme_slug.clone())
-            .replace("{{ scheme-system }}", &scheme_system.to_string())
-            .replace("{{scheme-system}}", &scheme_system.to_string())
-            .replace("{{ scheme.slug }}", &scheme_slug.clone())
-            .replace("{{scheme.slug}}", &scheme_slug.clone())
-            .replace("{{ scheme.system }}", &scheme_system.to_string())
-            .replace("{{scheme.system}}", &scheme_system.to_string());
-
+        let filepath = replace_filename_variables(&filename, scheme_slug, scheme_system);
         let parsed_filename = parse_filename(&theme_template_path, &filepath);
         if !parsed_filename.directory.exists() {
             create_dir_all(&parsed_filename.directory)?;
@@ -442,6 +638,7 @@ fn generate_themes_for_config(
             &template_content,
             parsed_filename,
             scheme_path,
+            scheme,
             &scheme_system.clone(),
         )?;
     }
@@ -461,11 +658,137 @@ fn generate_themes_for_config(
     Ok(())
 }
 
+/// Generates one theme file per `SchemePair` of the schemes of a template config entry with
+/// `pairs: true`.
+///
+/// Each file is rendered with `Template::new_pair`, so the template receives both schemes as
+/// `dark` and `light`. The `scheme-slug` in the filename is replaced with the slug of the pair.
+/// Schemes without a counterpart of the opposite variant are skipped, and a `W001` warning is
+/// printed when no pairs are found.
+fn generate_pair_themes_for_config(
+    config_name: &str,
+    config_value: &TemplateConfig,
+    theme_template_path: impl AsRef<Path>,
+    scheme_files: &[(PathBuf, Scheme)],
+    min_contrast: Option<&MinContrast>,
+    is_quiet: bool,
+) -> Result<()> {
+    let pairs = SchemePair::from_schemes(
+        scheme_files
+            .iter()
+            .filter(|(path, _)| {
+                !path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with('.'))
+            })
+            .map(|(_, scheme)| scheme),
+    );
+
+    if pairs.is_empty() {
+        eprintln!("W001: No scheme pairs found for a template config entry \"{config_name}\"");
+
+        return Ok(());
+    }
+
+    check_min_contrast(
+        config_name,
+        config_value.get_min_contrast(min_contrast),
+        scheme_files.iter().filter(|(_, scheme)| {
+            pairs.iter().any(|pair| {
+                pair.get_scheme_system() == scheme.get_scheme_system()
+                    && [&pair.dark, &pair.light]
+                        .iter()
+                        .any(|paired| paired.get_scheme_slug() == scheme.get_scheme_slug())
+            })
+        }),
+    )?;
+
+    let filename = get_filename(config_value, is_quiet)?;
+    let mustache_template_path = theme_template_path
+        .as_ref()
+        .join(format!("templates/{config_name}.mustache"));
+    let template_content = read_to_string(&mustache_template_path).map_err(|_| {
+        anyhow!(
+            "E303: Mustache template missing: {}",
+            mustache_template_path.display()
+        )
+    })?;
+    let tinted8_styling_req: Option<VersionReq> = config_value
+        .supports
+        .as_ref()
+        .and_then(|m| m.get("tinted8-styling"))
+        .and_then(|s| VersionReq::parse(s).ok());
+
+    for pair in pairs {
+        for scheme in [&pair.dark, &pair.light] {
+            check_tinted8_styling(scheme, tinted8_styling_req.as_ref(), is_quiet)?;
+        }
+
+        let filepath = replace_filename_variables(&filename, &pair.slug, &pair.get_scheme_system());
+        let parsed_filename = parse_filename(&theme_template_path, &filepath);
+        let output_path = parsed_filename.get_path();
+        let output = Template::new_pair(template_content.clone(), pair).render()?;
+
+        if !parsed_filename.directory.exists() {
+            create_dir_all(&parsed_filename.directory)?;
+        }
+
+        write_to_file(&output_path, &output)?;
+    }
+
+    if !is_quiet {
+        println!("✔ Successfully generated scheme pair themes for \"{config_name}\"");
+    }
+
+    Ok(())
+}
+
+/// Checks a Tinted8 scheme against the `tinted8-styling` version requirement of a template
+/// config entry. Other schemes, and configs without a requirement, always pass.
+fn check_tinted8_styling(scheme: &Scheme, req: Option<&VersionReq>, is_quiet: bool) -> Result<()> {
+    let (Scheme::Tinted8(s), Some(req)) = (scheme, req) else {
+        return Ok(());
+    };
+
+    // Print system line (per example output)
+    if !is_quiet {
+        println!("→ system: {}", s.scheme.system);
+    }
+
+    let scheme_styling_version = Version::parse(&s.scheme.supports.styling_spec)?;
+    if !req.matches(&scheme_styling_version) {
+        return Err(anyhow!(
+            "E002: Scheme requires Styling v{scheme_styling_version} but tinted8-builder supports only {req}",
+        ));
+    }
+    if !is_quiet {
+        println!("→ tinted8-styling: v{scheme_styling_version} (supported range {req})");
+    }
+
+    Ok(())
+}
+
+/// Replaces the `scheme-slug` and `scheme-system` variables of a template config `filename`.
+fn replace_filename_variables(filename: &str, slug: &str, system: &SchemeSystem) -> String {
+    // Replace string variables. Use lazy replace instead of running through mustache template
+    // rendering engine for performace
+    filename
+        .replace("{{ scheme-slug }}", slug)
+        .replace("{{scheme-slug}}", slug)
+        .replace("{{ scheme-system }}", &system.to_string())
+        .replace("{{scheme-system}}", &system.to_string())
+        .replace("{{ scheme.slug }}", slug)
+        .replace("{{scheme.slug}}", slug)
+        .replace("{{ scheme.system }}", &system.to_string())
+        .replace("{{scheme.system}}", &system.to_string())
+}
+
 /// Generates a theme file based on a given template and scheme.
 ///
-/// This function processes a scheme file and generates a themed output file
-/// in the specified directory. It reads the scheme data, applies it to the template,
-/// and writes the output to a file with the appropriate extension.
+/// This function renders a scheme read from a scheme file (or converted from one) and generates
+/// a themed output file in the specified directory. It applies the scheme to the template, and
+/// writes the output to a file with the appropriate extension.
 ///
 /// The function also filters out hidden files (those whose names start with a `.`)
 /// and ensures that the scheme system matches the provided `SchemeSystem`.
@@ -475,6 +798,7 @@ fn generate_themes_for_config(
 /// * `template_content` - A reference to a string slice containing the template's content.
 /// * `output_dir` - A reference to a `PathBuf` representing the directory where the output file will be written.
 /// * `scheme_path` - A `impl AsRef<Path>` representing the file path to the scheme file.
+/// * `scheme` - The `Scheme` parsed from `scheme_path`, or converted from it.
 /// * `system` - The `SchemeSystem` that the scheme file should match.
 /// * `explicit_extension` - A string slice representing the file extension for the generated theme
 ///   file. The parameter is named "explict" extension because it includes the "dot" or lack thereof
@@ -504,6 +828,7 @@ fn generate_theme(
     template_content: &str,
     parsed_filename: ParsedFilename,
     scheme_path: impl AsRef<Path>,
+    scheme: &Scheme,
     system: &SchemeSystem,
 ) -> Result<()> {
     let scheme_file_type = SchemeFile::new(scheme_path)?;
@@ -519,9 +844,7 @@ fn generate_theme(
         return Ok(());
     }
 
-    let scheme = scheme_file_type.get_scheme()?;
-
-    match &scheme {
+    match scheme {
         Scheme::Base16(scheme_inner) => {
             if scheme_inner.system != *system {
                 return Err(anyhow!("E001: Invalid system"));
@@ -1,9 +1,10 @@
 use anyhow::{anyhow, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use std::collections::HashMap;
 use std::fs::read_to_string;
+use std::ops::RangeInclusive;
 use std::path::{Path, PathBuf};
-use tinted_builder::{Scheme, SchemeSystem};
+use tinted_builder::{Derivation, Scheme, SchemeSystem};
 use wax::{Glob, Program};
 
 /// Represents a path to a scheme file with a supported extension.
@@ -118,6 +119,32 @@ pub struct TemplateConfig {
 
     pub options: Option<HashMap<String, String>>,
 
+    /// Scheme systems to convert into `supported-systems` when rendering. Native schemes take
+    /// precedence over converted schemes with the same slug.
+    #[serde(rename = "convert-from")]
+    pub convert_from: Option<Vec<SchemeSystem>>,
+
+    /// Minimum WCAG contrast ratio of each scheme's text/background pairs. Schemes below it fail
+    /// the build.
+    #[serde(
+        rename = "min-contrast",
+        default,
+        deserialize_with = "deserialize_min_contrast"
+    )]
+    pub min_contrast: Option<f64>,
+
+    /// Reports schemes below `min-contrast` as warnings instead of failing the build.
+    #[serde(rename = "min-contrast-warn")]
+    pub min_contrast_warn: Option<bool>,
+
+    /// Color space used to derive Tinted8 `dim`/`bright` palette variants, overriding the
+    /// `derivation` of each scheme.
+    pub derivation: Option<Derivation>,
+
+    /// Renders one file per pair of dark and light schemes instead of one file per scheme, see
+    /// `SchemePair`.
+    pub pairs: Option<bool>,
+
     #[deprecated]
     pub extension: Option<String>,
 
@@ -125,6 +152,79 @@ pub struct TemplateConfig {
     pub output: Option<String>,
 }
 
+impl TemplateConfig {
+    /// Returns the contrast threshold for this config, preferring `min_contrast_override`.
+    #[must_use]
+    pub fn get_min_contrast(
+        &self,
+        min_contrast_override: Option<&MinContrast>,
+    ) -> Option<MinContrast> {
+        min_contrast_override.copied().or_else(|| {
+            self.min_contrast.map(|ratio| MinContrast {
+                ratio,
+                is_warning: self.min_contrast_warn.unwrap_or_default(),
+            })
+        })
+    }
+}
+
+/// Options of [`build_with_options`](crate::operations::build::build_with_options) that
+/// [`build`](crate::operations::build::build) leaves at their defaults.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BuildOptions {
+    /// A minimum WCAG contrast ratio that takes precedence over the `min-contrast` and
+    /// `min-contrast-warn` properties of each template config entry.
+    pub min_contrast: Option<MinContrast>,
+}
+
+/// Minimum WCAG contrast ratio required of a scheme's text/background pairs.
+///
+/// The pairs are the ones checked by `Scheme::contrast_report`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinContrast {
+    pub ratio: f64,
+    /// Report schemes below `ratio` as warnings instead of failing the build.
+    pub is_warning: bool,
+}
+
+/// The range of WCAG contrast ratios, from identical colors to black on white.
+pub const MIN_CONTRAST_RANGE: RangeInclusive<f64> = 1.0..=21.0;
+
+/// Parses a minimum WCAG contrast ratio, used as the value parser of `--min-contrast`.
+///
+/// # Errors
+///
+/// Returns an error if `value` is not a number within `MIN_CONTRAST_RANGE`.
+pub fn parse_min_contrast(value: &str) -> Result<f64, String> {
+    let ratio = value
+        .parse::<f64>()
+        .map_err(|_| format!("\"{value}\" is not a contrast ratio"))?;
+
+    validate_min_contrast(ratio)
+}
+
+fn validate_min_contrast(ratio: f64) -> Result<f64, String> {
+    if MIN_CONTRAST_RANGE.contains(&ratio) {
+        Ok(ratio)
+    } else {
+        Err(format!(
+            "min-contrast must be a WCAG contrast ratio between {} and {}, got {ratio}",
+            MIN_CONTRAST_RANGE.start(),
+            MIN_CONTRAST_RANGE.end()
+        ))
+    }
+}
+
+fn deserialize_min_contrast<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<f64>::deserialize(deserializer)?
+        .map(validate_min_contrast)
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
+
 /// Parsed components of a generated output filename.
 #[derive(Debug)]
 pub struct ParsedFilename {
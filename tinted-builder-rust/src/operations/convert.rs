@@ -0,0 +1,76 @@
+use crate::helpers::write_to_file;
+use crate::operations::build::SchemeFile;
+use anyhow::{anyhow, Context, Result};
+use std::fs::create_dir_all;
+use std::path::Path;
+use tinted_builder::SchemeSystem;
+
+/// Converts a scheme file into another scheme system and outputs it as a YAML scheme.
+///
+/// The scheme is read with [`SchemeFile::get_scheme`] and converted with `Scheme::to_system`, so
+/// the slot-mapping rules are the ones documented by the `tinted-builder` library. The converted
+/// scheme is written to `out_path` when provided, otherwise it is printed to stdout.
+///
+/// # Arguments
+///
+/// * `scheme_path` - Path to the `.yaml`/`.yml` scheme file to convert.
+/// * `system` - The scheme system to convert the scheme into.
+/// * `out_path` - Optional path of the file to write the converted scheme to.
+/// * `is_quiet` - A boolean flag that, when set to `true`, suppresses the success message printed
+///   after writing `out_path`.
+///
+/// # Errors
+///
+/// Returns an error if the scheme file cannot be read or parsed, if the scheme cannot be
+/// converted into `system`, or if the output file cannot be written.
+///
+/// # Usage
+///
+/// ```sh
+/// tinted-builder-rust convert path/to/scheme.yaml --to tinted8 --out path/to/tinted8-scheme.yaml
+/// ```
+pub fn convert(
+    scheme_path: impl AsRef<Path>,
+    system: &SchemeSystem,
+    out_path: Option<&Path>,
+    is_quiet: bool,
+) -> Result<()> {
+    let scheme_path = scheme_path.as_ref();
+    let scheme = SchemeFile::new(scheme_path)?
+        .get_scheme()
+        .with_context(|| {
+            format!(
+                "E112: Unable to parse scheme file: {}",
+                scheme_path.display()
+            )
+        })?;
+    let converted_scheme = scheme.to_system(system).map_err(|err| {
+        anyhow!(
+            "E113: Unable to convert scheme \"{}\" from {} to {system}: {err}",
+            scheme_path.display(),
+            scheme.get_scheme_system(),
+        )
+    })?;
+    let yaml = converted_scheme.to_yaml_string()?;
+
+    match out_path {
+        Some(out_path) => {
+            if let Some(parent) = out_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                create_dir_all(parent)?;
+            }
+
+            write_to_file(out_path, &yaml)?;
+
+            if !is_quiet {
+                println!(
+                    "✔ Successfully converted \"{}\" to {system}: {}",
+                    scheme_path.display(),
+                    out_path.display()
+                );
+            }
+        }
+        None => print!("{yaml}"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use crate::helpers::write_to_file;
+use crate::operations::build::SchemeFile;
+use anyhow::{anyhow, Context, Result};
+use std::fs::create_dir_all;
+use std::path::Path;
+
+/// Derives the opposite light/dark variant of a scheme file and writes it as a YAML scheme.
+///
+/// The scheme is read with [`SchemeFile::get_scheme`] and inverted with `Scheme::invert_variant`,
+/// which reflects the lightness of every color and updates the scheme name and slug. The new
+/// scheme is written to `out_path` when provided, otherwise next to the scheme file as
+/// `<slug>.yaml`. In the latter case an existing file is never overwritten, since it is usually a
+/// hand-made variant of the same scheme.
+///
+/// # Arguments
+///
+/// * `scheme_path` - Path to the `.yaml`/`.yml` scheme file to derive the variant from.
+/// * `out_path` - Optional path of the file to write the derived scheme to.
+/// * `is_quiet` - A boolean flag that, when set to `true`, suppresses the success message.
+///
+/// # Errors
+///
+/// Returns an error if the scheme file cannot be read or parsed, an `E119` error if the variant
+/// cannot be derived or the default output file already exists, and an error if the output file
+/// cannot be written.
+///
+/// # Usage
+///
+/// ```sh
+/// tinted-builder-rust derive-variant path/to/gruvbox-dark.yaml --out path/to/gruvbox-light.yaml
+/// ```
+pub fn derive_variant(
+    scheme_path: impl AsRef<Path>,
+    out_path: Option<&Path>,
+    is_quiet: bool,
+) -> Result<()> {
+    let scheme_path = scheme_path.as_ref();
+    let scheme = SchemeFile::new(scheme_path)?
+        .get_scheme()
+        .with_context(|| {
+            format!(
+                "E112: Unable to parse scheme file: {}",
+                scheme_path.display()
+            )
+        })?;
+    let derived_scheme = scheme.invert_variant().map_err(|err| {
+        anyhow!(
+            "E119: Unable to derive the {} variant of \"{}\": {err}",
+            scheme.get_scheme_variant().opposite(),
+            scheme_path.display(),
+        )
+    })?;
+    let out_path = if let Some(out_path) = out_path {
+        out_path.to_path_buf()
+    } else {
+        let out_path =
+            scheme_path.with_file_name(format!("{}.yaml", derived_scheme.get_scheme_slug()));
+
+        if out_path.exists() {
+            return Err(anyhow!(
+                "E119: Unable to derive the {} variant of \"{}\", \"{}\" already exists. Use --out to write it elsewhere",
+                derived_scheme.get_scheme_variant(),
+                scheme_path.display(),
+                out_path.display(),
+            ));
+        }
+
+        out_path
+    };
+
+    if let Some(parent) = out_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        create_dir_all(parent)?;
+    }
+
+    write_to_file(&out_path, &derived_scheme.to_yaml_string()?)?;
+
+    if !is_quiet {
+        println!(
+            "✔ Successfully derived {} scheme \"{}\": {}",
+            derived_scheme.get_scheme_variant(),
+            derived_scheme.get_scheme_name(),
+            out_path.display()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use crate::helpers::write_to_file;
+use crate::operations::build::utils::get_scheme_files;
+use crate::operations::build::SchemeFile;
+use anyhow::{anyhow, Context, Result};
+use serde_yaml::Value;
+use similar::TextDiff;
+use std::fs::read_to_string;
+use std::path::PathBuf;
+use tinted_builder::Scheme;
+
+/// Rewrites scheme files in their canonical form.
+///
+/// Each path can be a scheme file or a directory, which is searched recursively for scheme files
+/// while skipping `ignores`. Every scheme is read with [`SchemeFile::get_scheme`] and compared
+/// with the output of `Scheme::to_yaml_string`.
+///
+/// # Arguments
+///
+/// * `paths` - Scheme files and/or directories containing scheme files.
+/// * `ignores` - Glob patterns to skip when searching directories.
+/// * `is_check` - When `true`, files are not rewritten. Instead a diff is printed for every file
+///   that is not formatted and an error is returned if there is at least one.
+/// * `is_quiet` - A boolean flag that, when set to `true`, suppresses the list of rewritten files.
+///
+/// # Errors
+///
+/// Returns an error if a path does not exist, a scheme file cannot be read, parsed or written, if
+/// the formatted scheme does not have the same palette as the original (`E120`), or if `is_check`
+/// is `true` and a scheme file is not formatted.
+///
+/// # Usage
+///
+/// ```sh
+/// tinted-builder-rust fmt --check path/to/schemes
+/// ```
+pub fn fmt(paths: &[PathBuf], ignores: &[String], is_check: bool, is_quiet: bool) -> Result<()> {
+    let mut unformatted_paths: Vec<PathBuf> = vec![];
+
+    for scheme_file in get_scheme_files_from_paths(paths, ignores)? {
+        let scheme_path = scheme_file.get_path();
+        let content = read_to_string(&scheme_path)
+            .with_context(|| format!("Unable to read scheme file: {}", scheme_path.display()))?;
+        let scheme = scheme_file.get_scheme().with_context(|| {
+            format!(
+                "E112: Unable to parse scheme file: {}",
+                scheme_path.display()
+            )
+        })?;
+        let formatted_content = scheme.to_yaml_string()?;
+
+        if get_palette(&Scheme::from_yaml(&formatted_content)?)? != get_palette(&scheme)? {
+            return Err(anyhow!(
+                "E120: Formatting would change the palette of scheme file: {}",
+                scheme_path.display()
+            ));
+        }
+
+        if content == formatted_content {
+            continue;
+        }
+
+        if is_check {
+            let path_str = scheme_path.display().to_string();
+
+            print!(
+                "{}",
+                TextDiff::from_lines(&content, &formatted_content)
+                    .unified_diff()
+                    .header(&path_str, &path_str)
+            );
+        } else {
+            write_to_file(&scheme_path, &formatted_content)?;
+
+            if !is_quiet {
+                println!("Formatted {}", scheme_path.display());
+            }
+        }
+
+        unformatted_paths.push(scheme_path);
+    }
+
+    if is_check && !unformatted_paths.is_empty() {
+        return Err(anyhow!(
+            "E114: {} scheme file(s) are not formatted, run `fmt` without `--check` to format them",
+            unformatted_paths.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the resolved palette of a scheme, to compare a scheme with its formatted output.
+fn get_palette(scheme: &Scheme) -> Result<Option<Value>> {
+    Ok(serde_yaml::to_value(scheme)?.get("palette").cloned())
+}
+
+/// Collects the scheme files for a list of scheme file and directory paths.
+fn get_scheme_files_from_paths(paths: &[PathBuf], ignores: &[String]) -> Result<Vec<SchemeFile>> {
+    let mut scheme_files: Vec<SchemeFile> = vec![];
+
+    for path in paths {
+        if path.is_dir() {
+            scheme_files.extend(get_scheme_files(path, ignores, true)?);
+        } else if path.is_file() {
+            scheme_files.push(SchemeFile::new(path)?);
+        } else {
+            return Err(anyhow!(
+                "The provided scheme path does not exist: {}",
+                path.display()
+            ));
+        }
+    }
+
+    Ok(scheme_files)
+}
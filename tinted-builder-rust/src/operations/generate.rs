@@ -0,0 +1,124 @@
+use crate::helpers::write_to_file;
+use anyhow::{anyhow, Result};
+use std::fs::create_dir_all;
+use std::path::Path;
+use tinted_builder::{Color, GenerateOptions, Scheme};
+
+/// Generates a scheme from the colors of a PNG or JPEG image and outputs it as a YAML scheme.
+///
+/// The image is decoded locally and its pixels are passed to `Scheme::from_pixels`, which
+/// quantizes them and picks the background, foreground and accents. The generated scheme is
+/// written to `out_path` when provided, otherwise it is printed to stdout.
+///
+/// # Arguments
+///
+/// * `image_path` - Path to the `.png`/`.jpg`/`.jpeg` image to generate the scheme from.
+/// * `options` - The name, author, system and optional variant of the generated scheme.
+/// * `out_path` - Optional path of the file to write the generated scheme to.
+/// * `is_quiet` - A boolean flag that, when set to `true`, suppresses the success message printed
+///   after writing `out_path`.
+///
+/// # Errors
+///
+/// Returns an `E118` error if the image cannot be read or decoded, or if no scheme can be
+/// generated from it, and an error if the output file cannot be written.
+///
+/// # Usage
+///
+/// ```sh
+/// tinted-builder-rust generate --from-image wallpaper.png --system tinted8 --out wallpaper.yaml
+/// ```
+pub fn generate_from_image(
+    image_path: impl AsRef<Path>,
+    options: &GenerateOptions,
+    out_path: Option<&Path>,
+    is_quiet: bool,
+) -> Result<()> {
+    let image_path = image_path.as_ref();
+    let image = image::open(image_path).map_err(|err| {
+        anyhow!(
+            "E118: Unable to read image \"{}\": {err}",
+            image_path.display()
+        )
+    })?;
+    let pixels: Vec<[u8; 3]> = image.to_rgb8().pixels().map(|pixel| pixel.0).collect();
+    let scheme = Scheme::from_pixels(&pixels, options).map_err(|err| {
+        anyhow!(
+            "E118: Unable to generate a scheme from \"{}\": {err}",
+            image_path.display()
+        )
+    })?;
+
+    write_scheme(&scheme, out_path, is_quiet)
+}
+
+/// Generates a scheme from seed colors and outputs it as a YAML scheme.
+///
+/// The seeds are parsed like scheme colors (hex, `rgb()`, `hsl()`, `oklch()` or CSS named
+/// colors) and passed to `Scheme::from_seed_colors`, which synthesizes the rest of the palette
+/// and validates its contrast. The generated scheme is written to `out_path` when provided,
+/// otherwise it is printed to stdout.
+///
+/// # Arguments
+///
+/// * `seeds` - The seed colors, e.g. a background and one or more accents.
+/// * `options` - The name, author, system and optional variant of the generated scheme.
+/// * `out_path` - Optional path of the file to write the generated scheme to.
+/// * `is_quiet` - A boolean flag that, when set to `true`, suppresses the success message printed
+///   after writing `out_path`.
+///
+/// # Errors
+///
+/// Returns an `E118` error if a seed is not a valid color or if no readable scheme can be
+/// generated from the seeds, and an error if the output file cannot be written.
+///
+/// # Usage
+///
+/// ```sh
+/// tinted-builder-rust generate --seed "#1e1e2e" --seed "#89b4fa" --variant dark --system base24
+/// ```
+pub fn generate_from_seeds(
+    seeds: &[String],
+    options: &GenerateOptions,
+    out_path: Option<&Path>,
+    is_quiet: bool,
+) -> Result<()> {
+    let colors = seeds
+        .iter()
+        .map(|seed| {
+            Color::new(seed, None, None)
+                .map_err(|err| anyhow!("E118: Invalid seed color \"{seed}\": {err}"))
+        })
+        .collect::<Result<Vec<Color>>>()?;
+    let scheme = Scheme::from_seed_colors(&colors, options)
+        .map_err(|err| anyhow!("E118: Unable to generate a scheme from the seed colors, {err}"))?;
+
+    write_scheme(&scheme, out_path, is_quiet)
+}
+
+/// Writes a generated scheme to `out_path`, or prints it to stdout.
+fn write_scheme(scheme: &Scheme, out_path: Option<&Path>, is_quiet: bool) -> Result<()> {
+    let yaml = scheme.to_yaml_string()?;
+
+    match out_path {
+        Some(out_path) => {
+            if let Some(parent) = out_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                create_dir_all(parent)?;
+            }
+
+            write_to_file(out_path, &yaml)?;
+
+            if !is_quiet {
+                println!(
+                    "✔ Successfully generated {} scheme \"{}\": {}",
+                    scheme.get_scheme_system(),
+                    scheme.get_scheme_name(),
+                    out_path.display()
+                );
+            }
+        }
+        None => print!("{yaml}"),
+    }
+
+    Ok(())
+}
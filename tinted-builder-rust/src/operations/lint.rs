@@ -0,0 +1,632 @@
+use crate::operations::build::SchemeFile;
+use anyhow::{anyhow, Result};
+use semver::{Version, VersionReq};
+use serde_yaml::{Mapping, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tinted_builder::base16::REQUIRED_BASE16_PALETTE_KEYS;
+use tinted_builder::base24::REQUIRED_BASE24_PALETTE_KEYS;
+use tinted_builder::tinted8::{ColorExpression, SUPPORTED_STYLING_SPEC_VERSION};
+use tinted_builder::{Color, SchemeSystem};
+use wax::{Glob, Program};
+
+const TINTED8_REQUIRED_PALETTE_KEYS: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// A single problem found in a scheme file, located by its 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}: {}",
+            self.path.display(),
+            self.line,
+            self.column,
+            self.code,
+            self.message
+        )
+    }
+}
+
+/// Lints every scheme file in a schemes directory and prints the problems found.
+///
+/// Unlike `build`, linting does not stop at the first invalid scheme. Every scheme file is
+/// checked for YAML syntax errors, missing or unknown systems, schemes in a directory of another
+/// system, missing required properties and palette keys, invalid colors and unsupported
+/// `supports.styling-spec` versions. Slugs are checked for collisions across scheme files of the
+/// same system. Each problem is printed as `path:line:column: code: message`, followed by a
+/// summary.
+///
+/// # Arguments
+///
+/// * `schemes_path` - Directory containing the scheme files, searched recursively.
+/// * `ignores` - Glob patterns to skip when searching `schemes_path`.
+/// * `is_quiet` - A boolean flag that, when set to `true`, suppresses the summary when no
+///   problems are found.
+///
+/// # Errors
+///
+/// Returns an error if `schemes_path` cannot be read or if any problem was found.
+///
+/// # Usage
+///
+/// ```sh
+/// tinted-builder-rust lint path/to/schemes
+/// ```
+pub fn lint(schemes_path: impl AsRef<Path>, ignores: &[String], is_quiet: bool) -> Result<()> {
+    let schemes_path = schemes_path.as_ref();
+
+    if !schemes_path.is_dir() {
+        return Err(anyhow!(
+            "The provided schemes path does not exist or is not a directory: {}",
+            schemes_path.display()
+        ));
+    }
+
+    let glob_ignores: Vec<Glob> = ignores
+        .iter()
+        .map(|s| Glob::new(s))
+        .collect::<Result<_, _>>()?;
+    let mut scheme_paths: Vec<PathBuf> = vec![];
+    collect_paths(schemes_path, &glob_ignores, &mut scheme_paths)?;
+    scheme_paths.sort();
+
+    let diagnostics = lint_paths(&scheme_paths);
+
+    for diagnostic in &diagnostics {
+        println!("{diagnostic}");
+    }
+
+    if diagnostics.is_empty() {
+        if !is_quiet {
+            println!(
+                "✔ No problems found in {} scheme file(s)",
+                scheme_paths.len()
+            );
+        }
+
+        return Ok(());
+    }
+
+    let mut problem_paths: Vec<&PathBuf> = diagnostics.iter().map(|d| &d.path).collect();
+    problem_paths.dedup();
+
+    Err(anyhow!(
+        "Found {} problem(s) in {} of {} scheme file(s)",
+        diagnostics.len(),
+        problem_paths.len(),
+        scheme_paths.len()
+    ))
+}
+
+/// Lints a list of scheme file paths, including slug collisions between them.
+#[must_use]
+pub fn lint_paths(scheme_paths: &[PathBuf]) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = vec![];
+    let mut slugs: HashMap<(String, String), Vec<Diagnostic>> = HashMap::new();
+
+    for path in scheme_paths {
+        let mut file_diagnostics = lint_file(path);
+
+        // Only schemes which are otherwise valid take part in slug collision checks
+        if file_diagnostics.is_empty() {
+            match slug_location(path) {
+                Ok((system, slug, location)) => {
+                    slugs.entry((system, slug)).or_default().push(location);
+                }
+                Err(diagnostic) => file_diagnostics.push(diagnostic),
+            }
+        }
+
+        diagnostics.extend(file_diagnostics);
+    }
+
+    for ((system, slug), locations) in slugs {
+        if locations.len() < 2 {
+            continue;
+        }
+
+        for location in &locations {
+            let others: Vec<String> = locations
+                .iter()
+                .filter(|other| other.path != location.path)
+                .map(|other| other.path.display().to_string())
+                .collect();
+
+            diagnostics.push(Diagnostic {
+                code: "E116",
+                message: format!(
+                    "Duplicate {system} scheme slug \"{slug}\", also used by {}",
+                    others.join(", ")
+                ),
+                ..location.clone()
+            });
+        }
+    }
+
+    diagnostics.sort_by(|a, b| (&a.path, a.line, a.column).cmp(&(&b.path, b.line, b.column)));
+
+    diagnostics
+}
+
+/// Lints a single scheme file.
+#[allow(clippy::too_many_lines)]
+fn lint_file(path: &Path) -> Vec<Diagnostic> {
+    let diagnostic = |line: usize, column: usize, code: &'static str, message: String| Diagnostic {
+        path: path.to_path_buf(),
+        line,
+        column,
+        code,
+        message,
+    };
+
+    if let Err(err) = SchemeFile::new(path) {
+        return vec![diagnostic(
+            1,
+            1,
+            "E111",
+            err.to_string().replace("E111: ", ""),
+        )];
+    }
+
+    let source = match read_to_string(path) {
+        Ok(source) => source,
+        Err(err) => {
+            return vec![diagnostic(
+                1,
+                1,
+                "E112",
+                format!("Unable to read scheme file: {err}"),
+            )]
+        }
+    };
+    let root = match serde_yaml::from_str::<Value>(&source) {
+        Ok(Value::Mapping(root)) => root,
+        Ok(_) => {
+            return vec![diagnostic(
+                1,
+                1,
+                "E112",
+                "Scheme file is not a YAML mapping".to_string(),
+            )]
+        }
+        Err(err) => {
+            let (line, column) = err
+                .location()
+                .map_or((1, 1), |location| (location.line(), location.column()));
+
+            return vec![diagnostic(
+                line,
+                column,
+                "E112",
+                format!("Unable to parse scheme file: {err}"),
+            )];
+        }
+    };
+    let locate_key = |key_path: &[&str]| locate(&source, key_path);
+    let mut diagnostics: Vec<Diagnostic> = vec![];
+
+    let system_key_path: &[&str] = if root.contains_key("system") {
+        &["system"]
+    } else {
+        &["scheme", "system"]
+    };
+    let system_value = root.get("system").or_else(|| {
+        root.get("scheme")
+            .and_then(|scheme_meta| scheme_meta.get("system"))
+    });
+    let Some(system_str) = system_value.and_then(Value::as_str) else {
+        return vec![diagnostic(
+            1,
+            1,
+            "E111",
+            "Missing required field `system` (or `scheme.system` for tinted8)".to_string(),
+        )];
+    };
+    let Ok(system) = system_str.parse::<SchemeSystem>() else {
+        let (line, column) = locate_key(system_key_path);
+
+        return vec![diagnostic(
+            line,
+            column,
+            "E110",
+            format!("Unknown or unsupported scheme system \"{system_str}\""),
+        )];
+    };
+
+    if let Some(directory_system) = path
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.parse::<SchemeSystem>().ok())
+    {
+        if directory_system != system {
+            let (line, column) = locate_key(system_key_path);
+
+            diagnostics.push(diagnostic(
+                line,
+                column,
+                "E117",
+                format!("Scheme system is {system}, but the scheme is in a {directory_system} directory"),
+            ));
+        }
+    }
+
+    match system {
+        SchemeSystem::Base16 | SchemeSystem::Base24 => {
+            let required_palette_keys: &[&str] = if system == SchemeSystem::Base24 {
+                &REQUIRED_BASE24_PALETTE_KEYS
+            } else {
+                &REQUIRED_BASE16_PALETTE_KEYS
+            };
+
+            for field in ["name", "author"] {
+                if !root.contains_key(field) {
+                    diagnostics.push(diagnostic(
+                        1,
+                        1,
+                        "E111",
+                        format!("Missing required field `{field}`"),
+                    ));
+                }
+            }
+
+            lint_palette(
+                &root,
+                &["palette"],
+                required_palette_keys,
+                str::to_lowercase,
+                &locate_key,
+                &mut |line, column, code, message| {
+                    diagnostics.push(diagnostic(line, column, code, message));
+                },
+            );
+        }
+        SchemeSystem::Tinted8 => {
+            let scheme_meta = root.get("scheme");
+
+            if scheme_meta.and_then(|meta| meta.get("author")).is_none() {
+                let (line, column) = locate_key(&["scheme"]);
+
+                diagnostics.push(diagnostic(
+                    line,
+                    column,
+                    "E111",
+                    "Missing required field `scheme.author`".to_string(),
+                ));
+            }
+
+            if let Some(styling_spec) = scheme_meta
+                .and_then(|meta| meta.get("supports"))
+                .and_then(|supports| supports.get("styling-spec"))
+            {
+                let styling_spec = match styling_spec {
+                    Value::String(styling_spec) => styling_spec.clone(),
+                    other => serde_yaml::to_string(other)
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string(),
+                };
+                let is_supported = VersionReq::parse(&styling_spec).is_ok_and(|req| {
+                    Version::parse(SUPPORTED_STYLING_SPEC_VERSION)
+                        .is_ok_and(|version| req.matches(&version))
+                });
+
+                if !is_supported {
+                    let (line, column) = locate_key(&["scheme", "supports", "styling-spec"]);
+
+                    diagnostics.push(diagnostic(
+                            line,
+                            column,
+                            "E002",
+                            format!("Unsupported Tinted8 Styling Spec \"{styling_spec}\" (supported v{SUPPORTED_STYLING_SPEC_VERSION})"),
+                        ));
+                }
+            } else {
+                let (line, column) = locate_key(&["scheme"]);
+
+                diagnostics.push(diagnostic(
+                    line,
+                    column,
+                    "E111",
+                    "Missing required field `scheme.supports.styling-spec`".to_string(),
+                ));
+            }
+
+            lint_palette(
+                &root,
+                &["palette"],
+                &TINTED8_REQUIRED_PALETTE_KEYS,
+                ToString::to_string,
+                &locate_key,
+                &mut |line, column, code, message| {
+                    diagnostics.push(diagnostic(line, column, code, message));
+                },
+            );
+
+            for section in ["ui", "syntax"] {
+                if let Some(Value::Mapping(mapping)) = root.get(section) {
+                    lint_colors(
+                        mapping,
+                        &[section],
+                        &|color| ColorExpression::from_str(color).is_ok(),
+                        "color expression",
+                        &locate_key,
+                        &mut |line, column, code, message| {
+                            diagnostics.push(diagnostic(line, column, code, message));
+                        },
+                    );
+                }
+            }
+        }
+        _ => {}
+    }
+
+    // Anything the targeted checks above do not cover is reported as the builder would
+    if diagnostics.is_empty() {
+        if let Err(err) = SchemeFile::new(path).and_then(|scheme_file| scheme_file.get_scheme()) {
+            diagnostics.push(diagnostic(
+                1,
+                1,
+                "E112",
+                format!("Unable to parse scheme file: {err}"),
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+/// Checks a palette mapping for missing required keys and invalid colors.
+fn lint_palette(
+    root: &Mapping,
+    palette_key_path: &[&str],
+    required_keys: &[&str],
+    normalize_key: impl Fn(&str) -> String,
+    locate: &impl Fn(&[&str]) -> (usize, usize),
+    push: &mut impl FnMut(usize, usize, &'static str, String),
+) {
+    let Some(Value::Mapping(palette)) = root.get(palette_key_path[0]) else {
+        push(
+            1,
+            1,
+            "E111",
+            format!("Missing required field `{}`", palette_key_path.join(".")),
+        );
+
+        return;
+    };
+    let (palette_line, palette_column) = locate(palette_key_path);
+    let keys: Vec<String> = palette
+        .keys()
+        .filter_map(Value::as_str)
+        .map(&normalize_key)
+        .collect();
+
+    for required_key in required_keys {
+        if !keys.contains(&normalize_key(required_key)) {
+            push(
+                palette_line,
+                palette_column,
+                "E111",
+                format!("Missing required palette key `{required_key}`"),
+            );
+        }
+    }
+
+    lint_colors(
+        palette,
+        palette_key_path,
+        &|color| Color::new(color, None, None).is_ok(),
+        "color",
+        locate,
+        push,
+    );
+}
+
+/// Checks every (nested) value of a mapping with `is_valid`, reporting invalid values as
+/// `Invalid <description>`.
+fn lint_colors(
+    mapping: &Mapping,
+    key_path: &[&str],
+    is_valid: &impl Fn(&str) -> bool,
+    description: &str,
+    locate: &impl Fn(&[&str]) -> (usize, usize),
+    push: &mut impl FnMut(usize, usize, &'static str, String),
+) {
+    for (key, value) in mapping {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+        let mut value_key_path = key_path.to_vec();
+        value_key_path.push(key);
+
+        match value {
+            Value::Mapping(child) => {
+                lint_colors(child, &value_key_path, is_valid, description, locate, push);
+            }
+            Value::String(color) if is_valid(color) => {}
+            _ => {
+                let (line, column) = locate(&value_key_path);
+                let value_str = serde_yaml::to_string(value).unwrap_or_default();
+
+                push(
+                    line,
+                    column,
+                    "E115",
+                    format!(
+                        "Invalid {description} for `{}`: {}",
+                        value_key_path.join("."),
+                        value_str.trim()
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the scheme system, slug and slug location of a valid scheme file.
+fn slug_location(path: &Path) -> Result<(String, String, Diagnostic), Diagnostic> {
+    let diagnostic = |message: String| Diagnostic {
+        path: path.to_path_buf(),
+        line: 1,
+        column: 1,
+        code: "E112",
+        message,
+    };
+    let scheme = SchemeFile::new(path)
+        .and_then(|scheme_file| scheme_file.get_scheme())
+        .map_err(|err| diagnostic(format!("Unable to parse scheme file: {err}")))?;
+    let source = read_to_string(path)
+        .map_err(|err| diagnostic(format!("Unable to read scheme file: {err}")))?;
+    let system = scheme.get_scheme_system();
+    let (slug_key_path, name_key_path): (&[&str], &[&str]) = match system {
+        SchemeSystem::Tinted8 => (&["scheme", "slug"], &["scheme", "name"]),
+        _ => (&["slug"], &["name"]),
+    };
+    let (line, column) = find_key(&source, slug_key_path)
+        .or_else(|| find_key(&source, name_key_path))
+        .unwrap_or((1, 1));
+
+    Ok((
+        system.to_string(),
+        scheme.get_scheme_slug(),
+        Diagnostic {
+            line,
+            column,
+            ..diagnostic(String::new())
+        },
+    ))
+}
+
+/// Returns the 1-based line and column of a key in a YAML source, falling back to its closest
+/// located parent key or the start of the file.
+fn locate(source: &str, key_path: &[&str]) -> (usize, usize) {
+    (1..=key_path.len())
+        .rev()
+        .find_map(|len| find_key(source, &key_path[..len]))
+        .unwrap_or((1, 1))
+}
+
+/// Finds the 1-based line and column of a (nested) key in a YAML source.
+///
+/// Each key in `key_path` is searched for below the previous one, at a deeper indentation. This
+/// works on the source text rather than the parsed YAML, since parsed values carry no location.
+fn find_key(source: &str, key_path: &[&str]) -> Option<(usize, usize)> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut start = 0;
+    let mut parent_indent: Option<usize> = None;
+    let mut location = None;
+
+    for key in key_path {
+        let (line_index, indent) = lines
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take_while(|(index, line)| {
+                // Stop searching once the parent mapping has ended
+                *index == start
+                    || parent_indent.is_none_or(|parent_indent| {
+                        line.trim().is_empty()
+                            || line.trim_start().starts_with('#')
+                            || indentation(line) > parent_indent
+                    })
+            })
+            .find_map(|(index, line)| {
+                let indent = indentation(line);
+                let content = line.trim_start();
+                let is_deeper = parent_indent.is_none_or(|parent_indent| indent > parent_indent);
+                let is_key = [
+                    format!("{key}:"),
+                    format!("\"{key}\":"),
+                    format!("'{key}':"),
+                ]
+                .iter()
+                .any(|prefix| content.starts_with(prefix.as_str()));
+
+                (is_deeper && is_key).then_some((index, indent))
+            })?;
+
+        start = line_index + 1;
+        parent_indent = Some(indent);
+        location = Some((line_index + 1, indent + 1));
+    }
+
+    location
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Recursively collects file paths in a directory, skipping paths matching `ignores`.
+fn collect_paths(dirpath: &Path, ignores: &[Glob], paths: &mut Vec<PathBuf>) -> Result<()> {
+    for item in dirpath.read_dir()? {
+        let path = item?.path();
+
+        if ignores.iter().any(|glob| glob.is_match(path.as_path())) {
+            continue;
+        }
+
+        if path.is_dir() {
+            collect_paths(&path, ignores, paths)?;
+        } else if path.is_file() {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r##"scheme:
+  system: "tinted8"
+  supports:
+    styling-spec: "0.2.0"
+palette:
+  black: "#000000"
+ui:
+  global:
+    background:
+      normal: "#zzzzzz"
+  "selection.background": "#111111"
+"##;
+
+    #[test]
+    fn test_find_key_nested() {
+        assert_eq!(
+            find_key(SOURCE, &["scheme", "supports", "styling-spec"]),
+            Some((4, 5))
+        );
+        assert_eq!(
+            find_key(SOURCE, &["ui", "global", "background", "normal"]),
+            Some((10, 7))
+        );
+        assert_eq!(
+            find_key(SOURCE, &["ui", "selection.background"]),
+            Some((11, 3))
+        );
+    }
+
+    #[test]
+    fn test_find_key_does_not_leave_parent_mapping() {
+        assert_eq!(find_key(SOURCE, &["scheme", "black"]), None);
+        assert_eq!(locate(SOURCE, &["scheme", "black"]), (1, 1));
+    }
+}
@@ -0,0 +1,87 @@
+mod test_utils;
+
+use anyhow::Result;
+use std::fs;
+use test_utils::{run_command, unique_tmp_dir};
+
+/// Tests `audit --cvd` reports color pairs that become indistinguishable for every scheme
+#[test]
+fn test_operation_audit_cvd() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_audit_cvd")?;
+    let tinted8_scheme_path = tmp_dir.join("tinted8/gruvbox-dark.yaml");
+    fs::create_dir_all(tmp_dir.join("tinted8"))?;
+    fs::copy(
+        "./tests/fixtures/schemes/tinted8/gruvbox-dark.yaml",
+        &tinted8_scheme_path,
+    )?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "audit".to_string(),
+        "--cvd".to_string(),
+        tmp_dir.display().to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stdout.contains(&format!("{}\n", tinted8_scheme_path.display())),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stdout.contains(
+            "  deuteranopia: ui.status.info (#d77b21) vs ui.status.success (#98971a): ΔE 27.6 → 2.2\n"
+        ),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stdout.contains("color vision deficiency conflict(s) in 1 of 1 scheme file(s)"),
+        "stdout does not contain the expected output"
+    );
+    assert!(stderr.is_empty(), "stderr should be empty");
+
+    Ok(())
+}
+
+/// Tests `audit --cvd --min-delta-e` only reports pairs closer than the given distance
+#[test]
+fn test_operation_audit_cvd_min_delta_e() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_audit_cvd_min_delta_e")?;
+    fs::create_dir_all(tmp_dir.join("tinted8"))?;
+    fs::copy(
+        "./tests/fixtures/schemes/tinted8/gruvbox-dark.yaml",
+        tmp_dir.join("tinted8/gruvbox-dark.yaml"),
+    )?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "audit".to_string(),
+        "--cvd".to_string(),
+        "--min-delta-e=1".to_string(),
+        tmp_dir.display().to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stdout.contains("✔ No color vision deficiency conflicts found in 1 scheme file(s)"),
+        "stdout does not contain the expected output"
+    );
+    assert!(stderr.is_empty(), "stderr should be empty");
+
+    Ok(())
+}
@@ -506,6 +506,171 @@ fn test_operation_build_listbase16() -> Result<()> {
     Ok(())
 }
 
+/// Tests list templates receive `families` grouping light and dark schemes across systems
+#[test]
+fn test_operation_build_list_families() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_list_families")?;
+    let template_theme_path = tmp_dir.join("template");
+    let template_templates_path = template_theme_path.join("templates");
+    let schemes_path = tmp_dir.join("schemes");
+
+    fs::create_dir_all(&template_templates_path)?;
+    write_to_file(
+        template_templates_path.join("config.yaml"),
+        "families:\n  filename: families.md\n  supported-systems: [base16, base24]\n  options:\n    list: true\n",
+    )?;
+    write_to_file(
+        template_templates_path.join("families.mustache"),
+        "{{#families}}\n{{name}} ({{slug}}):{{#dark-schemes}} dark={{system}}-{{slug}}{{/dark-schemes}}{{#light-schemes}} light={{system}}-{{slug}}{{/light-schemes}}\n{{/families}}",
+    )?;
+    copy_dir_all("./tests/fixtures/schemes", &schemes_path)?;
+    run_command(&[
+        "derive-variant".to_string(),
+        schemes_path
+            .join("base16/silk-light.yaml")
+            .display()
+            .to_string(),
+        "--quiet".to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+    ])
+    .expect("Unable to run command");
+    let rendered_content = fs::read_to_string(template_theme_path.join("families.md"))?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert_eq!(
+        rendered_content,
+        "Dracula (dracula): dark=base24-dracula\nSilk (silk): dark=base16-silk-dark light=base16-silk-light\n"
+    );
+
+    Ok(())
+}
+
+/// Tests templates with `pairs: true` render one file per pair of dark and light schemes
+#[test]
+fn test_operation_build_pairs() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_pairs")?;
+    let template_theme_path = tmp_dir.join("template");
+    let template_templates_path = template_theme_path.join("templates");
+    let schemes_path = tmp_dir.join("schemes");
+
+    fs::create_dir_all(&template_templates_path)?;
+    write_to_file(
+        template_templates_path.join("config.yaml"),
+        "default:\n  filename: \"themes/{{ scheme-system }}-{{ scheme-slug }}.json\"\n  supported-systems: [base16]\n  pairs: true\n",
+    )?;
+    write_to_file(
+        template_templates_path.join("default.mustache"),
+        "{{pair.name}}: {{dark.scheme-name}} #{{dark.base00-hex}}, {{light.scheme-name}} #{{light.base00-hex}}",
+    )?;
+    copy_dir_all("./tests/fixtures/schemes", &schemes_path)?;
+    run_command(&[
+        "derive-variant".to_string(),
+        schemes_path
+            .join("base16/silk-light.yaml")
+            .display()
+            .to_string(),
+        "--quiet".to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+    ])
+    .expect("Unable to run command");
+    let rendered_content = fs::read_to_string(template_theme_path.join("themes/base16-silk.json"))?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert!(
+        stdout.contains("✔ Successfully generated scheme pair themes for \"default\""),
+        "stdout does not contain the expected output"
+    );
+    assert_eq!(
+        rendered_content,
+        "Silk: Silk Dark #090f0d, Silk Light #e9f1ef"
+    );
+    assert!(!template_theme_path
+        .join("themes/base16-silk-light.json")
+        .exists());
+
+    Ok(())
+}
+
+/// Tests a `W001` warning is printed when templates with `pairs: true` have no scheme pairs
+#[test]
+fn test_operation_build_pairs_without_pairs() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_pairs_without_pairs")?;
+    let template_theme_path = tmp_dir.join("template");
+    let template_templates_path = template_theme_path.join("templates");
+    let schemes_path = tmp_dir.join("schemes");
+
+    fs::create_dir_all(&template_templates_path)?;
+    write_to_file(
+        template_templates_path.join("config.yaml"),
+        "default:\n  filename: \"themes/{{ scheme-slug }}.json\"\n  supported-systems: [base24]\n  pairs: true\n",
+    )?;
+    write_to_file(
+        template_templates_path.join("default.mustache"),
+        "{{pair.name}}",
+    )?;
+    copy_dir_all("./tests/fixtures/schemes", &schemes_path)?;
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.contains("W001: No scheme pairs found for a template config entry \"default\""),
+        "stderr does not contain the expected output"
+    );
+    assert!(!template_theme_path.join("themes").exists());
+
+    Ok(())
+}
+
 #[test]
 fn test_operation_build_listtinted8() -> Result<()> {
     // -------
@@ -802,3 +967,465 @@ base16-template:
 
     Ok(())
 }
+
+/// Tests tinted8 schemes are converted for base16 templates when "convert-from" is configured
+#[test]
+fn test_operation_build_base16_convert_from_tinted8() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_base16_convert_from_tinted8")?;
+    let template_theme_path = tmp_dir.join("template");
+    let template_templates_path = template_theme_path.join("templates");
+    let template_config_path = template_templates_path.join("config.yaml");
+    let template_mustache_path = template_templates_path.join("base16-template.mustache");
+    let schemes_path = tmp_dir.join("schemes");
+    let themes_path = template_theme_path.join("output-themes");
+    let base16_rendered_theme_path = themes_path.join("base16-silk-light.md");
+    let converted_rendered_theme_path = themes_path.join("base16-gruvbox-dark.md");
+    let tinted8_scheme_content = fs::read_to_string(PathBuf::from(
+        "./tests/fixtures/schemes/tinted8/gruvbox-dark.yaml",
+    ))?;
+    let config_file_content = r"
+base16-template:
+  filename: output-themes/{{ scheme-system }}-{{ scheme-slug }}.md
+  convert-from: [tinted8]";
+    let (_, scheme_file_content, template_file_content, base16_template_rendered_content_fixture) =
+        setup("base16", "silk-light")?;
+
+    fs::create_dir_all(&template_templates_path)?;
+    fs::create_dir_all(&schemes_path)?;
+    write_to_file(&template_config_path, config_file_content)?;
+    write_to_file(&template_mustache_path, &template_file_content)?;
+    write_to_file(schemes_path.join("silk-light.yaml"), &scheme_file_content)?;
+    write_to_file(
+        schemes_path.join("gruvbox-dark.yaml"),
+        &tinted8_scheme_content,
+    )?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+    ])
+    .expect("Unable to run command");
+    let base16_rendered_content = fs::read_to_string(base16_rendered_theme_path)?;
+    let converted_rendered_content = fs::read_to_string(converted_rendered_theme_path)?;
+
+    // ------
+    // Assert
+    // ------
+    assert_eq!(
+        base16_rendered_content,
+        base16_template_rendered_content_fixture
+    );
+    assert!(
+        converted_rendered_content.contains("system: base16"),
+        "converted theme does not contain the expected system"
+    );
+    assert!(
+        converted_rendered_content.contains("base00: 282828"),
+        "converted theme does not contain the expected background"
+    );
+    assert!(
+        converted_rendered_content.contains("base08: cc241d"),
+        "converted theme does not contain the expected red"
+    );
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert!(
+        stdout.contains("✔ Successfully generated \"base16\" themes for \"base16-template\""),
+        "stdout does not contain the exptected output"
+    );
+
+    Ok(())
+}
+
+/// Tests schemes converted to the same slug are rendered once, from the first "convert-from" system
+#[test]
+fn test_operation_build_convert_from_deduplicates_slugs() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_convert_from_deduplicates_slugs")?;
+    let template_theme_path = tmp_dir.join("template");
+    let template_templates_path = template_theme_path.join("templates");
+    let schemes_path = tmp_dir.join("schemes");
+    let tinted8_scheme_content = fs::read_to_string(PathBuf::from(
+        "./tests/fixtures/schemes/tinted8/gruvbox-dark.yaml",
+    ))?;
+    let base24_scheme_content = fs::read_to_string(PathBuf::from(
+        "./tests/fixtures/schemes/base24/dracula.yaml",
+    ))?
+    .replace("name: \"Dracula\"", "name: \"Gruvbox Dark\"");
+
+    fs::create_dir_all(&template_templates_path)?;
+    fs::create_dir_all(&schemes_path)?;
+    write_to_file(
+        template_templates_path.join("config.yaml"),
+        "base16-template:\n  filename: output-themes/{{ scheme-slug }}.md\n  convert-from: [tinted8, base24]\n",
+    )?;
+    write_to_file(
+        template_templates_path.join("base16-template.mustache"),
+        "{{scheme-system}} {{base00-hex}}\n",
+    )?;
+    write_to_file(
+        schemes_path.join("gruvbox-dark.yaml"),
+        &tinted8_scheme_content,
+    )?;
+    write_to_file(
+        schemes_path.join("zz-gruvbox-dark.yaml"),
+        &base24_scheme_content,
+    )?;
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+    ])
+    .expect("Unable to run command");
+    let rendered_content =
+        fs::read_to_string(template_theme_path.join("output-themes/gruvbox-dark.md"))?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert_eq!(rendered_content, "base16 282828\n");
+
+    Ok(())
+}
+
+/// Tests list templates include the schemes converted with "convert-from"
+#[test]
+fn test_operation_build_list_convert_from() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_list_convert_from")?;
+    let template_theme_path = tmp_dir.join("template");
+    let template_templates_path = template_theme_path.join("templates");
+    let schemes_path = tmp_dir.join("schemes");
+    let tinted8_scheme_content = fs::read_to_string(PathBuf::from(
+        "./tests/fixtures/schemes/tinted8/gruvbox-dark.yaml",
+    ))?;
+    let (_, scheme_file_content, _, _) = setup("base16", "silk-light")?;
+
+    fs::create_dir_all(&template_templates_path)?;
+    fs::create_dir_all(&schemes_path)?;
+    write_to_file(
+        template_templates_path.join("config.yaml"),
+        "list:\n  filename: list.md\n  convert-from: [tinted8]\n  options:\n    list: true\n",
+    )?;
+    write_to_file(
+        template_templates_path.join("list.mustache"),
+        "{{#schemes}}{{system}}-{{slug}}\n{{/schemes}}",
+    )?;
+    write_to_file(schemes_path.join("silk-light.yaml"), &scheme_file_content)?;
+    write_to_file(
+        schemes_path.join("gruvbox-dark.yaml"),
+        &tinted8_scheme_content,
+    )?;
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+    ])
+    .expect("Unable to run command");
+    let rendered_content = fs::read_to_string(template_theme_path.join("list.md"))?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert_eq!(rendered_content, "base16-silk-light\nbase16-gruvbox-dark\n");
+
+    Ok(())
+}
+
+/// Tests `min-contrast` in the template config fails the build for low contrast schemes
+#[test]
+fn test_operation_build_min_contrast_config() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_min_contrast_config")?;
+    let template_theme_path = tmp_dir.join("template");
+    let template_templates_path = template_theme_path.join("templates");
+    let schemes_path = tmp_dir.join("schemes");
+    let rendered_theme_path = template_theme_path.join("output-themes/base16-silk-light.md");
+    let config_file_content = r"
+base16-template:
+  filename: output-themes/{{ scheme-system }}-{{ scheme-slug }}.md
+  min-contrast: 8";
+    let (_, scheme_file_content, template_file_content, _) = setup("base16", "silk-light")?;
+
+    fs::create_dir_all(&template_templates_path)?;
+    fs::create_dir_all(&schemes_path)?;
+    write_to_file(
+        template_templates_path.join("config.yaml"),
+        config_file_content,
+    )?;
+    write_to_file(
+        template_templates_path.join("base16-template.mustache"),
+        &template_file_content,
+    )?;
+    write_to_file(schemes_path.join("silk-light.yaml"), &scheme_file_content)?;
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.contains(&format!(
+            "E401: Scheme \"silk-light\" ({}) is below the minimum contrast ratio 8:1 for \"base16-template\":",
+            schemes_path.join("silk-light.yaml").display()
+        )),
+        "stderr does not contain the expected output"
+    );
+    assert!(
+        stderr.contains("  base05 (#385156) on base00 (#e9f1ef): 7.38:1, APCA Lc 79.7"),
+        "stderr does not contain the expected output"
+    );
+    assert!(
+        !rendered_theme_path.exists(),
+        "theme should not be generated"
+    );
+
+    Ok(())
+}
+
+/// Tests `--min-contrast-warn` reports low contrast schemes as warnings and still builds them
+#[test]
+fn test_operation_build_min_contrast_warn_flag() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_min_contrast_warn_flag")?;
+    let template_theme_path = tmp_dir.join("template");
+    let template_templates_path = template_theme_path.join("templates");
+    let schemes_path = tmp_dir.join("schemes");
+    let rendered_theme_path = template_theme_path.join("output-themes/base16-silk-light.md");
+    let config_file_content = r"
+base16-template:
+  filename: output-themes/{{ scheme-system }}-{{ scheme-slug }}.md
+  min-contrast: 4.5";
+    let (_, scheme_file_content, template_file_content, template_rendered_content_fixture) =
+        setup("base16", "silk-light")?;
+
+    fs::create_dir_all(&template_templates_path)?;
+    fs::create_dir_all(&schemes_path)?;
+    write_to_file(
+        template_templates_path.join("config.yaml"),
+        config_file_content,
+    )?;
+    write_to_file(
+        template_templates_path.join("base16-template.mustache"),
+        &template_file_content,
+    )?;
+    write_to_file(schemes_path.join("silk-light.yaml"), &scheme_file_content)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+        "--min-contrast=8".to_string(),
+        "--min-contrast-warn".to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.contains("W002: Scheme \"silk-light\""),
+        "stderr does not contain the expected output"
+    );
+    assert!(
+        stdout.contains("✔ Successfully generated \"base16\" themes for \"base16-template\""),
+        "stdout does not contain the expected output"
+    );
+    assert_eq!(
+        fs::read_to_string(rendered_theme_path)?,
+        template_rendered_content_fixture
+    );
+
+    Ok(())
+}
+
+/// Tests `min-contrast` in the template config is rejected outside of the WCAG contrast ratios
+#[test]
+fn test_operation_build_min_contrast_config_out_of_range() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_min_contrast_config_out_of_range")?;
+    let template_theme_path = tmp_dir.join("template");
+    let template_templates_path = template_theme_path.join("templates");
+    let schemes_path = tmp_dir.join("schemes");
+    let config_file_content = r"
+base16-template:
+  filename: output-themes/{{ scheme-system }}-{{ scheme-slug }}.md
+  min-contrast: 45";
+    let (_, scheme_file_content, template_file_content, _) = setup("base16", "silk-light")?;
+
+    fs::create_dir_all(&template_templates_path)?;
+    fs::create_dir_all(&schemes_path)?;
+    write_to_file(
+        template_templates_path.join("config.yaml"),
+        config_file_content,
+    )?;
+    write_to_file(
+        template_templates_path.join("base16-template.mustache"),
+        &template_file_content,
+    )?;
+    write_to_file(schemes_path.join("silk-light.yaml"), &scheme_file_content)?;
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.contains("E305: Template config missing or invalid")
+            && stderr
+                .contains("min-contrast must be a WCAG contrast ratio between 1 and 21, got 45"),
+        "stderr does not contain the expected output"
+    );
+
+    Ok(())
+}
+
+/// Tests `--min-contrast` is rejected outside of the WCAG contrast ratios
+#[test]
+fn test_operation_build_min_contrast_flag_out_of_range() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_min_contrast_flag_out_of_range")?;
+    let template_theme_path = tmp_dir.join("template");
+    let schemes_path = tmp_dir.join("schemes");
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+        "--min-contrast=0.5".to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.contains("min-contrast must be a WCAG contrast ratio between 1 and 21, got 0.5"),
+        "stderr does not contain the expected output"
+    );
+
+    Ok(())
+}
+
+/// Tests `derivation` in the template config re-derives tinted8 palette variants
+#[test]
+fn test_operation_build_tinted8_derivation_config() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_build_tinted8_derivation_config")?;
+    let template_theme_path = tmp_dir.join("template");
+    let template_templates_path = template_theme_path.join("templates");
+    let schemes_path = tmp_dir.join("schemes");
+    let rendered_theme_path = template_theme_path.join("output-themes/tinted8-gruvbox-dark.md");
+    let config_file_content = r"
+tinted8-template:
+  filename: output-themes/{{ scheme.system }}-{{ scheme.slug }}.md
+  supported-systems: [tinted8]
+  supports:
+    tinted8-builder: 0.2.0
+    tinted8-styling: 0.2.0
+  derivation: oklch";
+    let template_file_content =
+        "{{palette.blue.normal.hex}} {{palette.blue.bright.hex}} {{palette.blue.dim.hex}}";
+    let scheme_file_content = fs::read_to_string(PathBuf::from(
+        "./tests/fixtures/schemes/tinted8/gruvbox-dark.yaml",
+    ))?;
+
+    fs::create_dir_all(&template_templates_path)?;
+    fs::create_dir_all(&schemes_path)?;
+    write_to_file(
+        template_templates_path.join("config.yaml"),
+        config_file_content,
+    )?;
+    write_to_file(
+        template_templates_path.join("tinted8-template.mustache"),
+        template_file_content,
+    )?;
+    write_to_file(schemes_path.join("gruvbox-dark.yaml"), &scheme_file_content)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "build".to_string(),
+        template_theme_path.display().to_string(),
+        format!("--schemes-dir={}", schemes_path.display()),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(stderr.is_empty(), "stderr is not empty: {stderr}");
+    assert!(
+        stdout.contains("✔ Successfully generated \"tinted8\" themes for \"tinted8-template\""),
+        "stdout does not contain the expected output"
+    );
+    // HSL derivation gives 5aacb0 and 2f5e60
+    assert_eq!(
+        fs::read_to_string(rendered_theme_path)?,
+        "458588 5d9da0 2c6d70"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+mod test_utils;
+
+use anyhow::Result;
+use std::fs;
+use std::path::PathBuf;
+use test_utils::{run_command, unique_tmp_dir};
+use tinted_builder::{base16, base24, tinted8};
+
+/// Tests base16 schemes are upconverted to base24 and written to the `--out` file
+#[test]
+fn test_operation_convert_base16_to_base24() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_convert_base16_to_base24")?;
+    let scheme_path = PathBuf::from("./tests/fixtures/schemes/base16/silk-light.yaml");
+    let out_path = tmp_dir.join("schemes/silk-light.yaml");
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "convert".to_string(),
+        scheme_path.display().to_string(),
+        "--to=base24".to_string(),
+        format!("--out={}", out_path.display()),
+    ])
+    .expect("Unable to run command");
+    let scheme: base24::Scheme = serde_yaml::from_str(&fs::read_to_string(&out_path)?)?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert!(
+        stdout.contains("✔ Successfully converted"),
+        "stdout does not contain the expected output"
+    );
+    assert_eq!(scheme.name, "Silk Light");
+    assert_eq!(scheme.palette.len(), 24);
+    assert_eq!(scheme.palette["base00"].to_hex(), "e9f1ef");
+    assert_eq!(scheme.palette["base12"].to_hex(), "cf432e");
+
+    Ok(())
+}
+
+/// Tests base24 schemes are downconverted to base16 and printed to stdout without `--out`
+#[test]
+fn test_operation_convert_base24_to_base16_stdout() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let scheme_path = PathBuf::from("./tests/fixtures/schemes/base24/dracula.yaml");
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "convert".to_string(),
+        scheme_path.display().to_string(),
+        "--to=base16".to_string(),
+    ])
+    .expect("Unable to run command");
+    let scheme: base16::Scheme = serde_yaml::from_str(&stdout)?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert_eq!(scheme.name, "Dracula");
+    assert_eq!(scheme.palette.len(), 16);
+    assert_eq!(scheme.palette["base00"].to_hex(), "21222c");
+
+    Ok(())
+}
+
+/// Tests base16 schemes are converted to tinted8 schemes that can be read back
+#[test]
+fn test_operation_convert_base16_to_tinted8() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let scheme_path = PathBuf::from("./tests/fixtures/schemes/base16/silk-light.yaml");
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "convert".to_string(),
+        scheme_path.display().to_string(),
+        "--to=tinted8".to_string(),
+    ])
+    .expect("Unable to run command");
+    let scheme: tinted8::Scheme = serde_yaml::from_str(&stdout)?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert_eq!(scheme.scheme.name, "Silk Light");
+    assert_eq!(scheme.palette.white_normal.to_hex(), "e9f1ef");
+    assert_eq!(scheme.palette.orange_normal.to_hex(), "d27f46");
+    assert_eq!(scheme.ui.global.background.normal.to_hex(), "e9f1ef");
+    // `base07` (`#d2faff`) is lighter than `base00`, so `base06` is used as the foreground
+    assert_eq!(scheme.palette.black_normal.to_hex(), "0e3c46");
+    assert!(
+        scheme
+            .ui
+            .global
+            .foreground
+            .normal
+            .contrast_ratio(&scheme.ui.global.background.normal)
+            > 7.0
+    );
+
+    Ok(())
+}
+
+/// Tests tinted8 schemes are projected onto base16
+#[test]
+fn test_operation_convert_tinted8_to_base16() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let scheme_path = PathBuf::from("./tests/fixtures/schemes/tinted8/gruvbox-dark.yaml");
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "convert".to_string(),
+        scheme_path.display().to_string(),
+        "--to=base16".to_string(),
+    ])
+    .expect("Unable to run command");
+    let scheme: base16::Scheme = serde_yaml::from_str(&stdout)?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert_eq!(scheme.slug, "gruvbox-dark");
+    assert_eq!(scheme.palette["base00"].to_hex(), "282828");
+    assert_eq!(scheme.palette["base08"].to_hex(), "cc241d");
+
+    Ok(())
+}
+
+/// Tests an invalid scheme file extension returns E111
+#[test]
+fn test_operation_convert_invalid_extension() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_convert_invalid_extension")?;
+    let scheme_path = tmp_dir.join("scheme.json");
+    fs::create_dir_all(&tmp_dir)?;
+    fs::write(&scheme_path, "{}")?;
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "convert".to_string(),
+        scheme_path.display().to_string(),
+        "--to=base24".to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.contains("E111: Invalid scheme file extension"),
+        "stderr does not contain the expected output"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+mod test_utils;
+
+use anyhow::Result;
+use std::fs;
+use std::path::PathBuf;
+use test_utils::{run_command, unique_tmp_dir};
+use tinted_builder::{base16, tinted8, SchemeVariant};
+
+/// Tests the derived variant is written next to the scheme file as `<slug>.yaml` without `--out`
+#[test]
+fn test_operation_derive_variant_base16_next_to_scheme() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_derive_variant_base16_next_to_scheme")?;
+    let scheme_path = tmp_dir.join("silk-light.yaml");
+    let out_path = tmp_dir.join("silk-dark.yaml");
+    fs::create_dir_all(&tmp_dir)?;
+    fs::copy(
+        "./tests/fixtures/schemes/base16/silk-light.yaml",
+        &scheme_path,
+    )?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "derive-variant".to_string(),
+        scheme_path.display().to_string(),
+    ])
+    .expect("Unable to run command");
+    let scheme: base16::Scheme = serde_yaml::from_str(&fs::read_to_string(&out_path)?)?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert!(
+        stdout.contains("✔ Successfully derived dark scheme \"Silk Dark\""),
+        "stdout does not contain the expected output"
+    );
+    assert_eq!(scheme.variant, SchemeVariant::Dark);
+    assert_eq!(scheme.slug, "silk-dark");
+    assert!(scheme.palette["base05"].contrast_ratio(&scheme.palette["base00"]) > 4.5);
+
+    Ok(())
+}
+
+/// Tests tinted8 schemes are inverted with `black` and `white` swapped and written to `--out`
+#[test]
+fn test_operation_derive_variant_tinted8() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_derive_variant_tinted8")?;
+    let scheme_path = PathBuf::from("./tests/fixtures/schemes/tinted8/gruvbox-dark.yaml");
+    let out_path = tmp_dir.join("schemes/gruvbox-light.yaml");
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "derive-variant".to_string(),
+        scheme_path.display().to_string(),
+        format!("--out={}", out_path.display()),
+        "--quiet".to_string(),
+    ])
+    .expect("Unable to run command");
+    let scheme: tinted8::Scheme = serde_yaml::from_str(&fs::read_to_string(&out_path)?)?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty() && stdout.is_empty(),
+        "stdout or stderr is not empty"
+    );
+    assert_eq!(scheme.variant, SchemeVariant::Light);
+    assert_eq!(scheme.scheme.name, "Gruvbox Light");
+    assert_eq!(scheme.scheme.slug, "gruvbox-light");
+    assert_eq!(
+        scheme.ui.global.background.normal.to_hex(),
+        scheme.palette.white_normal.to_hex()
+    );
+    assert!(
+        scheme
+            .palette
+            .white_normal
+            .contrast_ratio(&scheme.palette.black_normal)
+            > 7.0
+    );
+
+    Ok(())
+}
+
+/// Tests an `E119` error is returned instead of overwriting an existing scheme file
+#[test]
+fn test_operation_derive_variant_existing_file() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_derive_variant_existing_file")?;
+    let scheme_path = tmp_dir.join("silk-light.yaml");
+    let existing_path = tmp_dir.join("silk-dark.yaml");
+    fs::create_dir_all(&tmp_dir)?;
+    fs::copy(
+        "./tests/fixtures/schemes/base16/silk-light.yaml",
+        &scheme_path,
+    )?;
+    fs::write(&existing_path, "hand-made")?;
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "derive-variant".to_string(),
+        scheme_path.display().to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.contains("E119: Unable to derive the dark variant of")
+            && stderr.contains("silk-dark.yaml\" already exists"),
+        "stderr does not contain the expected output"
+    );
+    assert_eq!(fs::read_to_string(&existing_path)?, "hand-made");
+
+    Ok(())
+}
@@ -0,0 +1,379 @@
+mod test_utils;
+
+use anyhow::Result;
+use std::fs;
+use test_utils::{run_command, unique_tmp_dir, write_to_file};
+
+const UNFORMATTED_SCHEME: &str = r##"name: Silk Light
+system: base16
+author: "Gabriel Fontes"
+variant: light
+palette:
+  base0F: "865369"
+  base00: "#E9F1EF"
+  base01: CCD4D3
+  base02: "90B7B6"
+  base03: "5C787B"
+  base04: "4B5B5F"
+  base05: "385156"
+  base06: "0e3c46"
+  base07: "D2FAFF"
+  base08: "CF432E"
+  base09: "D27F46"
+  base0a: "CFAD25"
+  base0b: "6CA38C"
+  base0c: "329CA2"
+  base0d: "39AAC9"
+  base0E: "FFF"
+"##;
+
+const FORMATTED_SCHEME: &str = r"system: base16
+name: Silk Light
+slug: silk-light
+author: Gabriel Fontes
+variant: light
+palette:
+  base00: '#e9f1ef'
+  base01: '#ccd4d3'
+  base02: '#90b7b6'
+  base03: '#5c787b'
+  base04: '#4b5b5f'
+  base05: '#385156'
+  base06: '#0e3c46'
+  base07: '#d2faff'
+  base08: '#cf432e'
+  base09: '#d27f46'
+  base0A: '#cfad25'
+  base0B: '#6ca38c'
+  base0C: '#329ca2'
+  base0D: '#39aac9'
+  base0E: '#ffffff'
+  base0F: '#865369'
+";
+
+const UNFORMATTED_TINTED8_SCHEME: &str = r"scheme:
+  system: tinted8
+  supports:
+    styling-spec: 0.2.0
+  author: Jane Doe
+  name: Named Colors
+palette:
+  black: oklch(20% 0.02 260)
+  red: hsl(0 100% 50%)
+  green: '#00ff00'
+  yellow: yellow
+  blue: '#0000ff'
+  magenta: '#ff00ff'
+  cyan: '#00ffff'
+  white: white
+variant: dark
+";
+
+const FORMATTED_TINTED8_SCHEME: &str = r"scheme:
+  system: tinted8
+  supports:
+    styling-spec: 0.2.0
+  name: Named Colors
+  slug: named-colors
+  author: Jane Doe
+variant: dark
+palette:
+  black: oklch(20% 0.02 260)
+  blue: '#0000ff'
+  cyan: '#00ffff'
+  green: '#00ff00'
+  magenta: '#ff00ff'
+  red: hsl(0 100% 50%)
+  white: white
+  yellow: yellow
+";
+
+const FORMATTED_TINTED8_EXPRESSIONS_SCHEME: &str = r"scheme:
+  system: tinted8
+  supports:
+    styling-spec: 0.2.0
+  name: Expressions
+  slug: expressions
+  author: Jane Doe
+variant: dark
+palette:
+  black: '#1e1e2e'
+  blue: '#89b4fa'
+  cyan: '#94e2d5'
+  green: '#a6e3a1'
+  magenta: '#cba6f7'
+  red: '#f38ba8'
+  white: '#cdd6f4'
+  yellow: '#f9e2af'
+syntax:
+  keyword: magenta_bright
+  string: gray.dim
+ui:
+  selection.background: blue.normal | mix(black.normal, 0.7) | lighten(0.05)
+";
+
+const FORMATTED_TINTED8_VARIANTS_SCHEME: &str = r"scheme:
+  system: tinted8
+  supports:
+    styling-spec: 0.2.0
+  name: Variants
+  slug: variants
+  author: Jane Doe
+variant: dark
+palette:
+  black: '#a5cd68'
+  black-bright: '#4d3c1a'
+  black-dim: '#ca264e'
+  blue: '#18b8ff'
+  blue-bright: '#25165e'
+  blue-dim: '#3031d0'
+  brown: '#bb3b93'
+  brown-bright: '#1db208'
+  brown-dim: '#6deceb'
+  cyan: '#1332a1'
+  cyan-bright: '#2c0146'
+  cyan-dim: '#de06ce'
+  gray: '#d61aa9'
+  gray-bright: '#23c417'
+  gray-dim: '#7b382e'
+  green: '#2e71ef'
+  green-bright: '#d95a94'
+  green-dim: '#1e43bb'
+  magenta: '#3f62f8'
+  magenta-bright: '#724c60'
+  magenta-dim: '#1fac61'
+  orange: '#cb19b4'
+  orange-bright: '#1963c5'
+  orange-dim: '#7131a3'
+  red: '#17d9af'
+  red-bright: '#442f7d'
+  red-dim: '#9447ab'
+  white: '#d69964'
+  white-bright: '#49dbcd'
+  white-dim: '#3c4f43'
+  yellow: '#9df154'
+  yellow-bright: '#5c882b'
+  yellow-dim: '#34c3b7'
+";
+
+/// Tests `fmt` rewrites scheme files in a directory in their canonical form
+#[test]
+fn test_operation_fmt_rewrites_scheme_files() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_fmt_rewrites_scheme_files")?;
+    let schemes_path = tmp_dir.join("schemes");
+    let scheme_path = schemes_path.join("silk-light.yaml");
+    fs::create_dir_all(&schemes_path)?;
+    write_to_file(&scheme_path, UNFORMATTED_SCHEME)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&["fmt".to_string(), schemes_path.display().to_string()])
+        .expect("Unable to run command");
+    let formatted_content = fs::read_to_string(&scheme_path)?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert!(
+        stdout.contains(&format!("Formatted {}", scheme_path.display())),
+        "stdout does not contain the expected output"
+    );
+    assert_eq!(formatted_content, FORMATTED_SCHEME);
+
+    Ok(())
+}
+
+/// Tests `fmt --check` prints a diff and errors without rewriting unformatted files
+#[test]
+fn test_operation_fmt_check_unformatted() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_fmt_check_unformatted")?;
+    let scheme_path = tmp_dir.join("silk-light.yaml");
+    fs::create_dir_all(&tmp_dir)?;
+    write_to_file(&scheme_path, UNFORMATTED_SCHEME)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "fmt".to_string(),
+        "--check".to_string(),
+        scheme_path.display().to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stdout.contains(&format!("--- {}", scheme_path.display())),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stdout.contains("-  base0a: \"CFAD25\"\n") && stdout.contains("+  base0A: '#cfad25'\n"),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stderr.contains("E114: 1 scheme file(s) are not formatted"),
+        "stderr does not contain the expected output"
+    );
+    assert_eq!(fs::read_to_string(&scheme_path)?, UNFORMATTED_SCHEME);
+
+    Ok(())
+}
+
+/// Tests `fmt --check` succeeds silently for formatted files
+#[test]
+fn test_operation_fmt_check_formatted() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_fmt_check_formatted")?;
+    let scheme_path = tmp_dir.join("silk-light.yaml");
+    fs::create_dir_all(&tmp_dir)?;
+    write_to_file(&scheme_path, FORMATTED_SCHEME)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "fmt".to_string(),
+        "--check".to_string(),
+        scheme_path.display().to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(stdout.is_empty(), "stdout should be empty");
+    assert!(stderr.is_empty(), "stderr should be empty");
+
+    Ok(())
+}
+
+/// Tests `fmt` keeps colors written as color functions or named colors instead of rewriting them
+/// as hex
+#[test]
+fn test_operation_fmt_keeps_color_functions_and_named_colors() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_fmt_keeps_color_functions_and_named_colors")?;
+    let base16_scheme_path = tmp_dir.join("silk-light.yaml");
+    let tinted8_scheme_path = tmp_dir.join("named-colors.yaml");
+    let base16_scheme_content = FORMATTED_SCHEME
+        .replace("base00: '#e9f1ef'", "base00: oklch(20% 0.02 260)")
+        .replace("base08: '#cf432e'", "base08: hsl(0 100% 50%)")
+        .replace("base0A: '#cfad25'", "base0A: yellow")
+        .replace("base0E: '#ffffff'", "base0E: white");
+    fs::create_dir_all(&tmp_dir)?;
+    write_to_file(&base16_scheme_path, &base16_scheme_content)?;
+    write_to_file(&tinted8_scheme_path, UNFORMATTED_TINTED8_SCHEME)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&["fmt".to_string(), tmp_dir.display().to_string()])
+        .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert!(
+        !stdout.contains(&format!("Formatted {}", base16_scheme_path.display())),
+        "stdout does not contain the expected output"
+    );
+    assert_eq!(
+        fs::read_to_string(&base16_scheme_path)?,
+        base16_scheme_content
+    );
+    assert_eq!(
+        fs::read_to_string(&tinted8_scheme_path)?,
+        FORMATTED_TINTED8_SCHEME
+    );
+
+    Ok(())
+}
+
+/// Tests `fmt` keeps Tinted8 color expressions as they were written
+#[test]
+fn test_operation_fmt_keeps_color_expressions() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_fmt_keeps_color_expressions")?;
+    let scheme_path = tmp_dir.join("expressions.yaml");
+    fs::create_dir_all(&tmp_dir)?;
+    write_to_file(&scheme_path, FORMATTED_TINTED8_EXPRESSIONS_SCHEME)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "fmt".to_string(),
+        "--check".to_string(),
+        scheme_path.display().to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(stdout.is_empty(), "stdout should be empty");
+    assert!(stderr.is_empty(), "stderr should be empty");
+    assert_eq!(
+        fs::read_to_string(&scheme_path)?,
+        FORMATTED_TINTED8_EXPRESSIONS_SCHEME
+    );
+
+    Ok(())
+}
+
+/// Tests `fmt` keeps every `dim` and `bright` palette value
+#[test]
+fn test_operation_fmt_keeps_every_palette_variant() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_fmt_keeps_every_palette_variant")?;
+    let scheme_path = tmp_dir.join("variants.yaml");
+    fs::create_dir_all(&tmp_dir)?;
+    write_to_file(&scheme_path, FORMATTED_TINTED8_VARIANTS_SCHEME)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "fmt".to_string(),
+        "--check".to_string(),
+        scheme_path.display().to_string(),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(stdout.is_empty(), "stdout should be empty");
+    assert!(stderr.is_empty(), "stderr should be empty");
+    assert_eq!(
+        fs::read_to_string(&scheme_path)?,
+        FORMATTED_TINTED8_VARIANTS_SCHEME
+    );
+
+    Ok(())
+}
@@ -0,0 +1,203 @@
+mod test_utils;
+
+use anyhow::Result;
+use image::{Rgb, RgbImage};
+use std::fs;
+use std::path::Path;
+use test_utils::{run_command, unique_tmp_dir};
+use tinted_builder::{base24, tinted8, Scheme, SchemeSystem, SchemeVariant};
+
+/// Writes a wallpaper-like PNG: a dark navy background with orange, green and blue stripes
+fn write_wallpaper(path: &Path) -> Result<()> {
+    let image = RgbImage::from_fn(64, 64, |x, y| match (x, y) {
+        (0..40, _) => Rgb([20, 24, 48]),
+        (_, 0..16) => Rgb([230, 90, 60]),
+        (_, 16..32) => Rgb([80, 180, 120]),
+        _ => Rgb([90, 140, 240]),
+    });
+
+    image.save(path)?;
+
+    Ok(())
+}
+
+/// Tests a tinted8 scheme is generated from an image and round-trips through `Scheme::from_yaml`
+#[test]
+fn test_operation_generate_from_image_tinted8() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_generate_from_image_tinted8")?;
+    let image_path = tmp_dir.join("wallpaper.png");
+    let out_path = tmp_dir.join("schemes/wallpaper.yaml");
+    fs::create_dir_all(&tmp_dir)?;
+    write_wallpaper(&image_path)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "generate".to_string(),
+        format!("--from-image={}", image_path.display()),
+        "--system=tinted8".to_string(),
+        "--name=Wallpaper".to_string(),
+        format!("--out={}", out_path.display()),
+    ])
+    .expect("Unable to run command");
+    let yaml = fs::read_to_string(&out_path)?;
+    let scheme: tinted8::Scheme = serde_yaml::from_str(&yaml)?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert!(
+        stdout.contains("✔ Successfully generated tinted8 scheme \"Wallpaper\""),
+        "stdout does not contain the expected output"
+    );
+    assert_eq!(scheme.scheme.slug, "wallpaper");
+    assert_eq!(scheme.variant, SchemeVariant::Dark);
+    assert!(
+        scheme
+            .palette
+            .black_normal
+            .contrast_ratio(&scheme.palette.white_normal)
+            > 7.0
+    );
+    assert_eq!(Scheme::from_yaml(&yaml)?.to_yaml_string()?, yaml);
+
+    Ok(())
+}
+
+/// Tests generated base16 schemes are printed to stdout and respect `--variant`
+#[test]
+fn test_operation_generate_from_image_stdout_light() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_generate_from_image_stdout_light")?;
+    fs::create_dir_all(&tmp_dir)?;
+    let image_path = tmp_dir.join("wallpaper.png");
+    write_wallpaper(&image_path)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "generate".to_string(),
+        format!("--from-image={}", image_path.display()),
+        "--variant=light".to_string(),
+    ])
+    .expect("Unable to run command");
+    let scheme = Scheme::from_yaml(&stdout)?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert_eq!(scheme.get_scheme_system(), SchemeSystem::Base16);
+    assert_eq!(scheme.get_scheme_name(), "wallpaper");
+    assert_eq!(scheme.get_scheme_variant(), SchemeVariant::Light);
+
+    Ok(())
+}
+
+/// Tests an `E118` error is returned when the image cannot be decoded
+#[test]
+fn test_operation_generate_from_image_invalid_image() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_generate_from_image_invalid_image")?;
+    fs::create_dir_all(&tmp_dir)?;
+    let image_path = tmp_dir.join("wallpaper.png");
+    fs::write(&image_path, "not a png")?;
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "generate".to_string(),
+        format!("--from-image={}", image_path.display()),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.contains("E118: Unable to read image"),
+        "stderr does not contain the expected output"
+    );
+
+    Ok(())
+}
+
+/// Tests a base24 scheme is generated from seed colors, keeping the seeds as they are
+#[test]
+fn test_operation_generate_from_seeds() -> Result<()> {
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&[
+        "generate".to_string(),
+        "--seed=#1e1e2e".to_string(),
+        "--seed=#89b4fa".to_string(),
+        "--variant=dark".to_string(),
+        "--system=base24".to_string(),
+    ])
+    .expect("Unable to run command");
+    let scheme: base24::Scheme = serde_yaml::from_str(&stdout)?;
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.is_empty(),
+        "stderr does not contain the expected output"
+    );
+    assert_eq!(scheme.name, "Generated");
+    assert_eq!(scheme.palette.len(), 24);
+    assert_eq!(scheme.palette["base00"].to_hex(), "1e1e2e");
+    assert_eq!(scheme.palette["base0D"].to_hex(), "89b4fa");
+
+    Ok(())
+}
+
+/// Tests an `E118` error is returned for invalid seed colors
+#[test]
+fn test_operation_generate_from_seeds_invalid_seed() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_generate_from_seeds_invalid_seed")?;
+    let out_path = tmp_dir.join("generated.yaml");
+
+    // ---
+    // Act
+    // ---
+    let (_, stderr) = run_command(&[
+        "generate".to_string(),
+        "--seed=#1e1e2e".to_string(),
+        "--seed=not-a-color".to_string(),
+        format!("--out={}", out_path.display()),
+    ])
+    .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stderr.contains("E118: Invalid seed color \"not-a-color\""),
+        "stderr does not contain the expected output"
+    );
+    assert!(!out_path.exists(), "a scheme file was written");
+
+    Ok(())
+}
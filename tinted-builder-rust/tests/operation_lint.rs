@@ -0,0 +1,308 @@
+mod test_utils;
+
+use anyhow::Result;
+use std::fs;
+use test_utils::{run_command, unique_tmp_dir, write_to_file};
+
+const SCHEME_SILK_LIGHT: &str = r##"system: "base16"
+name: "Silk Light"
+author: "Gabriel Fontes"
+variant: "light"
+palette:
+  base00: "#e9f1ef"
+  base01: "#ccd4d3"
+  base02: "#90b7b6"
+  base03: "#5c787b"
+  base04: "#4b5b5f"
+  base05: "#385156"
+  base06: "#0e3c46"
+  base07: "#d2faff"
+  base08: "#cf432e"
+  base09: "#d27f46"
+  base0A: "#cfad25"
+  base0B: "#6ca38c"
+  base0C: "#329ca2"
+  base0D: "#39aac9"
+  base0E: "#6e6582"
+  base0F: "#865369"
+"##;
+
+const SCHEME_INVALID: &str = r##"system: "base16"
+name: "Invalid"
+author: "Gabriel Fontes"
+variant: "light"
+palette:
+  base00: "#e9f1ef"
+  base01: "#ccd4d3"
+  base02: "#90b7b6"
+  base03: "#5c787z"
+  base04: "#4b5b5f"
+  base05: "#385156"
+  base06: "#0e3c46"
+  base07: "#d2faff"
+  base08: "#cf432e"
+  base09: "#d27f46"
+  base0A: "#cfad25"
+  base0B: "#6ca38c"
+  base0C: "#329ca2"
+  base0D: "#39aac9"
+  base0E: "#6e6582"
+"##;
+
+/// Tests `lint` reports every problem in a schemes directory with its location
+#[test]
+fn test_operation_lint_reports_problems() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_lint_reports_problems")?;
+    let base16_path = tmp_dir.join("base16");
+    let base24_path = tmp_dir.join("base24");
+    let invalid_path = base16_path.join("invalid.yaml");
+    let silk_light_path = base16_path.join("silk-light.yaml");
+    let silk_light_copy_path = base16_path.join("silk-light-copy.yaml");
+    let misplaced_path = base24_path.join("silk-light.yaml");
+    fs::create_dir_all(&base16_path)?;
+    fs::create_dir_all(&base24_path)?;
+    write_to_file(&invalid_path, SCHEME_INVALID)?;
+    write_to_file(&silk_light_path, SCHEME_SILK_LIGHT)?;
+    write_to_file(&silk_light_copy_path, SCHEME_SILK_LIGHT)?;
+    write_to_file(&misplaced_path, SCHEME_SILK_LIGHT)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&["lint".to_string(), tmp_dir.display().to_string()])
+        .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stdout.contains(&format!(
+            "{}:5:1: E111: Missing required palette key `base0F`",
+            invalid_path.display()
+        )),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stdout.contains(&format!(
+            "{}:9:3: E115: Invalid color for `palette.base03`: '#5c787z'",
+            invalid_path.display()
+        )),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stdout.contains(&format!(
+            "{}:2:1: E116: Duplicate base16 scheme slug \"silk-light\", also used by {}",
+            silk_light_path.display(),
+            silk_light_copy_path.display()
+        )),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stdout.contains(&format!(
+            "{}:1:1: E117: Scheme system is base16, but the scheme is in a base24 directory",
+            misplaced_path.display()
+        )),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stderr.contains("Found 5 problem(s) in 4 of 4 scheme file(s)"),
+        "stderr does not contain the expected output"
+    );
+
+    Ok(())
+}
+
+/// Tests `lint` reports YAML syntax errors at the location given by the parser
+#[test]
+fn test_operation_lint_reports_yaml_syntax_error() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_lint_reports_yaml_syntax_error")?;
+    let scheme_path = tmp_dir.join("broken.yaml");
+    fs::create_dir_all(&tmp_dir)?;
+    write_to_file(&scheme_path, "system: \"base16\"\npalette:\n  base00: [\n")?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&["lint".to_string(), tmp_dir.display().to_string()])
+        .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stdout.contains(&format!("{}:", scheme_path.display())) && stdout.contains(": E112: "),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stderr.contains("Found 1 problem(s) in 1 of 1 scheme file(s)"),
+        "stderr does not contain the expected output"
+    );
+
+    Ok(())
+}
+
+/// Tests `lint` reports palette keys that only differ in casing
+#[test]
+fn test_operation_lint_reports_colliding_palette_keys() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_lint_reports_colliding_palette_keys")?;
+    let base16_path = tmp_dir.join("base16");
+    let scheme_path = base16_path.join("silk-light.yaml");
+    fs::create_dir_all(&base16_path)?;
+    write_to_file(
+        &scheme_path,
+        &SCHEME_SILK_LIGHT.replace(
+            "  base0A: \"#cfad25\"\n",
+            "  base0A: \"#cfad25\"\n  base0a: \"#ff0000\"\n",
+        ),
+    )?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&["lint".to_string(), tmp_dir.display().to_string()])
+        .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stdout.contains(&format!("{}:", scheme_path.display()))
+            && stdout.contains(r#"palette keys "base0A" and "base0a" are both "base0A""#),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stderr.contains("Found 1 problem(s) in 1 of 1 scheme file(s)"),
+        "stderr does not contain the expected output"
+    );
+
+    Ok(())
+}
+
+/// Tests `lint` succeeds when no problems are found
+#[test]
+fn test_operation_lint_no_problems() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_lint_no_problems")?;
+    let base16_path = tmp_dir.join("base16");
+    fs::create_dir_all(&base16_path)?;
+    write_to_file(base16_path.join("silk-light.yaml"), SCHEME_SILK_LIGHT)?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&["lint".to_string(), tmp_dir.display().to_string()])
+        .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stdout.contains("✔ No problems found in 1 scheme file(s)"),
+        "stdout does not contain the expected output"
+    );
+    assert!(stderr.is_empty(), "stderr should be empty");
+
+    Ok(())
+}
+
+/// Tests `lint` accepts color functions in palettes
+#[test]
+fn test_operation_lint_accepts_color_functions() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_lint_accepts_color_functions")?;
+    let base16_path = tmp_dir.join("base16");
+    fs::create_dir_all(&base16_path)?;
+    write_to_file(
+        base16_path.join("silk-light.yaml"),
+        &SCHEME_SILK_LIGHT.replace(r##"base00: "#e9f1ef""##, r#"base00: "oklch(95% 0.01 180)""#),
+    )?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&["lint".to_string(), tmp_dir.display().to_string()])
+        .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stdout.contains("✔ No problems found in 1 scheme file(s)"),
+        "stdout does not contain the expected output"
+    );
+    assert!(stderr.is_empty(), "stderr should be empty");
+
+    Ok(())
+}
+
+/// Tests `lint` accepts Tinted8 color expressions and reports invalid ones
+#[test]
+fn test_operation_lint_tinted8_color_expressions() -> Result<()> {
+    // -------
+    // Arrange
+    // -------
+    let tmp_dir = unique_tmp_dir("operation_lint_tinted8_color_expressions")?;
+    let tinted8_path = tmp_dir.join("tinted8");
+    let scheme_path = tinted8_path.join("expressions.yaml");
+    fs::create_dir_all(&tinted8_path)?;
+    write_to_file(
+        &scheme_path,
+        r##"scheme:
+  system: "tinted8"
+  supports:
+    styling-spec: "0.2.0"
+  name: "Expressions"
+  author: "Test Author"
+variant: "dark"
+palette:
+  black: "#1a1b26"
+  red: "#f7768e"
+  green: "#9ece6a"
+  yellow: "#e0af68"
+  blue: "#7aa2f7"
+  magenta: "#bb9af7"
+  cyan: "#7dcfff"
+  white: "#c0caf5"
+ui:
+  selection.background: "blue.normal | mix(black.normal, 0.7)"
+syntax:
+  comment: "gray.dim | blur(2)"
+"##,
+    )?;
+
+    // ---
+    // Act
+    // ---
+    let (stdout, stderr) = run_command(&["lint".to_string(), tmp_dir.display().to_string()])
+        .expect("Unable to run command");
+
+    // ------
+    // Assert
+    // ------
+    assert!(
+        stdout.contains(&format!(
+            "{}:20:3: E115: Invalid color expression for `syntax.comment`: gray.dim | blur(2)",
+            scheme_path.display()
+        )),
+        "stdout does not contain the expected output"
+    );
+    assert!(
+        stderr.contains("Found 1 problem(s) in 1 of 1 scheme file(s)"),
+        "stderr does not contain the expected output"
+    );
+
+    Ok(())
+}
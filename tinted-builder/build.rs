@@ -414,7 +414,7 @@ fn generate_syntax_construction(
         if node.children.is_empty() {
             let _ = writeln!(
                 code,
-                "{indent}let {variant_name} = parse_or_inherit(&[{}], &get_palette_color(palette, &ColorType::from_str(\"{default_color}\")?, variant)?)?;",
+                "{indent}let {variant_name} = parse_or_inherit(&[{}], &get_palette_color(palette, &ColorType::from_str(\"{default_color}\")?, variant)?, palette)?;",
                 format_parent_chain(&basic_field, &parent_chain)
             );
         } else {
@@ -424,7 +424,7 @@ fn generate_syntax_construction(
             let _ = writeln!(code, "{indent}let {variant_name} = {struct_name} {{");
             let _ = writeln!(
                 code,
-                "{indent}    default: parse_or_inherit(&[{}], &get_palette_color(palette, &ColorType::from_str(\"{default_color}\")?, variant)?)?,",
+                "{indent}    default: parse_or_inherit(&[{}], &get_palette_color(palette, &ColorType::from_str(\"{default_color}\")?, variant)?, palette)?,",
                 format_parent_chain(&basic_field, &parent_chain)
             );
 
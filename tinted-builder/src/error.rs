@@ -23,6 +23,13 @@ pub enum TintedBuilderError {
     #[error("unable to deserialize yaml")]
     YamlDeserialize(#[from] serde_yaml::Error),
 
+    /// Error that occurs when YAML serialization fails.
+    ///
+    /// This variant wraps the `serde_yaml::Error` and is used when a scheme cannot be written out
+    /// as a YAML string.
+    #[error("unable to serialize yaml")]
+    YamlSerialize(#[source] serde_yaml::Error),
+
     /// Error that occurs during rendering using Ribboncurls.
     ///
     /// This variant wraps the `RibboncurlsError` and is used when an error is encountered while
@@ -37,11 +44,11 @@ pub enum TintedBuilderError {
     #[error("unable to convert string slice to integer with given base")]
     ColorRadix(#[from] std::num::ParseIntError),
 
-    /// Error indicating that a hex color input is not formatted correctly.
+    /// Error indicating that a color input is not formatted correctly.
     ///
-    /// This variant is used when a color string that is expected to be in hex format does not match
-    /// the expected format.
-    #[error("hex input is not formatted correctly")]
+    /// This variant is used when a color string is neither a hex color nor a supported color
+    /// function or named color.
+    #[error("color input is not formatted correctly")]
     HexInputFormat,
 
     /// Error indicating that an invalid scheme variant was provided.
@@ -69,12 +76,47 @@ pub enum TintedBuilderError {
     #[error("invalid color variant: {0}")]
     InvalidColorVariant(String),
 
+    /// Error indicating that an invalid color derivation was provided.
+    ///
+    /// This variant is used when an input string does not correspond to any valid derivation,
+    /// such as "hsl" or "oklch".
+    #[error("invalid derivation: {0}")]
+    InvalidDerivation(String),
+
     /// Error indicating that an invalid color type was provided.
     ///
     /// This variant is used when an input string does not correspond to a valid color type.
     #[error("invalid color type: {0}")]
     InvalidColorType(String),
 
+    /// Error indicating that a Tinted8 color expression could not be parsed.
+    ///
+    /// This variant is used when a `ui` or `syntax` value is neither a color, a palette reference
+    /// nor a palette reference followed by valid modifiers.
+    #[error("invalid color expression: {0}")]
+    InvalidColorExpression(String),
+
+    /// Error indicating that a template color filter could not be applied.
+    ///
+    /// This variant is used when a filter tag in a template has an unknown filter, invalid
+    /// arguments, an unknown output format or a value that is not a color.
+    #[error("invalid template filter: {0}")]
+    InvalidTemplateFilter(String),
+
+    /// Error indicating that a template color filter is used inside a mustache section.
+    ///
+    /// Filters resolve colors against the top-level template context, so they cannot be used
+    /// inside sections like `{{#palette}}` that change the context.
+    #[error("template filter {0} cannot be used inside the \"{1}\" section")]
+    TemplateFilterInSection(String, String),
+
+    /// Error indicating that an invalid color vision deficiency was provided.
+    ///
+    /// This variant is used when an input string does not correspond to any valid color vision
+    /// deficiency.
+    #[error("invalid color vision deficiency: {0}")]
+    InvalidColorVisionDeficiency(String),
+
     /// Error indicating a `Color` conversion problem
     ///
     /// This variant is used when a color is not able to convert from one color to another
@@ -92,6 +134,20 @@ pub enum TintedBuilderError {
         supported_derivations: String,
     },
 
+    /// Error indicating that a scheme could not be generated.
+    ///
+    /// This variant is used when the input of a generated scheme, such as the pixels of an
+    /// image, cannot produce a palette.
+    #[error("unable to generate scheme: {0}")]
+    SchemeGeneration(String),
+
+    /// Error indicating that a scheme color cannot be written so that it is read back unchanged.
+    ///
+    /// This variant is used when a scheme is serialized into its minimal input YAML and writing
+    /// a color explicitly still derives a different color.
+    #[error("unable to write {0} so that it is read back as {1}")]
+    UnreproducibleColor(String, String),
+
     /// Error indicating an inability to convert from type
     ///
     /// This variant is used when attempting to derive a color from another color
@@ -6,7 +6,10 @@ mod utils;
 
 pub use error::TintedBuilderError;
 pub use scheme::{
-    Color, ColorName, ColorType, ColorVariant, Scheme, SchemeSupports, SchemeSystem, SchemeVariant,
+    quantize_pixels, Color, ColorCluster, ColorDerivation, ColorName, ColorType, ColorVariant,
+    ColorVisionDeficiency, ContrastCheck, ContrastReport, CvdConflict, CvdReport, Derivation,
+    GenerateOptions, Scheme, SchemeFamily, SchemePair, SchemeSupports, SchemeSystem, SchemeVariant,
+    CVD_MIN_DELTA_E, WCAG_AAA_CONTRAST_RATIO, WCAG_AA_CONTRAST_RATIO,
 };
 pub use template::Template;
 
@@ -15,15 +18,24 @@ pub mod base16 {
     ///
     /// - `Scheme`: deserialize Base16 YAML into this type and wrap in `Scheme::Base16` to render
     ///   templates.
-    pub use crate::scheme::base16::Scheme;
+    /// - `REQUIRED_BASE16_PALETTE_KEYS`: the palette keys every Base16 scheme must define.
+    /// - `BASE16_ANSI16_PALETTE_KEYS`: the palette keys of ANSI colors `0`–`15`.
+    pub use crate::scheme::base16::{Scheme, REQUIRED_BASE16_PALETTE_KEYS};
+    pub use crate::scheme::BASE16_ANSI16_PALETTE_KEYS;
 }
 
 pub mod base24 {
     /// Base24 support for the library.
     ///
     /// - `Scheme`: deserialize Base24 YAML into this type and wrap in `Scheme::Base24` to render
-    ///   templates.
-    pub use crate::scheme::base24::Scheme;
+    ///   templates. Base16 schemes can be upconverted with `Scheme::try_from`.
+    /// - `BASE16_TO_BASE24_PALETTE_KEYS`: the slot rules used when upconverting from Base16.
+    /// - `REQUIRED_BASE24_PALETTE_KEYS`: the palette keys every Base24 scheme must define.
+    /// - `BASE24_ANSI16_PALETTE_KEYS`: the palette keys of ANSI colors `0`–`15`.
+    pub use crate::scheme::base24::{
+        Scheme, BASE16_TO_BASE24_PALETTE_KEYS, REQUIRED_BASE24_PALETTE_KEYS,
+    };
+    pub use crate::scheme::BASE24_ANSI16_PALETTE_KEYS;
 }
 
 pub mod tinted8 {
@@ -33,8 +45,12 @@ pub mod tinted8 {
     ///   templates with nested variables (`palette`, `ui`, `syntax`).
     /// - `SUPPORTED_STYLING_SPEC_VERSION` / `SUPPORTED_BUILDER_SPEC_VERSION`: version strings the
     ///   library targets; useful for compatibility checks.
+    /// - `BASE16_TO_TINTED8_PALETTE_KEYS` / `BASE24_TO_TINTED8_PALETTE_KEYS`: the slot rules used
+    ///   when converting Base16/Base24 schemes with `Scheme::try_from`.
+    /// - `ColorExpression`: a `ui`/`syntax` value referencing and modifying palette colors.
     pub use crate::scheme::tinted8::{
-        Palette, Scheme, SyntaxKey, UiKey, SUPPORTED_BUILDER_SPEC_VERSION,
+        ColorExpression, Palette, Scheme, SyntaxKey, UiKey, BASE16_TO_TINTED8_PALETTE_KEYS,
+        BASE24_TO_TINTED8_PALETTE_KEYS, SUPPORTED_BUILDER_SPEC_VERSION,
         SUPPORTED_STYLING_SPEC_VERSION,
     };
 }
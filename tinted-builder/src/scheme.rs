@@ -1,20 +1,43 @@
+mod ansi;
 pub mod base16;
 pub mod base24;
 mod color;
+mod color_input;
+mod contrast;
+mod cvd;
+mod derivation;
+mod family;
+mod generate;
+mod invert;
+mod pair;
 pub mod tinted8;
 
 use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 
+pub use crate::scheme::ansi::{
+    ansi16_from_keys, BASE16_ANSI16_PALETTE_KEYS, BASE24_ANSI16_PALETTE_KEYS,
+};
 pub use crate::scheme::color::Color;
 pub use crate::scheme::color::{ColorName, ColorType, ColorVariant};
+pub use crate::scheme::contrast::{
+    ContrastCheck, ContrastReport, WCAG_AAA_CONTRAST_RATIO, WCAG_AA_CONTRAST_RATIO,
+};
+pub use crate::scheme::cvd::{ColorVisionDeficiency, CvdConflict, CvdReport, CVD_MIN_DELTA_E};
+pub use crate::scheme::derivation::{ColorDerivation, Derivation};
+pub use crate::scheme::family::SchemeFamily;
+pub use crate::scheme::generate::{quantize_pixels, ColorCluster, GenerateOptions};
+pub use crate::scheme::pair::SchemePair;
 use crate::TintedBuilderError;
 
 /// Enum representing schemes for different scheme systems. This enum is non-exhaustive, meaning
 /// additional variants may be added in future versions without it being considered a breaking
 /// change.
+///
+/// It serializes as the scheme it wraps.
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
 pub enum Scheme {
     /// Base16 variant with `base16::Scheme` deserialized content.
     Base16(base16::Scheme),
@@ -65,6 +88,57 @@ impl Scheme {
         }
     }
 
+    /// Converts the scheme into the given scheme system.
+    ///
+    /// Converting into the scheme's own system returns a clone. Base16 schemes can be upconverted
+    /// to Base24 (see `base24::BASE16_TO_BASE24_PALETTE_KEYS` for the slot rules) and Base24
+    /// schemes downconverted to Base16 by dropping `base10`–`base17`. Base16 and Base24 schemes
+    /// can be converted to Tinted8 (see `tinted8::BASE16_TO_TINTED8_PALETTE_KEYS`), and Tinted8
+    /// schemes can be projected onto Base16 or Base24 (see `tinted8::Scheme::to_base16` and
+    /// `tinted8::Scheme::to_base24`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheme's colors cannot be mapped onto `system`.
+    pub fn to_system(&self, system: &SchemeSystem) -> Result<Self, TintedBuilderError> {
+        match (self, system) {
+            (Self::Base16(_), SchemeSystem::Base16)
+            | (Self::Base24(_), SchemeSystem::Base24)
+            | (Self::Tinted8(_), SchemeSystem::Tinted8) => Ok(self.clone()),
+            (Self::Base16(scheme), SchemeSystem::Base24) => {
+                Ok(Self::Base24(base24::Scheme::try_from(scheme.clone())?))
+            }
+            (Self::Base24(scheme), SchemeSystem::Base16) => {
+                Ok(Self::Base16(base16::Scheme::from(scheme.clone())))
+            }
+            (Self::Base16(scheme), SchemeSystem::Tinted8) => Ok(Self::Tinted8(Box::new(
+                tinted8::Scheme::try_from(scheme.clone())?,
+            ))),
+            (Self::Base24(scheme), SchemeSystem::Tinted8) => Ok(Self::Tinted8(Box::new(
+                tinted8::Scheme::try_from(scheme.clone())?,
+            ))),
+            (Self::Tinted8(scheme), SchemeSystem::Base16) => Ok(Self::Base16(scheme.to_base16())),
+            (Self::Tinted8(scheme), SchemeSystem::Base24) => Ok(Self::Base24(scheme.to_base24()?)),
+        }
+    }
+
+    /// Serializes the scheme into its canonical input scheme YAML string.
+    ///
+    /// The output can be read back with `Scheme::from_yaml` and yields the same colors. See
+    /// `base16::Scheme::to_yaml_string`, `base24::Scheme::to_yaml_string` and
+    /// `tinted8::Scheme::to_yaml_string` for the format of each scheme system.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheme cannot be serialized.
+    pub fn to_yaml_string(&self) -> Result<String, TintedBuilderError> {
+        match self {
+            Self::Base16(scheme) => scheme.to_yaml_string(),
+            Self::Base24(scheme) => scheme.to_yaml_string(),
+            Self::Tinted8(scheme) => scheme.to_yaml_string(),
+        }
+    }
+
     /// Returns the author of the scheme.
     #[must_use]
     pub fn get_scheme_author(&self) -> String {
@@ -230,6 +304,15 @@ impl SchemeVariant {
             Self::Light => "light",
         }
     }
+
+    /// Returns the opposite variant, `Light` for `Dark` and vice versa.
+    #[must_use]
+    pub const fn opposite(&self) -> Self {
+        match self {
+            Self::Dark => Self::Light,
+            Self::Light => Self::Dark,
+        }
+    }
 }
 
 impl fmt::Display for SchemeVariant {
@@ -0,0 +1,204 @@
+use palette::color_difference::EuclideanDistance;
+use palette::Oklab;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::scheme::tinted8::Palette;
+use crate::{Color, ColorName, ColorVariant, Scheme, SchemeSystem, TintedBuilderError};
+
+/// The Base16 palette keys of ANSI colors `0`–`15`, as used by base16-shell.
+pub const BASE16_ANSI16_PALETTE_KEYS: [&str; 16] = [
+    "base00", "base08", "base0B", "base0A", "base0D", "base0E", "base0C", "base05", "base03",
+    "base08", "base0B", "base0A", "base0D", "base0E", "base0C", "base07",
+];
+
+/// The Base24 palette keys of ANSI colors `0`–`15`, with the bright colors from
+/// `base12`–`base17`.
+pub const BASE24_ANSI16_PALETTE_KEYS: [&str; 16] = [
+    "base00", "base08", "base0B", "base0A", "base0D", "base0E", "base0C", "base05", "base03",
+    "base12", "base14", "base13", "base16", "base17", "base15", "base07",
+];
+
+/// The channel levels of the xterm-256 6×6×6 color cube (indices `16`–`231`).
+const XTERM_CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The first index of the xterm-256 grayscale ramp (indices `232`–`255`).
+const XTERM_GRAY_START: u8 = 232;
+
+/// The Oklab values of xterm-256 colors `16`–`255`, computed once on first use.
+static XTERM_OKLAB_TABLE: OnceLock<Vec<(u8, Oklab<f64>)>> = OnceLock::new();
+
+impl Color {
+    /// Returns the index of the nearest xterm-256 color.
+    ///
+    /// Only the 6×6×6 color cube (`16`–`231`) and the grayscale ramp (`232`–`255`) are
+    /// considered, since the first 16 colors are set by the terminal theme. Colors are compared
+    /// by their Oklab distance, see `Color::delta_e_ok`.
+    #[must_use]
+    pub fn to_ansi256(&self) -> u8 {
+        let oklab = self.oklab();
+
+        xterm_oklab_table()
+            .iter()
+            .map(|(index, xterm_oklab)| (*index, oklab.distance(*xterm_oklab)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(16, |(index, _)| index)
+    }
+
+    /// Returns the index of the nearest color in an ANSI-16 table, such as the one returned by
+    /// `Scheme::ansi16`.
+    ///
+    /// Colors are compared by their Oklab distance; on a tie the lower index wins.
+    #[must_use]
+    pub fn nearest_ansi16(&self, table: &[Self; 16]) -> u8 {
+        (0..16_u8)
+            .zip(table)
+            .map(|(index, color)| (index, self.delta_e_ok(color)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(0, |(index, _)| index)
+    }
+}
+
+impl Palette {
+    /// Returns the canonical ANSI-16 table of the palette: the `normal` variants of `black`,
+    /// `red`, `green`, `yellow`, `blue`, `magenta`, `cyan` and `white` as colors `0`–`7`, followed
+    /// by their `bright` variants as colors `8`–`15`.
+    #[must_use]
+    pub fn ansi16(&self) -> [Color; 16] {
+        [
+            &self.black_normal,
+            &self.red_normal,
+            &self.green_normal,
+            &self.yellow_normal,
+            &self.blue_normal,
+            &self.magenta_normal,
+            &self.cyan_normal,
+            &self.white_normal,
+            &self.black_bright,
+            &self.red_bright,
+            &self.green_bright,
+            &self.yellow_bright,
+            &self.blue_bright,
+            &self.magenta_bright,
+            &self.cyan_bright,
+            &self.white_bright,
+        ]
+        .map(Clone::clone)
+    }
+}
+
+impl Scheme {
+    /// Returns the ANSI-16 table of the scheme, colors `0`–`15` of a terminal.
+    ///
+    /// Base16 and Base24 schemes use `BASE16_ANSI16_PALETTE_KEYS` and
+    /// `BASE24_ANSI16_PALETTE_KEYS`, Tinted8 schemes use `tinted8::Palette::ansi16`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TintedBuilderError::SchemeMissingProperty` if a Base16/Base24 palette key is
+    /// missing.
+    pub fn ansi16(&self) -> Result<[Color; 16], TintedBuilderError> {
+        match self {
+            Self::Base16(scheme) => ansi16_from_keys(&scheme.palette, &scheme.system),
+            Self::Base24(scheme) => ansi16_from_keys(&scheme.palette, &scheme.system),
+            Self::Tinted8(scheme) => Ok(scheme.palette.ansi16()),
+        }
+    }
+}
+
+/// Returns the ANSI-16 table of a Base16/Base24 palette.
+pub fn ansi16_from_keys(
+    palette: &HashMap<String, Color>,
+    system: &SchemeSystem,
+) -> Result<[Color; 16], TintedBuilderError> {
+    let keys = match system {
+        SchemeSystem::Base24 => BASE24_ANSI16_PALETTE_KEYS,
+        _ => BASE16_ANSI16_PALETTE_KEYS,
+    };
+    let colors =
+        keys.iter()
+            .map(|key| {
+                palette.get(*key).cloned().ok_or_else(|| {
+                    TintedBuilderError::SchemeMissingProperty(format!("palette.{key}"))
+                })
+            })
+            .collect::<Result<Vec<Color>, TintedBuilderError>>()?;
+
+    colors
+        .try_into()
+        .map_err(|_| TintedBuilderError::SchemeMissingProperty("palette".to_string()))
+}
+
+/// Returns the Oklab value of every xterm-256 index in `16`–`255`.
+fn xterm_oklab_table() -> &'static [(u8, Oklab<f64>)] {
+    XTERM_OKLAB_TABLE.get_or_init(|| {
+        (16..=u8::MAX)
+            .map(|index| (index, xterm_color(index).oklab()))
+            .collect()
+    })
+}
+
+/// Returns the color of an xterm-256 index in `16`–`255`.
+fn xterm_color(index: u8) -> Color {
+    let rgb = if index >= XTERM_GRAY_START {
+        let level = 8 + (index - XTERM_GRAY_START) * 10;
+
+        (level, level, level)
+    } else {
+        let cube_index = usize::from(index - 16);
+
+        (
+            XTERM_CUBE_LEVELS[cube_index / 36],
+            XTERM_CUBE_LEVELS[(cube_index / 6) % 6],
+            XTERM_CUBE_LEVELS[cube_index % 6],
+        )
+    };
+
+    Color::from_rgb(rgb, ColorName::Other, ColorVariant::Normal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_colors_to_nearest_xterm_index() {
+        let to_ansi256 = |hex: &str| {
+            Color::new(hex, None, None)
+                .expect("unable to create color")
+                .to_ansi256()
+        };
+
+        assert_eq!(to_ansi256("#000000"), 16);
+        assert_eq!(to_ansi256("#ffffff"), 231);
+        assert_eq!(to_ansi256("#ff0000"), 196);
+        assert_eq!(to_ansi256("#5f87af"), 67);
+        assert_eq!(to_ansi256("#808080"), 244);
+        assert_eq!(to_ansi256("#eeeeee"), 255);
+    }
+
+    #[test]
+    fn builds_base24_ansi16_table() {
+        let palette: HashMap<String, Color> = (0..24_u8)
+            .map(|index| {
+                (
+                    format!("base{index:02X}"),
+                    Color::from_rgb(
+                        (index, index, index),
+                        ColorName::Other,
+                        ColorVariant::Normal,
+                    ),
+                )
+            })
+            .collect();
+        let table = ansi16_from_keys(&palette, &SchemeSystem::Base24).expect("missing palette key");
+        let err = ansi16_from_keys(&HashMap::new(), &SchemeSystem::Base24)
+            .expect_err("expected a missing palette key");
+
+        assert_eq!(table[1].to_hex(), "080808");
+        assert_eq!(table[9].to_hex(), "121212");
+        assert_eq!(table[15].to_hex(), "070707");
+        assert_eq!(table[9].nearest_ansi16(&table), 9);
+        assert_eq!(err.to_string(), "missing scheme property: palette.base00");
+    }
+}
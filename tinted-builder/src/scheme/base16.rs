@@ -1,4 +1,8 @@
-use crate::{utils::slugify, SchemeSystem, SchemeVariant};
+use crate::scheme::base24;
+use crate::{
+    utils::{normalize_palette_keys, slugify, write_palette_sources},
+    SchemeSystem, SchemeVariant, TintedBuilderError,
+};
 use serde::ser::{SerializeMap, SerializeStruct};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{collections::HashMap, fmt};
@@ -18,6 +22,7 @@ struct SchemeWrapper {
     pub(crate) author: String,
     pub(crate) description: Option<String>,
     pub(crate) variant: Option<SchemeVariant>,
+    pub(crate) pair: Option<String>,
     pub(crate) palette: HashMap<String, String>,
 }
 
@@ -34,9 +39,31 @@ pub struct Scheme {
     pub author: String,
     pub description: Option<String>,
     pub variant: SchemeVariant,
+    /// Slug of the scheme of the opposite variant this scheme is paired with, e.g. the light
+    /// variant of a dark scheme.
+    pub pair: Option<String>,
     pub palette: HashMap<String, Color>,
 }
 
+impl Scheme {
+    /// Serializes the scheme into its canonical Base16 scheme YAML string.
+    ///
+    /// Properties are written in specification order (`system`, `name`, `slug`, `author`,
+    /// `description`, `variant`, `pair` when set, `palette`), palette keys are sorted and every
+    /// color is written as `#rrggbb`, or as the color function or named color it was written as
+    /// (`Color::to_source_string`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `TintedBuilderError::YamlSerialize` if the scheme cannot be serialized.
+    pub fn to_yaml_string(&self) -> Result<String, TintedBuilderError> {
+        let mut value = serde_yaml::to_value(self).map_err(TintedBuilderError::YamlSerialize)?;
+        write_palette_sources(&mut value, &self.palette);
+
+        serde_yaml::to_string(&value).map_err(TintedBuilderError::YamlSerialize)
+    }
+}
+
 impl fmt::Display for Scheme {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "author: \"{}\"", self.author)?;
@@ -47,6 +74,9 @@ impl fmt::Display for Scheme {
         writeln!(f, "slug: \"{}\"", self.slug)?;
         writeln!(f, "system: \"{}\"", self.system)?;
         writeln!(f, "variant: \"{}\"", self.variant)?;
+        if let Some(ref pair) = self.pair {
+            writeln!(f, "pair: \"{pair}\"")?;
+        }
         writeln!(f, "palette:")?;
 
         let mut palette_vec: Vec<(String, Color)> = self
@@ -64,6 +94,29 @@ impl fmt::Display for Scheme {
     }
 }
 
+impl From<base24::Scheme> for Scheme {
+    /// Downconverts a Base24 scheme by keeping `base00`–`base0F` and dropping the Base24-only
+    /// `base10`–`base17` slots.
+    fn from(scheme: base24::Scheme) -> Self {
+        let palette = scheme
+            .palette
+            .into_iter()
+            .filter(|(key, _)| REQUIRED_BASE16_PALETTE_KEYS.contains(&key.as_str()))
+            .collect();
+
+        Self {
+            system: SchemeSystem::Base16,
+            name: scheme.name,
+            slug: scheme.slug,
+            author: scheme.author,
+            description: scheme.description,
+            variant: scheme.variant,
+            pair: scheme.pair,
+            palette,
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for Scheme {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -82,9 +135,10 @@ impl<'de> Deserialize<'de> for Scheme {
             )));
         }
 
+        let palette = normalize_palette_keys(wrapper.palette).map_err(serde::de::Error::custom)?;
         let contains_all_keys = REQUIRED_BASE16_PALETTE_KEYS
             .iter()
-            .all(|&key| wrapper.palette.contains_key(key));
+            .all(|&key| palette.contains_key(key));
 
         if !contains_all_keys {
             return Err(serde::de::Error::custom(
@@ -92,8 +146,7 @@ impl<'de> Deserialize<'de> for Scheme {
             ));
         }
 
-        let palette_result: Result<HashMap<String, Color>, _> = wrapper
-            .palette
+        let palette_result: Result<HashMap<String, Color>, _> = palette
             .into_iter()
             .map(|(key, value)| {
                 Color::new(&value, None, None)
@@ -109,6 +162,7 @@ impl<'de> Deserialize<'de> for Scheme {
             author: wrapper.author,
             description: wrapper.description,
             variant,
+            pair: wrapper.pair.map(|pair| slugify(&pair)),
             palette: palette_result?,
         })
     }
@@ -119,7 +173,7 @@ impl Serialize for Scheme {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("Scheme", 7)?;
+        let mut state = serializer.serialize_struct("Scheme", 8)?;
         state.serialize_field("system", &self.system)?;
         state.serialize_field("name", &self.name)?;
         state.serialize_field("slug", &self.slug)?;
@@ -128,6 +182,9 @@ impl Serialize for Scheme {
             state.serialize_field("description", description)?;
         }
         state.serialize_field("variant", &self.variant)?;
+        if let Some(pair) = &self.pair {
+            state.serialize_field("pair", pair)?;
+        }
 
         // Collect and sort the palette by key
         let mut sorted_palette: Vec<(&String, &Color)> = self.palette.iter().collect();
@@ -151,7 +208,7 @@ impl<'a> Serialize for SortedPalette<'a> {
     {
         let mut map = serializer.serialize_map(Some(self.0.len()))?;
         for (key, value) in &self.0 {
-            map.serialize_entry(key, format!("#{}", &value.to_hex()).as_str())?;
+            map.serialize_entry(key, value.to_string().as_str())?;
         }
         map.end()
     }
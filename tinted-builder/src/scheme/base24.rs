@@ -1,4 +1,8 @@
-use crate::{utils::slugify, SchemeSystem, SchemeVariant};
+use crate::{
+    scheme::base16,
+    utils::{normalize_palette_keys, slugify, write_palette_sources},
+    SchemeSystem, SchemeVariant, TintedBuilderError,
+};
 use serde::ser::{SerializeMap, SerializeStruct};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{collections::HashMap, fmt};
@@ -11,6 +15,22 @@ pub const REQUIRED_BASE24_PALETTE_KEYS: [&str; 24] = [
     "base12", "base13", "base14", "base15", "base16", "base17",
 ];
 
+/// Base24-only palette keys paired with the Base16 key used to fill them when upconverting.
+///
+/// Follows the Base24 styling guidance for Base16 compatibility: the extra background shades
+/// (`base10`, `base11`) reuse the default background and the bright accents (`base12`–`base17`)
+/// reuse their normal Base16 counterparts. Every Base16 color is preserved unchanged.
+pub const BASE16_TO_BASE24_PALETTE_KEYS: [(&str, &str); 8] = [
+    ("base10", "base00"),
+    ("base11", "base00"),
+    ("base12", "base08"),
+    ("base13", "base0A"),
+    ("base14", "base0B"),
+    ("base15", "base0C"),
+    ("base16", "base0D"),
+    ("base17", "base0E"),
+];
+
 #[derive(Deserialize, Serialize)]
 struct SchemeWrapper {
     pub(crate) system: SchemeSystem,
@@ -19,6 +39,7 @@ struct SchemeWrapper {
     pub(crate) author: String,
     pub(crate) description: Option<String>,
     pub(crate) variant: Option<SchemeVariant>,
+    pub(crate) pair: Option<String>,
     pub(crate) palette: HashMap<String, String>,
 }
 
@@ -35,9 +56,31 @@ pub struct Scheme {
     pub author: String,
     pub description: Option<String>,
     pub variant: SchemeVariant,
+    /// Slug of the scheme of the opposite variant this scheme is paired with, e.g. the light
+    /// variant of a dark scheme.
+    pub pair: Option<String>,
     pub palette: HashMap<String, Color>,
 }
 
+impl Scheme {
+    /// Serializes the scheme into its canonical Base24 scheme YAML string.
+    ///
+    /// Properties are written in specification order (`system`, `name`, `slug`, `author`,
+    /// `description`, `variant`, `pair` when set, `palette`), palette keys are sorted and every
+    /// color is written as `#rrggbb`, or as the color function or named color it was written as
+    /// (`Color::to_source_string`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `TintedBuilderError::YamlSerialize` if the scheme cannot be serialized.
+    pub fn to_yaml_string(&self) -> Result<String, TintedBuilderError> {
+        let mut value = serde_yaml::to_value(self).map_err(TintedBuilderError::YamlSerialize)?;
+        write_palette_sources(&mut value, &self.palette);
+
+        serde_yaml::to_string(&value).map_err(TintedBuilderError::YamlSerialize)
+    }
+}
+
 impl fmt::Display for Scheme {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "author: \"{}\"", self.author)?;
@@ -48,6 +91,9 @@ impl fmt::Display for Scheme {
         writeln!(f, "slug: \"{}\"", self.slug)?;
         writeln!(f, "system: \"{}\"", self.system)?;
         writeln!(f, "variant: \"{}\"", self.variant)?;
+        if let Some(ref pair) = self.pair {
+            writeln!(f, "pair: \"{pair}\"")?;
+        }
         writeln!(f, "palette:")?;
 
         let mut palette_vec: Vec<(String, Color)> = self
@@ -83,9 +129,10 @@ impl<'de> Deserialize<'de> for Scheme {
             )));
         }
 
+        let palette = normalize_palette_keys(wrapper.palette).map_err(serde::de::Error::custom)?;
         let contains_all_keys = REQUIRED_BASE24_PALETTE_KEYS
             .iter()
-            .all(|&key| wrapper.palette.contains_key(key));
+            .all(|&key| palette.contains_key(key));
 
         if !contains_all_keys {
             return Err(serde::de::Error::custom(
@@ -93,8 +140,7 @@ impl<'de> Deserialize<'de> for Scheme {
             ));
         }
 
-        let palette_result: Result<HashMap<String, Color>, _> = wrapper
-            .palette
+        let palette_result: Result<HashMap<String, Color>, _> = palette
             .into_iter()
             .map(|(key, value)| {
                 Color::new(&value, None, None)
@@ -110,17 +156,55 @@ impl<'de> Deserialize<'de> for Scheme {
             author: wrapper.author,
             description: wrapper.description,
             variant,
+            pair: wrapper.pair.map(|pair| slugify(&pair)),
             palette: palette_result?,
         })
     }
 }
 
+impl TryFrom<base16::Scheme> for Scheme {
+    type Error = TintedBuilderError;
+
+    /// Upconverts a Base16 scheme into a Base24 scheme.
+    ///
+    /// The Base16 palette is kept as-is and `base10`–`base17` are filled according to
+    /// `BASE16_TO_BASE24_PALETTE_KEYS`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TintedBuilderError::SchemeMissingProperty` if the Base16 palette is missing a key
+    /// required to derive the Base24 palette.
+    fn try_from(scheme: base16::Scheme) -> Result<Self, Self::Error> {
+        let mut palette = scheme.palette;
+
+        for (base24_key, base16_key) in BASE16_TO_BASE24_PALETTE_KEYS {
+            let color = palette
+                .get(base16_key)
+                .cloned()
+                .ok_or_else(|| TintedBuilderError::SchemeMissingProperty(base16_key.to_string()))?;
+
+            palette.insert(base24_key.to_string(), color);
+        }
+
+        Ok(Self {
+            system: SchemeSystem::Base24,
+            name: scheme.name,
+            slug: scheme.slug,
+            author: scheme.author,
+            description: scheme.description,
+            variant: scheme.variant,
+            pair: scheme.pair,
+            palette,
+        })
+    }
+}
+
 impl Serialize for Scheme {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("Scheme", 7)?;
+        let mut state = serializer.serialize_struct("Scheme", 8)?;
         state.serialize_field("system", &self.system)?;
         state.serialize_field("name", &self.name)?;
         state.serialize_field("slug", &self.slug)?;
@@ -129,6 +213,9 @@ impl Serialize for Scheme {
             state.serialize_field("description", description)?;
         }
         state.serialize_field("variant", &self.variant)?;
+        if let Some(pair) = &self.pair {
+            state.serialize_field("pair", pair)?;
+        }
 
         // Collect and sort the palette by key
         let mut sorted_palette: Vec<(&String, &Color)> = self.palette.iter().collect();
@@ -152,7 +239,7 @@ impl<'a> Serialize for SortedPalette<'a> {
     {
         let mut map = serializer.serialize_map(Some(self.0.len()))?;
         for (key, value) in &self.0 {
-            map.serialize_entry(key, format!("#{}", &value.to_hex()).as_str())?;
+            map.serialize_entry(key, value.to_string().as_str())?;
         }
         map.end()
     }
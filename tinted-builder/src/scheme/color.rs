@@ -1,52 +1,77 @@
-use palette::{rgb::Rgb, FromColor, GetHue, Hsl, IntoColor};
+use palette::color_difference::{Ciede2000, EuclideanDistance};
+use palette::white_point::D65;
+use palette::{
+    convert::FromColorUnclamped, rgb::Rgb, FromColor, GetHue, Hsl, IntoColor, Lab, Oklab, Oklch,
+    RgbHue, Srgb,
+};
 use serde::{Deserialize, Serialize, Serializer};
 use std::fmt;
 use std::str::FromStr;
 
 use crate::error::TintedBuilderError;
+use crate::scheme::color_input;
+use crate::Derivation;
 
 /// A normalized color with multiple representations used by templates.
 ///
 /// Stores hex (lowercased, without the leading `#`), 8-bit RGB, and normalized decimal channels
-/// in `[0.0, 1.0]`. The custom `Serialize` implementation exposes template-friendly fields like
-/// `hex`, `hex-r/g/b`, `hex-bgr`, `rgb`, `rgb16`, and `dec` as documented in the spec.
+/// in `[0.0, 1.0]`, along with an 8-bit alpha channel (`255` when opaque). The custom
+/// `Serialize` implementation exposes template-friendly fields like `hex`, `hex-r/g/b/a`,
+/// `hex-bgr`, `hex-rgba`, `rgb`, `rgb16`, and `dec` as documented in the spec, plus `hex-upper`,
+/// `hex16`, `hsl`, `oklch`, `ansi256` and `xterm`.
 #[derive(Debug, Clone, Deserialize)]
 pub struct Color {
     pub hex: (String, String, String),
     pub rgb: (u8, u8, u8),
     pub dec: (f32, f32, f32),
+    #[serde(default = "opaque_alpha")]
+    pub alpha: u8,
     pub name: ColorName,
     pub variant: ColorVariant,
+    /// The color function, named color or Tinted8 `ColorExpression` the color was written as,
+    /// e.g. `"oklch(20% 0.02 260)"` or `"gray.dim"`, so that it can be written back unchanged.
+    /// `None` for hex and derived colors.
+    #[serde(skip)]
+    pub source: Option<String>,
 }
 
 impl Color {
-    /// Creates a `Color` from a hex string like `"ff00ff"` or `"#ffcc00"` along with the `ColorName`
-    /// and `ColorVariant`
-    ///
-    /// # Errors
-    ///
-    /// Returns `Err(TintedBuilderError::HexInputFormat)` if `hex_color` is not a valid
-    /// 6-digit hexadecimal color (optionally prefixed with `#`).
-    /// Creates a `Color` from a hex string like `"ff00ff"` or `"#ffcc00"`.
+    /// Creates a `Color` from a hex string like `"ff00ff"`, `"#ffcc00"` or `"#ffcc0080"`.
     ///
     /// The color is associated with an optional `ColorName` and `ColorVariant` for downstream usage.
+    /// 4- and 8-digit hex strings carry an alpha channel; other colors are opaque.
+    ///
+    /// `hex_color` may also be a CSS color function or named color, e.g. `"rgb(204 36 29)"`,
+    /// `"hsl(2 75% 46%)"`, `"oklch(55% 0.19 28 / 50%)"` or `"rebeccapurple"`, which is
+    /// normalized to the same hex representation.
     ///
     /// # Errors
     ///
     /// Returns `Err(TintedBuilderError::HexInputFormat)` if `hex_color` is not a valid
-    /// 3- or 6-digit hexadecimal color (optionally prefixed with `#`).
+    /// 3-, 4-, 6- or 8-digit hexadecimal color (optionally prefixed with `#`), nor a supported
+    /// color function or named color.
     pub fn new(
         hex_color: &str,
         name: Option<ColorName>,
         variant: Option<ColorVariant>,
     ) -> Result<Self, TintedBuilderError> {
-        let hex_full = process_hex_input(hex_color).ok_or(TintedBuilderError::HexInputFormat)?;
+        let (hex_full, source) = match process_hex_input(hex_color) {
+            Some(hex_full) => (hex_full, None),
+            None => (
+                color_input::to_hex(hex_color).ok_or(TintedBuilderError::HexInputFormat)?,
+                Some(hex_color.trim().to_string()),
+            ),
+        };
         let hex: (String, String, String) = (
             hex_full[0..2].to_lowercase(),
             hex_full[2..4].to_lowercase(),
             hex_full[4..6].to_lowercase(),
         );
         let rgb = hex_to_rgb(&hex)?;
+        let alpha = match hex_full.get(6..8) {
+            Some(hex_alpha) => u8::from_str_radix(hex_alpha, 16)?,
+            None => opaque_alpha(),
+        };
         // Store normalized decimal channels in [0.0, 1.0]
         let inv_255: f32 = 1.0 / 255.0;
         let dec: (f32, f32, f32) = (
@@ -59,35 +84,242 @@ impl Color {
             hex,
             rgb,
             dec,
+            alpha,
             name: name.unwrap_or(ColorName::Other),
             variant: variant.unwrap_or(ColorVariant::Normal),
+            source,
         })
     }
 
+    /// Creates an opaque `Color` from 8-bit RGB channels.
+    pub(crate) fn from_rgb(rgb: (u8, u8, u8), name: ColorName, variant: ColorVariant) -> Self {
+        let inv_255: f32 = 1.0 / 255.0;
+
+        Self {
+            hex: (
+                format!("{:02x}", rgb.0),
+                format!("{:02x}", rgb.1),
+                format!("{:02x}", rgb.2),
+            ),
+            rgb,
+            dec: (
+                f32::from(rgb.0) * inv_255,
+                f32::from(rgb.1) * inv_255,
+                f32::from(rgb.2) * inv_255,
+            ),
+            alpha: opaque_alpha(),
+            name,
+            variant,
+            source: None,
+        }
+    }
+
+    /// Returns the color as it is written in scheme files: its `source` when it still resolves to
+    /// this color, otherwise its hex string as written by `Display`.
+    #[must_use]
+    pub fn to_source_string(&self) -> String {
+        self.source
+            .as_ref()
+            .filter(|source| {
+                Self::new(source, None, None)
+                    .is_ok_and(|color| color.to_string() == self.to_string())
+            })
+            .cloned()
+            .unwrap_or_else(|| self.to_string())
+    }
+
+    /// Returns the color with its alpha channel set to `alpha`.
+    #[must_use]
+    pub const fn with_alpha(mut self, alpha: u8) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Returns `true` when the color has no transparency.
+    #[must_use]
+    pub const fn is_opaque(&self) -> bool {
+        self.alpha == u8::MAX
+    }
+
+    /// Returns the 2-digit hex string (lowercase) of the alpha channel.
+    #[must_use]
+    pub fn hex_alpha(&self) -> String {
+        format!("{:02x}", self.alpha)
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    /// Composites the color over an opaque `background` and returns the opaque result.
+    ///
+    /// Channels are mixed in sRGB as `color × alpha + background × (1 − alpha)`, matching how
+    /// browsers and terminals draw translucent colors. This is useful for templates whose
+    /// target does not support alpha. The alpha channel of `background` is ignored and the
+    /// result keeps the `name` and `variant` of the color.
+    #[must_use]
+    pub fn blend_over(&self, background: &Self) -> Self {
+        let alpha = f32::from(self.alpha) / 255.0;
+        let rgb: [u8; 3] = [
+            (self.rgb.0, background.rgb.0),
+            (self.rgb.1, background.rgb.1),
+            (self.rgb.2, background.rgb.2),
+        ]
+        .map(|(fg, bg)| {
+            f32::from(fg)
+                .mul_add(alpha, f32::from(bg) * (1.0 - alpha))
+                .round()
+                .clamp(0.0, 255.0) as u8
+        });
+
+        Self::from_rgb(rgb.into(), self.name.clone(), self.variant.clone())
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    /// Mixes the color with `other` in Oklab, where `weight` is the share of `other` in `[0.0,
+    /// 1.0]`.
+    ///
+    /// The alpha channels are mixed linearly. The result keeps the `name` and `variant` of the
+    /// color.
+    #[must_use]
+    pub fn mix(&self, other: &Self, weight: f32) -> Self {
+        let weight = f64::from(weight.clamp(0.0, 1.0));
+        let (l, a, b) = self.to_oklab();
+        let (other_l, other_a, other_b) = other.to_oklab();
+        let mixed = Oklab::new(
+            (other_l - l).mul_add(weight, l),
+            (other_a - a).mul_add(weight, a),
+            (other_b - b).mul_add(weight, b),
+        );
+        let srgb: Srgb<f64> = Srgb::from_color(mixed);
+        let rgb: [u8; 3] =
+            [srgb.red, srgb.green, srgb.blue].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        let alpha = (f64::from(other.alpha) - f64::from(self.alpha))
+            .mul_add(weight, f64::from(self.alpha))
+            .round() as u8;
+
+        Self::from_rgb(rgb.into(), self.name.clone(), self.variant.clone()).with_alpha(alpha)
+    }
+
+    /// Increases the HSL lightness by `amount`, clamped to `[0.0, 1.0]`.
+    #[must_use]
+    pub fn lighten(&self, amount: f32) -> Self {
+        self.adjust_hsl(|hsl| Hsl::new(hsl.hue, hsl.saturation, hsl.lightness + amount))
+    }
+
+    /// Decreases the HSL lightness by `amount`, clamped to `[0.0, 1.0]`.
+    #[must_use]
+    pub fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Increases the HSL saturation by `amount`, clamped to `[0.0, 1.0]`.
+    #[must_use]
+    pub fn saturate(&self, amount: f32) -> Self {
+        self.adjust_hsl(|hsl| Hsl::new(hsl.hue, hsl.saturation + amount, hsl.lightness))
+    }
+
+    /// Decreases the HSL saturation by `amount`, clamped to `[0.0, 1.0]`.
+    #[must_use]
+    pub fn desaturate(&self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    /// Applies `adjust` to the color in HSL, keeping the alpha, `name` and `variant`.
+    fn adjust_hsl(&self, adjust: impl Fn(Hsl) -> Hsl) -> Self {
+        let rgb = Rgb::new(self.rgb.0, self.rgb.1, self.rgb.2);
+        let adjusted = adjust(Hsl::from_color(rgb.into_format::<f32>()));
+        let clamped = Hsl::new(
+            adjusted.hue,
+            adjusted.saturation.clamp(0.0, 1.0),
+            adjusted.lightness.clamp(0.0, 1.0),
+        );
+        let updated_rgb: Rgb = clamped.into_color();
+        let rgb: [u8; 3] = [updated_rgb.red, updated_rgb.green, updated_rgb.blue]
+            .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+
+        Self::from_rgb(rgb.into(), self.name.clone(), self.variant.clone()).with_alpha(self.alpha)
+    }
+
     #[must_use]
     /// Returns the 6-digit hex string (lowercase) without the leading `#`.
     pub fn to_hex(&self) -> String {
         format!("{}{}{}", &self.hex.0, &self.hex.1, &self.hex.2)
     }
 
-    #[allow(
-        clippy::cast_possible_truncation,
-        clippy::cast_sign_loss,
-        clippy::missing_errors_doc
-    )]
+    #[must_use]
+    /// Returns the 8-digit hex string (lowercase) including alpha, without the leading `#`.
+    pub fn to_hex_rgba(&self) -> String {
+        format!("{}{}", self.to_hex(), self.hex_alpha())
+    }
+
+    #[must_use]
+    /// Returns the 12-digit hex string (lowercase) of the 16-bit channels (8-bit × 257), without
+    /// the leading `#`.
+    pub fn to_hex16(&self) -> String {
+        let (r, g, b) = self.rgb16();
+
+        format!("{r:04x}{g:04x}{b:04x}")
+    }
+
+    #[must_use]
+    /// Returns the X11 `rgb:rrrr/gggg/bbbb` form of the color, as used by Xresources and
+    /// terminal escape sequences.
+    pub fn to_xterm(&self) -> String {
+        let (r, g, b) = self.rgb16();
+
+        format!("rgb:{r:04x}/{g:04x}/{b:04x}")
+    }
+
+    /// Returns the 16-bit channels (8-bit × 257).
+    fn rgb16(&self) -> (u16, u16, u16) {
+        (
+            u16::from(self.rgb.0) * 257,
+            u16::from(self.rgb.1) * 257,
+            u16::from(self.rgb.2) * 257,
+        )
+    }
+
     /// Derives a `dim` or `bright` variant from a `normal` color according to the Tinted8 rules.
     ///
     /// # Errors
     ///
     /// Returns an error when the color cannot be converted.
     pub fn try_to_variant(&self, color_variant: &ColorVariant) -> Result<Self, TintedBuilderError> {
-        let rgb = Rgb::new(self.rgb.0, self.rgb.1, self.rgb.2);
-        let hsl: Hsl = Hsl::from_color(rgb.into_format::<f32>());
-        let updated_hsl = adjust_normal_hsl_for_variant(hsl, color_variant);
-        let updated_rgb: Rgb = updated_hsl.into_color();
-        let updated_rgb_r: u8 = (updated_rgb.red.clamp(0.0, 1.0) * 255.0).round() as u8;
-        let updated_rgb_g: u8 = (updated_rgb.green.clamp(0.0, 1.0) * 255.0).round() as u8;
-        let updated_rgb_b: u8 = (updated_rgb.blue.clamp(0.0, 1.0) * 255.0).round() as u8;
+        self.try_to_variant_with(color_variant, Derivation::Hsl)
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    /// Derives a `dim` or `bright` variant from a `normal` color in the color space of
+    /// `derivation`.
+    ///
+    /// `Derivation::Hsl` follows the Tinted8 rules and matches `Color::try_to_variant`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the color cannot be converted.
+    pub fn try_to_variant_with(
+        &self,
+        color_variant: &ColorVariant,
+        derivation: Derivation,
+    ) -> Result<Self, TintedBuilderError> {
+        let [updated_rgb_r, updated_rgb_g, updated_rgb_b]: [u8; 3] = match derivation {
+            Derivation::Hsl => {
+                let rgb = Rgb::new(self.rgb.0, self.rgb.1, self.rgb.2);
+                let hsl: Hsl = Hsl::from_color(rgb.into_format::<f32>());
+                let updated_hsl = adjust_normal_hsl_for_variant(hsl, color_variant);
+                let updated_rgb: Rgb = updated_hsl.into_color();
+
+                [updated_rgb.red, updated_rgb.green, updated_rgb.blue]
+                    .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+            }
+            Derivation::Oklch => {
+                let oklch = Oklch::from_color(self.oklab());
+                let updated_rgb =
+                    oklch_to_srgb_in_gamut(adjust_normal_oklch_for_variant(oklch, color_variant));
+
+                [updated_rgb.red, updated_rgb.green, updated_rgb.blue]
+                    .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+            }
+        };
         let updated_hex = format!("{updated_rgb_r:02X}{updated_rgb_g:02X}{updated_rgb_b:02X}");
 
         Self::new(
@@ -97,80 +329,321 @@ impl Color {
         )
     }
 
-    #[allow(
-        clippy::missing_errors_doc,
-        clippy::cast_possible_truncation,
-        clippy::cast_sign_loss
-    )]
-    /// Derives supplemental colors (e.g., `orange` or `brown`) from a base color as specified.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    /// Derives a supplemental color from a base color by adjusting its HSL channels.
+    ///
+    /// The derived color keeps the `variant` of the base color. Supported derivations, see
+    /// `Color::supported_derivations`:
+    ///
+    /// | From     | To       | Hue   | Saturation | Lightness |
+    /// |----------|----------|-------|------------|-----------|
+    /// | `yellow` | `orange` | −10°  | ×1.0       | ±0.0      |
+    /// | `red`    | `orange` | +25°  | ×1.0       | ±0.0      |
+    /// | `yellow` | `brown`  | −15°  | ×0.65      | −0.3      |
+    /// | `orange` | `brown`  | −5°   | ×0.65      | −0.3      |
+    /// | `red`    | `brown`  | +15°  | ×0.65      | −0.3      |
+    ///
+    /// `gray` is derived from a `black`/`white` pair with `Color::try_to_gray`.
     ///
     /// # Errors
     ///
-    /// Returns an error when the requested conversion is unsupported.
+    /// Returns `TintedBuilderError::UnsupportedColorDerivation` when the requested derivation is
+    /// not in the table above.
     pub fn try_to_color(&self, target_color_name: &ColorName) -> Result<Self, TintedBuilderError> {
-        let from_target_color_name = &self.name.clone();
-        let to_target_color_name = target_color_name.clone();
-        let to_color_variant = &self.variant.clone();
-
-        match (&from_target_color_name, &to_target_color_name) {
-            (ColorName::Yellow, ColorName::Orange) => {
-                let from_rgb = Rgb::new(self.rgb.0, self.rgb.1, self.rgb.2);
-                let from_hsl: Hsl = Hsl::from_color(from_rgb.into_format::<f32>());
-                let from_hsl_h = from_hsl.get_hue().into_degrees();
-                let from_hsl_s = from_hsl.saturation;
-                let from_hsl_l = from_hsl.lightness;
-                // Wrap-aware hue rotation toward orange (−12°), keep S/L unchanged
-                let h_prime = (from_hsl_h - 10.0 + 360.0) % 360.0;
-                let to_hsl: Hsl = Hsl::new(h_prime, from_hsl_s, from_hsl_l);
-                let to_rgb: Rgb = to_hsl.into_color();
-                let [to_rgb_r, to_rgb_g, to_rgb_b]: [u8; 3] =
-                    [to_rgb.red, to_rgb.green, to_rgb.blue]
-                        .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
-                let to_hex = format!("{to_rgb_r:02X}{to_rgb_g:02X}{to_rgb_b:02X}");
-
-                Self::new(
-                    &to_hex,
-                    Some(to_target_color_name.clone()),
-                    Some(to_color_variant.clone()),
-                )
-            }
-            (ColorName::Yellow, ColorName::Brown) => {
-                let from_rgb = Rgb::new(self.rgb.0, self.rgb.1, self.rgb.2);
-                let from_hsl: Hsl = Hsl::from_color(from_rgb.into_format::<f32>());
-                let from_hsl_h = from_hsl.get_hue().into_degrees();
-                let from_hsl_s = from_hsl.saturation;
-                let from_hsl_l = from_hsl.lightness;
-                let h_difference = 15.0;
-                let l_difference = 0.3;
-                let s_perc_difference = 0.65;
-                // Clamp S/L after adjustment as per spec
-                let s_prime = (from_hsl_s * s_perc_difference).clamp(0.0, 1.0);
-                let l_prime = (from_hsl_l - l_difference).clamp(0.0, 1.0);
-                let to_hsl: Hsl = Hsl::new(from_hsl_h - h_difference, s_prime, l_prime);
-                let to_rgb: Rgb = to_hsl.into_color();
-                let [to_rgb_r, to_rgb_g, to_rgb_b]: [u8; 3] =
-                    [to_rgb.red, to_rgb.green, to_rgb.blue]
-                        .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
-                let to_hex = format!("{to_rgb_r:02X}{to_rgb_g:02X}{to_rgb_b:02X}");
-
-                Self::new(
-                    &to_hex,
-                    Some(to_target_color_name.clone()),
-                    Some(to_color_variant.clone()),
-                )
-            }
-            _ => Err(TintedBuilderError::UnsupportedColorDerivation {
+        let rule = COLOR_DERIVATION_RULES
+            .iter()
+            .find(|rule| rule.from == self.name && &rule.to == target_color_name)
+            .ok_or_else(|| TintedBuilderError::UnsupportedColorDerivation {
                 from_color: self.name.to_string(),
                 target: target_color_name.to_string(),
-                supported_derivations: "yellow→orange, yellow→brown".to_string(),
-            }),
+                supported_derivations: Self::supported_derivations()
+                    .iter()
+                    .map(|(from, to)| format!("{from}→{to}"))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            })?;
+        let from_rgb = Rgb::new(self.rgb.0, self.rgb.1, self.rgb.2);
+        let from_hsl: Hsl = Hsl::from_color(from_rgb.into_format::<f32>());
+        // Wrap-aware hue rotation; clamp S/L after adjustment as per spec
+        let to_hsl: Hsl = Hsl::new(
+            (from_hsl.get_hue().into_degrees() + rule.hue + 360.0) % 360.0,
+            (from_hsl.saturation * rule.saturation).clamp(0.0, 1.0),
+            (from_hsl.lightness + rule.lightness).clamp(0.0, 1.0),
+        );
+        let to_rgb: Rgb = to_hsl.into_color();
+        let [to_rgb_r, to_rgb_g, to_rgb_b]: [u8; 3] = [to_rgb.red, to_rgb.green, to_rgb.blue]
+            .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+
+        Ok(Self::from_rgb(
+            [to_rgb_r, to_rgb_g, to_rgb_b].into(),
+            target_color_name.clone(),
+            self.variant.clone(),
+        ))
+    }
+
+    /// Returns the `(from, to)` color name pairs `Color::try_to_color` can derive.
+    #[must_use]
+    pub fn supported_derivations() -> Vec<(ColorName, ColorName)> {
+        COLOR_DERIVATION_RULES
+            .iter()
+            .map(|rule| (rule.from.clone(), rule.to.clone()))
+            .collect()
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    /// Derives a `gray` between a `black` and a `white` color.
+    ///
+    /// The lightness is the average HSL lightness of both colors. With a `tint` of `0.0` the gray
+    /// is neutral, as defined by the Tinted8 rules. Higher values, up to `1.0`, add that fraction
+    /// of the pair's average Oklab `a`/`b`, so a gray between a warm black and white stays warm.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the color cannot be converted.
+    pub fn try_to_gray(black: &Self, white: &Self, tint: f32) -> Result<Self, TintedBuilderError> {
+        let black_rgb = Rgb::new(black.rgb.0, black.rgb.1, black.rgb.2);
+        let black_hsl: Hsl = Hsl::from_color(black_rgb.into_format::<f32>());
+        let white_rgb = Rgb::new(white.rgb.0, white.rgb.1, white.rgb.2);
+        let white_hsl: Hsl = Hsl::from_color(white_rgb.into_format::<f32>());
+
+        // Compute midpoint hue (wrap-aware); hue is irrelevant for gray but keep it stable.
+        let h1 = white_hsl.get_hue().into_degrees();
+        let h2 = black_hsl.get_hue().into_degrees();
+        let d = ((h2 - h1 + 540.0) % 360.0) - 180.0;
+        let gray_hsl_h = (0.5_f32.mul_add(d, h1) + 360.0) % 360.0;
+        // For a neutral gray, force saturation to 0 and average the lightness.
+        let gray_hsl_s = 0.0;
+        let gray_hsl_l = 0.5 * (white_hsl.lightness + black_hsl.lightness);
+
+        let gray_hsl = Hsl::new(RgbHue::from_degrees(gray_hsl_h), gray_hsl_s, gray_hsl_l);
+        let gray_rgb: Rgb = gray_hsl.into_color();
+        let gray_rgb_r: u8 = (gray_rgb.red.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let gray_rgb_g: u8 = (gray_rgb.green.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let gray_rgb_b: u8 = (gray_rgb.blue.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let gray_hex = format!("{gray_rgb_r:02X}{gray_rgb_g:02X}{gray_rgb_b:02X}");
+        let neutral_gray = Self::new(&gray_hex, Some(ColorName::Gray), Some(ColorVariant::Normal))?;
+
+        let tint = f64::from(tint.clamp(0.0, 1.0));
+        if tint <= 0.0 {
+            return Ok(neutral_gray);
         }
+
+        let (black_oklab, white_oklab) = (black.oklab(), white.oklab());
+        let tinted_gray = Srgb::from_color(Oklab::new(
+            neutral_gray.oklab().l,
+            tint * 0.5 * (black_oklab.a + white_oklab.a),
+            tint * 0.5 * (black_oklab.b + white_oklab.b),
+        ));
+        let [tinted_r, tinted_g, tinted_b]: [u8; 3] =
+            [tinted_gray.red, tinted_gray.green, tinted_gray.blue]
+                .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+
+        Ok(Self::from_rgb(
+            [tinted_r, tinted_g, tinted_b].into(),
+            ColorName::Gray,
+            ColorVariant::Normal,
+        ))
     }
 }
 
+/// An HSL adjustment used by `Color::try_to_color` to derive one color from another.
+struct ColorDerivationRule {
+    from: ColorName,
+    to: ColorName,
+    /// Degrees added to the hue.
+    hue: f32,
+    /// Factor applied to the saturation.
+    saturation: f32,
+    /// Amount added to the lightness.
+    lightness: f32,
+}
+
+/// The derivations supported by `Color::try_to_color`.
+const COLOR_DERIVATION_RULES: [ColorDerivationRule; 5] = [
+    ColorDerivationRule {
+        from: ColorName::Yellow,
+        to: ColorName::Orange,
+        hue: -10.0,
+        saturation: 1.0,
+        lightness: 0.0,
+    },
+    ColorDerivationRule {
+        from: ColorName::Red,
+        to: ColorName::Orange,
+        hue: 25.0,
+        saturation: 1.0,
+        lightness: 0.0,
+    },
+    ColorDerivationRule {
+        from: ColorName::Yellow,
+        to: ColorName::Brown,
+        hue: -15.0,
+        saturation: 0.65,
+        lightness: -0.3,
+    },
+    ColorDerivationRule {
+        from: ColorName::Orange,
+        to: ColorName::Brown,
+        hue: -5.0,
+        saturation: 0.65,
+        lightness: -0.3,
+    },
+    ColorDerivationRule {
+        from: ColorName::Red,
+        to: ColorName::Brown,
+        hue: 15.0,
+        saturation: 0.65,
+        lightness: -0.3,
+    },
+];
+
+impl Color {
+    /// Returns the WCAG 2.x relative luminance of the color in `[0.0, 1.0]`.
+    ///
+    /// The sRGB channels are linearized and weighted as defined by
+    /// <https://www.w3.org/TR/WCAG21/#dfn-relative-luminance>.
+    #[must_use]
+    pub fn relative_luminance(&self) -> f64 {
+        let [r, g, b] = [self.rgb.0, self.rgb.1, self.rgb.2].map(|channel| {
+            let channel = f64::from(channel) / 255.0;
+
+            if channel <= 0.040_45 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        });
+
+        0.0722_f64.mul_add(b, 0.2126_f64.mul_add(r, 0.7152 * g))
+    }
+
+    /// Returns the WCAG 2.x contrast ratio between two colors, from `1.0` (no contrast) to `21.0`
+    /// (black on white). The ratio is symmetric, so the order of the colors does not matter.
+    #[must_use]
+    pub fn contrast_ratio(&self, other: &Self) -> f64 {
+        let luminance = self.relative_luminance();
+        let other_luminance = other.relative_luminance();
+        let (lighter, darker) = if luminance > other_luminance {
+            (luminance, other_luminance)
+        } else {
+            (other_luminance, luminance)
+        };
+
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Returns the CIE L\*a\*b\* (D65) coordinates of the color as `(l, a, b)`, with `l` in
+    /// `[0.0, 100.0]`.
+    #[must_use]
+    pub fn to_lab(&self) -> (f64, f64, f64) {
+        let lab = self.lab();
+
+        (lab.l, lab.a, lab.b)
+    }
+
+    /// Returns the Oklab coordinates of the color as `(l, a, b)`, with `l` in `[0.0, 1.0]`.
+    #[must_use]
+    pub fn to_oklab(&self) -> (f64, f64, f64) {
+        let oklab = self.oklab();
+
+        (oklab.l, oklab.a, oklab.b)
+    }
+
+    /// Returns the OKLCH coordinates of the color as `(l, c, h)`, with `l` in `[0.0, 1.0]` and the
+    /// hue `h` in degrees in `[0.0, 360.0)`. The hue of grays is `0.0`.
+    #[must_use]
+    pub fn to_oklch(&self) -> (f64, f64, f64) {
+        let oklch = Oklch::from_color(self.oklab());
+
+        (oklch.l, oklch.chroma, oklch.hue.into_positive_degrees())
+    }
+
+    /// Returns the HSL coordinates of the color as `(h, s, l)`, with the hue `h` in degrees in
+    /// `[0.0, 360.0)` and `s` and `l` in `[0.0, 1.0]`. The hue of grays is `0.0`.
+    #[must_use]
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let hsl = Hsl::from_color(self.srgb().into_format::<f32>());
+
+        (
+            hsl.hue.into_positive_degrees(),
+            hsl.saturation,
+            hsl.lightness,
+        )
+    }
+
+    /// Returns the CIEDE2000 distance between two colors. A distance below roughly `2.3` is
+    /// barely noticeable.
+    #[must_use]
+    pub fn delta_e_2000(&self, other: &Self) -> f64 {
+        self.lab().difference(other.lab())
+    }
+
+    /// Returns the Euclidean distance between two colors in Oklab. A distance below roughly `0.02`
+    /// is barely noticeable.
+    #[must_use]
+    pub fn delta_e_ok(&self, other: &Self) -> f64 {
+        self.oklab().distance(other.oklab())
+    }
+
+    fn lab(&self) -> Lab<D65, f64> {
+        Lab::from_color(self.srgb())
+    }
+
+    pub(crate) fn oklab(&self) -> Oklab<f64> {
+        Oklab::from_color(self.srgb())
+    }
+
+    fn srgb(&self) -> Srgb<f64> {
+        Srgb::new(self.rgb.0, self.rgb.1, self.rgb.2).into_format::<f64>()
+    }
+
+    /// Returns the APCA lightness contrast (Lc) of the color as text on `background`.
+    ///
+    /// Implements APCA-W3 `0.0.98G-4g`. The result is roughly in `[-108.0, 106.0]`: positive for
+    /// dark text on a light background, negative for light text on a dark background and `0.0`
+    /// when the contrast is too low to be meaningful. Unlike `Color::contrast_ratio`, the order
+    /// of the colors matters.
+    #[must_use]
+    pub fn apca_contrast(&self, background: &Self) -> f64 {
+        let text_y = apca_luminance(self);
+        let background_y = apca_luminance(background);
+
+        if (background_y - text_y).abs() < APCA_DELTA_Y_MIN {
+            return 0.0;
+        }
+
+        let lc = if background_y > text_y {
+            // Dark text on a light background
+            let sapc = (background_y.powf(0.56) - text_y.powf(0.57)) * APCA_SCALE;
+
+            if sapc < APCA_LO_CLIP {
+                0.0
+            } else {
+                sapc - APCA_LO_OFFSET
+            }
+        } else {
+            // Light text on a dark background
+            let sapc = (background_y.powf(0.65) - text_y.powf(0.62)) * APCA_SCALE;
+
+            if sapc > -APCA_LO_CLIP {
+                0.0
+            } else {
+                sapc + APCA_LO_OFFSET
+            }
+        };
+
+        lc * 100.0
+    }
+}
+
+/// Formats the color as `#rrggbb`, or `#rrggbbaa` when it is not opaque.
 impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "#{}", &self.to_hex())
+        if self.is_opaque() {
+            write!(f, "#{}", &self.to_hex())
+        } else {
+            write!(f, "#{}", &self.to_hex_rgba())
+        }
     }
 }
 
@@ -222,7 +695,7 @@ impl ColorVariant {
 }
 
 /// Canonical color names used by the palette and theming properties.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub enum ColorName {
     Black,
@@ -277,6 +750,7 @@ impl ColorName {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct ColorType(pub ColorName, pub ColorVariant);
 
 impl FromStr for ColorName {
@@ -310,7 +784,7 @@ impl FromStr for ColorName {
 impl FromStr for ColorType {
     type Err = TintedBuilderError;
 
-    /// Parses a string like `white_normal` into a `ColorType`.
+    /// Parses a string like `white_normal`, `white-normal` or `white.normal` into a `ColorType`.
     ///
     /// # Errors
     ///
@@ -323,6 +797,7 @@ impl FromStr for ColorType {
         let (name, variant) = lower
             .split_once('_')
             .or_else(|| lower.split_once('-'))
+            .or_else(|| lower.split_once('.'))
             .ok_or_else(|| TintedBuilderError::InvalidColorType(trimmed.to_string()))?;
 
         Ok(Self(
@@ -341,14 +816,20 @@ fn hex_to_rgb(hex: &(String, String, String)) -> Result<(u8, u8, u8), TintedBuil
     Ok((r, g, b))
 }
 
-/// Normalizes a hex string by removing an optional `#` and expanding 3-digit to 6-digit.
+/// The alpha channel of colors without transparency.
+const fn opaque_alpha() -> u8 {
+    u8::MAX
+}
+
+/// Normalizes a hex string by removing an optional `#` and expanding 3-digit to 6-digit and
+/// 4-digit to 8-digit.
 fn process_hex_input(input: &str) -> Option<String> {
     // Check and process the hash prefix
     let hex_str = input.strip_prefix('#').unwrap_or(input);
 
     match hex_str.len() {
-        // Convert 3-length hex to 6-length by duplicating each character
-        3 => {
+        // Convert 3-length and 4-length hex to 6-length and 8-length by duplicating each character
+        3 | 4 => {
             if hex_str.chars().all(|c| c.is_ascii_hexdigit()) {
                 Some(
                     hex_str
@@ -360,8 +841,8 @@ fn process_hex_input(input: &str) -> Option<String> {
                 None // Contains invalid characters
             }
         }
-        // Validate the 6-length hex value
-        6 => {
+        // Validate the 6-length and 8-length hex value
+        6 | 8 => {
             if hex_str.chars().all(|c| c.is_ascii_hexdigit()) {
                 Some(hex_str.to_string())
             } else {
@@ -373,6 +854,26 @@ fn process_hex_input(input: &str) -> Option<String> {
     }
 }
 
+const APCA_BLACK_THRESHOLD: f64 = 0.022;
+const APCA_BLACK_CLAMP: f64 = 1.414;
+const APCA_DELTA_Y_MIN: f64 = 0.000_5;
+const APCA_SCALE: f64 = 1.14;
+const APCA_LO_CLIP: f64 = 0.1;
+const APCA_LO_OFFSET: f64 = 0.027;
+
+/// Returns the APCA screen luminance of a color, with the soft clamp for near-black colors.
+fn apca_luminance(color: &Color) -> f64 {
+    let [r, g, b] = [color.rgb.0, color.rgb.1, color.rgb.2]
+        .map(|channel| (f64::from(channel) / 255.0).powf(2.4));
+    let y = 0.072_175_0_f64.mul_add(b, 0.212_672_9_f64.mul_add(r, 0.715_152_2 * g));
+
+    if y < APCA_BLACK_THRESHOLD {
+        y + (APCA_BLACK_THRESHOLD - y).powf(APCA_BLACK_CLAMP)
+    } else {
+        y
+    }
+}
+
 const DL: f32 = 0.12;
 
 /// Adjusts HSL channels to derive `dim`/`bright` from `normal` according to Tinted8 rules.
@@ -413,11 +914,56 @@ fn adjust_normal_hsl_for_variant(hsl: Hsl, color_variant: &ColorVariant) -> Hsl
     Hsl::new(hsl.hue, updated_s, updated_l)
 }
 
+const OKLCH_DL: f64 = 0.08;
+
+/// Adjusts OKLCH lightness to derive `dim`/`bright` from `normal`, keeping chroma and hue.
+fn adjust_normal_oklch_for_variant(oklch: Oklch<f64>, color_variant: &ColorVariant) -> Oklch<f64> {
+    let updated_l = match color_variant {
+        ColorVariant::Dim => oklch.l - OKLCH_DL.min(oklch.l),
+        ColorVariant::Bright => oklch.l + OKLCH_DL.min(1.0 - oklch.l),
+        _ => oklch.l,
+    };
+
+    Oklch::new(updated_l.clamp(0.0, 1.0), oklch.chroma, oklch.hue)
+}
+
+/// Converts an OKLCH color to sRGB, reducing chroma until it fits the sRGB gamut.
+pub fn oklch_to_srgb_in_gamut(oklch: Oklch<f64>) -> Srgb<f64> {
+    const EPSILON: f64 = 1e-6;
+    let is_in_gamut = |srgb: &Srgb<f64>| {
+        [srgb.red, srgb.green, srgb.blue]
+            .iter()
+            .all(|c| (-EPSILON..=1.0 + EPSILON).contains(c))
+    };
+    let srgb = Srgb::from_color_unclamped(oklch);
+
+    if is_in_gamut(&srgb) {
+        return srgb;
+    }
+
+    // Binary search the highest chroma that stays in gamut
+    let (mut low, mut high) = (0.0, oklch.chroma);
+    for _ in 0..20 {
+        let chroma = (low + high) / 2.0;
+
+        if is_in_gamut(&Srgb::from_color_unclamped(Oklch::new(
+            oklch.l, chroma, oklch.hue,
+        ))) {
+            low = chroma;
+        } else {
+            high = chroma;
+        }
+    }
+
+    Srgb::from_color_unclamped(Oklch::new(oklch.l, low, oklch.hue))
+}
+
 #[derive(Serialize)]
 struct RgbSer {
     r: u8,
     g: u8,
     b: u8,
+    a: u8,
 }
 #[derive(Serialize)]
 struct Rgb16Ser {
@@ -426,10 +972,23 @@ struct Rgb16Ser {
     b: u16,
 }
 #[derive(Serialize)]
+struct HslSer {
+    h: String,
+    s: String,
+    l: String,
+}
+#[derive(Serialize)]
+struct OklchSer {
+    l: String,
+    c: String,
+    h: String,
+}
+#[derive(Serialize)]
 struct DecSer {
     r: String,
     g: String,
     b: String,
+    a: String,
 }
 
 impl Serialize for Color {
@@ -439,18 +998,23 @@ impl Serialize for Color {
     {
         use serde::ser::SerializeMap;
 
-        let mut map = serializer.serialize_map(Some(8))?;
+        let mut map = serializer.serialize_map(Some(16))?;
         map.serialize_entry("hex", &self.to_hex())?;
+        map.serialize_entry("hex-upper", &self.to_hex().to_uppercase())?;
         map.serialize_entry("hex-r", &self.hex.0)?;
         map.serialize_entry("hex-g", &self.hex.1)?;
         map.serialize_entry("hex-b", &self.hex.2)?;
+        map.serialize_entry("hex-a", &self.hex_alpha())?;
         let hex_bgr = format!("{}{}{}", self.hex.2, self.hex.1, self.hex.0);
         map.serialize_entry("hex-bgr", &hex_bgr)?;
+        map.serialize_entry("hex-rgba", &self.to_hex_rgba())?;
+        map.serialize_entry("hex16", &self.to_hex16())?;
 
         let rgb = RgbSer {
             r: self.rgb.0,
             g: self.rgb.1,
             b: self.rgb.2,
+            a: self.alpha,
         };
         map.serialize_entry("rgb", &rgb)?;
 
@@ -465,9 +1029,28 @@ impl Serialize for Color {
             r: format!("{:.8}", f64::from(self.dec.0)),
             g: format!("{:.8}", f64::from(self.dec.1)),
             b: format!("{:.8}", f64::from(self.dec.2)),
+            a: format!("{:.8}", f64::from(self.alpha) / 255.0),
         };
         map.serialize_entry("dec", &dec)?;
 
+        let (h, s, l) = self.to_hsl();
+        let hsl = HslSer {
+            h: format!("{h:.2}"),
+            s: format!("{:.2}", s * 100.0),
+            l: format!("{:.2}", l * 100.0),
+        };
+        map.serialize_entry("hsl", &hsl)?;
+
+        let (l, c, h) = self.to_oklch();
+        let oklch = OklchSer {
+            l: format!("{l:.4}"),
+            c: format!("{c:.4}"),
+            h: format!("{h:.2}"),
+        };
+        map.serialize_entry("oklch", &oklch)?;
+        map.serialize_entry("ansi256", &self.to_ansi256())?;
+        map.serialize_entry("xterm", &self.to_xterm())?;
+
         map.end()
     }
 }
@@ -476,6 +1059,154 @@ impl Serialize for Color {
 mod tests {
     use super::*;
 
+    #[test]
+    fn relative_luminance_and_contrast_ratio() {
+        let black = Color::new("000000", None, None).expect("unable to create color");
+        let white = Color::new("ffffff", None, None).expect("unable to create color");
+        let gray = Color::new("777777", None, None).expect("unable to create color");
+
+        assert!(black.relative_luminance().abs() < f64::EPSILON);
+        assert!((white.relative_luminance() - 1.0).abs() < 1e-9);
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-9);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 1e-9);
+        assert!((white.contrast_ratio(&white) - 1.0).abs() < f64::EPSILON);
+        assert_eq!(format!("{:.2}", gray.contrast_ratio(&white)), "4.48");
+    }
+
+    #[test]
+    fn color_space_coordinates() {
+        let white = Color::new("ffffff", None, None).expect("unable to create color");
+        let red = Color::new("ff0000", None, None).expect("unable to create color");
+        let round = |(x, y, z): (f64, f64, f64)| format!("{x:.3} {y:.3} {z:.3}");
+
+        assert_eq!(round(white.to_lab()), "100.000 -0.000 0.000");
+        assert_eq!(round(red.to_lab()), "53.241 80.092 67.203");
+        assert_eq!(round(red.to_oklab()), "0.628 0.225 0.126");
+        assert_eq!(round(red.to_oklch()), "0.628 0.258 29.234");
+    }
+
+    #[test]
+    fn oklch_variants_step_lightness_evenly() {
+        for hex in ["59c2ff", "ffb454", "f07178"] {
+            let color = Color::new(hex, None, None).expect("unable to create color");
+            let (l, _, _) = color.to_oklch();
+
+            for (variant, step) in [
+                (ColorVariant::Dim, -OKLCH_DL),
+                (ColorVariant::Bright, OKLCH_DL),
+            ] {
+                let derived = color
+                    .try_to_variant_with(&variant, Derivation::Oklch)
+                    .expect("unable to derive variant");
+                let (derived_l, _, _) = derived.to_oklch();
+
+                assert!((derived_l - l - step).abs() < 0.005, "{hex} {variant}");
+            }
+        }
+
+        let white = Color::new("ffffff", None, None).expect("unable to create color");
+        let white_bright = white
+            .try_to_variant_with(&ColorVariant::Bright, Derivation::Oklch)
+            .expect("unable to derive variant");
+
+        assert_eq!(white_bright.to_hex(), "ffffff");
+    }
+
+    #[test]
+    fn hsl_derivation_matches_try_to_variant() {
+        let color = Color::new("59c2ff", None, None).expect("unable to create color");
+
+        for variant in ColorVariant::get_list() {
+            assert_eq!(
+                color
+                    .try_to_variant_with(variant, Derivation::Hsl)
+                    .expect("unable to derive variant")
+                    .to_hex(),
+                color
+                    .try_to_variant(variant)
+                    .expect("unable to derive variant")
+                    .to_hex()
+            );
+        }
+    }
+
+    #[test]
+    fn derives_supplemental_colors() {
+        let red = Color::new("cc241d", Some(ColorName::Red), None).expect("unable to create color");
+        let orange =
+            Color::new("d65d0e", Some(ColorName::Orange), None).expect("unable to create color");
+        let derive = |color: &Color, target: &ColorName| {
+            color
+                .try_to_color(target)
+                .expect("unable to derive color")
+                .to_hex()
+        };
+
+        assert_eq!(derive(&red, &ColorName::Orange), "cc6d1d");
+        assert_eq!(derive(&red, &ColorName::Brown), "3c2014");
+        assert_eq!(derive(&orange, &ColorName::Brown), "3b1d10");
+        assert!(matches!(
+            red.try_to_color(&ColorName::Orange)
+                .expect("unable to derive color")
+                .name,
+            ColorName::Orange
+        ));
+    }
+
+    #[test]
+    fn unsupported_derivation_lists_supported_derivations() {
+        let blue =
+            Color::new("458588", Some(ColorName::Blue), None).expect("unable to create color");
+        let err = blue
+            .try_to_color(&ColorName::Orange)
+            .expect_err("blue→orange should be unsupported");
+
+        assert_eq!(
+            err.to_string(),
+            "unsupported color derivation: cannot derive orange from blue (supported: yellow→orange, red→orange, yellow→brown, orange→brown, red→brown)"
+        );
+    }
+
+    #[test]
+    fn derives_gray_from_black_and_white() {
+        let black = Color::new("1d2021", None, None).expect("unable to create color");
+        let white = Color::new("fbf1c7", None, None).expect("unable to create color");
+        let gray = |tint| {
+            Color::try_to_gray(&black, &white, tint)
+                .expect("unable to derive gray")
+                .to_hex()
+        };
+
+        assert_eq!(gray(0.0), "808080");
+        assert_eq!(gray(0.5), "828078");
+        assert_eq!(gray(1.0), "83816f");
+    }
+
+    #[test]
+    fn delta_e_distances() {
+        let red = Color::new("ff0000", None, None).expect("unable to create color");
+        let near_red = Color::new("fe0101", None, None).expect("unable to create color");
+        let blue = Color::new("0000ff", None, None).expect("unable to create color");
+
+        assert!(red.delta_e_2000(&red).abs() < f64::EPSILON);
+        assert!(red.delta_e_2000(&near_red) < 1.0);
+        assert_eq!(format!("{:.2}", red.delta_e_2000(&blue)), "52.88");
+        assert!(red.delta_e_ok(&near_red) < 0.02);
+        assert_eq!(format!("{:.3}", red.delta_e_ok(&blue)), "0.537");
+    }
+
+    #[test]
+    fn apca_contrast_depends_on_polarity() {
+        let black = Color::new("000000", None, None).expect("unable to create color");
+        let white = Color::new("ffffff", None, None).expect("unable to create color");
+        let gray = Color::new("888888", None, None).expect("unable to create color");
+
+        assert_eq!(format!("{:.1}", black.apca_contrast(&white)), "106.0");
+        assert_eq!(format!("{:.1}", white.apca_contrast(&black)), "-107.9");
+        assert_eq!(format!("{:.1}", gray.apca_contrast(&white)), "63.1");
+        assert!(gray.apca_contrast(&gray).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn serializes_to_color_object() {
         let color = Color::new("#AABBCC", Some(ColorName::Blue), Some(ColorVariant::Normal))
@@ -552,4 +1283,21 @@ mod tests {
             .as_str()
             .is_some());
     }
+
+    #[test]
+    fn parses_and_blends_alpha() {
+        let short = Color::new("#f008", None, None).expect("unable to create color");
+        let selection = Color::new("#33467C80", None, None).expect("unable to create color");
+        let background = Color::new("#1a1b26", None, None).expect("unable to create color");
+
+        assert_eq!(short.to_hex_rgba(), "ff000088");
+        assert_eq!(selection.to_hex(), "33467c");
+        assert_eq!(selection.alpha, 128);
+        assert_eq!(selection.to_string(), "#33467c80");
+        assert!(background.is_opaque());
+        assert_eq!(background.to_string(), "#1a1b26");
+        assert_eq!(selection.blend_over(&background).to_hex(), "273151");
+        assert!(selection.blend_over(&background).is_opaque());
+        assert!(Color::new("#33467c8", None, None).is_err());
+    }
 }
@@ -0,0 +1,152 @@
+use palette::{FromColor, Hsl, OklabHue, Oklch, RgbHue, Srgb};
+
+use crate::scheme::color::oklch_to_srgb_in_gamut;
+
+/// Converts a CSS color function or named color into an 8-digit hex string (`rrggbbaa`).
+///
+/// Supported inputs, case-insensitive, with space or comma separated arguments and an optional
+/// `/ alpha`:
+///
+/// - `rgb(r g b)` / `rgba(r, g, b, a)`: channels in `0`–`255` or `0%`–`100%`
+/// - `hsl(h s l)` / `hsla(h, s, l, a)`: hue in degrees, saturation and lightness in
+///   `0%`–`100%`
+/// - `oklch(l c h)`: lightness in `0`–`1` or `0%`–`100%`, chroma, and hue in degrees. Colors
+///   outside the sRGB gamut have their chroma reduced until they fit
+/// - CSS named colors like `rebeccapurple`
+///
+/// Alpha is a number in `0`–`1` or a percentage. Returns `None` if `input` is none of these.
+pub fn to_hex(input: &str) -> Option<String> {
+    let input = input.trim().to_lowercase();
+    let Some((function, arguments)) = input
+        .strip_suffix(')')
+        .and_then(|value| value.split_once('('))
+    else {
+        let named: Srgb<u8> = palette::named::from_str(&input)?;
+
+        return Some(format!(
+            "{:02x}{:02x}{:02x}ff",
+            named.red, named.green, named.blue
+        ));
+    };
+    let (channels, alpha) = split_arguments(arguments)?;
+    let [first, second, third] = channels.as_slice() else {
+        return None;
+    };
+    let rgb: Srgb<f32> = match function.trim() {
+        "rgb" | "rgba" => Srgb::new(
+            parse_rgb_channel(first)?,
+            parse_rgb_channel(second)?,
+            parse_rgb_channel(third)?,
+        ),
+        "hsl" | "hsla" => Srgb::from_color(Hsl::new(
+            RgbHue::from_degrees(parse_hue(first)?),
+            parse_percentage(second)?,
+            parse_percentage(third)?,
+        )),
+        "oklch" => {
+            let oklch = Oklch::new(
+                f64::from(parse_unit_or_percentage(first)?),
+                f64::from(parse_number(second)?.max(0.0)),
+                OklabHue::from_degrees(f64::from(parse_hue(third)?)),
+            );
+
+            oklch_to_srgb_in_gamut(oklch).into_format()
+        }
+        _ => return None,
+    };
+    let alpha = alpha.map_or(Some(1.0), parse_unit_or_percentage)?;
+    let [r, g, b, a] = [rgb.red, rgb.green, rgb.blue, alpha].map(to_u8);
+
+    Some(format!("{r:02x}{g:02x}{b:02x}{a:02x}"))
+}
+
+/// Splits function arguments into channels and an optional alpha.
+///
+/// Accepts both `a b c / alpha` and `a, b, c, alpha`.
+fn split_arguments(arguments: &str) -> Option<(Vec<&str>, Option<&str>)> {
+    let (channels, alpha) = match arguments.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha.trim())),
+        None => (arguments, None),
+    };
+    let mut channels: Vec<&str> = channels
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|value| !value.is_empty())
+        .collect();
+
+    match (channels.len(), alpha) {
+        (3, _) => Some((channels, alpha)),
+        (4, None) => {
+            let alpha = channels.pop();
+
+            Some((channels, alpha))
+        }
+        _ => None,
+    }
+}
+
+fn parse_number(value: &str) -> Option<f32> {
+    value
+        .parse::<f32>()
+        .ok()
+        .filter(|number| number.is_finite())
+}
+
+/// Parses an `rgb()` channel, `0`–`255` or a percentage, into `0.0`–`1.0`.
+fn parse_rgb_channel(value: &str) -> Option<f32> {
+    let (number, scale) = value
+        .strip_suffix('%')
+        .map_or((value, 255.0), |percentage| (percentage, 100.0));
+
+    parse_number(number).map(|number| number / scale)
+}
+
+/// Parses a percentage, with or without `%`, into `0.0`–`1.0`.
+fn parse_percentage(value: &str) -> Option<f32> {
+    parse_number(value.strip_suffix('%').unwrap_or(value))
+        .map(|number| (number / 100.0).clamp(0.0, 1.0))
+}
+
+/// Parses a number in `0`–`1` or a percentage into `0.0`–`1.0`.
+fn parse_unit_or_percentage(value: &str) -> Option<f32> {
+    if value.ends_with('%') {
+        parse_percentage(value)
+    } else {
+        parse_number(value).map(|number| number.clamp(0.0, 1.0))
+    }
+}
+
+/// Parses a hue in degrees, with or without `deg`.
+fn parse_hue(value: &str) -> Option<f32> {
+    parse_number(value.strip_suffix("deg").unwrap_or(value))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_u8(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_hex;
+
+    #[test]
+    fn converts_color_functions_and_names() {
+        assert_eq!(to_hex("rgb(204 36 29)").as_deref(), Some("cc241dff"));
+        assert_eq!(
+            to_hex("rgba(204, 36, 29, 0.5)").as_deref(),
+            Some("cc241d80")
+        );
+        assert_eq!(to_hex("rgb(100% 0% 0% / 50%)").as_deref(), Some("ff000080"));
+        assert_eq!(to_hex("hsl(120deg 100% 25%)").as_deref(), Some("008000ff"));
+        assert_eq!(to_hex("HSL(0, 100%, 50%)").as_deref(), Some("ff0000ff"));
+        assert_eq!(
+            to_hex("oklch(62.8% 0.2577 29.23)").as_deref(),
+            Some("ff0000ff")
+        );
+        assert_eq!(to_hex("oklch(0.7 0.4 150)").as_deref(), Some("00be58ff"));
+        assert_eq!(to_hex("rebeccapurple").as_deref(), Some("663399ff"));
+        assert_eq!(to_hex("rgb(1 2)"), None);
+        assert_eq!(to_hex("lab(50 20 30)"), None);
+        assert_eq!(to_hex("not-a-color"), None);
+    }
+}
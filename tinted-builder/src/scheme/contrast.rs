@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::scheme::tinted8::structure::Syntax;
+use crate::{Color, Scheme};
+
+/// The WCAG 2.x AA minimum contrast ratio for normal text.
+pub const WCAG_AA_CONTRAST_RATIO: f64 = 4.5;
+
+/// The WCAG 2.x AAA minimum contrast ratio for normal text.
+pub const WCAG_AAA_CONTRAST_RATIO: f64 = 7.0;
+
+/// The contrast of a single foreground/background pair of a scheme.
+#[derive(Debug, Clone)]
+pub struct ContrastCheck {
+    /// The scheme property used as the foreground, e.g. `base05` or `syntax.comment`.
+    pub foreground_key: String,
+    /// The scheme property used as the background, e.g. `base00` or
+    /// `ui.global.background.normal`.
+    pub background_key: String,
+    pub foreground: Color,
+    pub background: Color,
+    /// The WCAG 2.x contrast ratio, see `Color::contrast_ratio`.
+    pub contrast_ratio: f64,
+    /// The APCA lightness contrast of the foreground on the background, see
+    /// `Color::apca_contrast`.
+    pub apca_contrast: f64,
+}
+
+impl ContrastCheck {
+    /// Computes the contrast of `foreground` on `background`.
+    #[must_use]
+    pub fn new(
+        foreground_key: impl Into<String>,
+        foreground: &Color,
+        background_key: impl Into<String>,
+        background: &Color,
+    ) -> Self {
+        Self {
+            foreground_key: foreground_key.into(),
+            background_key: background_key.into(),
+            foreground: foreground.clone(),
+            background: background.clone(),
+            contrast_ratio: foreground.contrast_ratio(background),
+            apca_contrast: foreground.apca_contrast(background),
+        }
+    }
+
+    /// Returns `true` if the WCAG contrast ratio is at least `min_contrast_ratio`.
+    #[must_use]
+    pub fn passes(&self, min_contrast_ratio: f64) -> bool {
+        self.contrast_ratio >= min_contrast_ratio
+    }
+}
+
+impl fmt::Display for ContrastCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) on {} ({}): {:.2}:1, APCA Lc {:.1}",
+            self.foreground_key,
+            self.foreground,
+            self.background_key,
+            self.background,
+            self.contrast_ratio,
+            self.apca_contrast
+        )
+    }
+}
+
+/// The contrast of the text/background pairs of a scheme.
+///
+/// Created with `Scheme::contrast_report`.
+#[derive(Debug, Clone, Default)]
+pub struct ContrastReport {
+    pub checks: Vec<ContrastCheck>,
+}
+
+impl ContrastReport {
+    /// Returns the checks with a WCAG contrast ratio below `min_contrast_ratio`.
+    #[must_use]
+    pub fn failing(&self, min_contrast_ratio: f64) -> Vec<&ContrastCheck> {
+        self.checks
+            .iter()
+            .filter(|check| !check.passes(min_contrast_ratio))
+            .collect()
+    }
+
+    /// Returns `true` if every check has a WCAG contrast ratio of at least `min_contrast_ratio`.
+    #[must_use]
+    pub fn passes(&self, min_contrast_ratio: f64) -> bool {
+        self.checks
+            .iter()
+            .all(|check| check.passes(min_contrast_ratio))
+    }
+
+    /// Returns the check with the lowest WCAG contrast ratio, if any.
+    #[must_use]
+    pub fn lowest(&self) -> Option<&ContrastCheck> {
+        self.checks
+            .iter()
+            .min_by(|a, b| a.contrast_ratio.total_cmp(&b.contrast_ratio))
+    }
+}
+
+impl fmt::Display for ContrastReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            writeln!(f, "{check}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Scheme {
+    /// Checks the contrast of the scheme's text/background pairs.
+    ///
+    /// - Base16 and Base24: `base05` on `base00`
+    /// - Tinted8: `ui.global.foreground.normal` and every `syntax` color on
+    ///   `ui.global.background.normal`
+    #[must_use]
+    pub fn contrast_report(&self) -> ContrastReport {
+        let checks = match self {
+            Self::Base16(scheme) => base_checks(&scheme.palette),
+            Self::Base24(scheme) => base_checks(&scheme.palette),
+            Self::Tinted8(scheme) => {
+                let background_key = "ui.global.background.normal";
+                let background = &scheme.ui.global.background.normal;
+                let mut checks = vec![ContrastCheck::new(
+                    "ui.global.foreground.normal",
+                    &scheme.ui.global.foreground.normal,
+                    background_key,
+                    background,
+                )];
+
+                checks.extend(Syntax::get_property_list().iter().map(|key| {
+                    ContrastCheck::new(
+                        format!("syntax.{key}"),
+                        scheme.syntax.get_color(key),
+                        background_key,
+                        background,
+                    )
+                }));
+
+                checks
+            }
+        };
+
+        ContrastReport { checks }
+    }
+}
+
+/// Returns the `base05` on `base00` check for a Base16/Base24 palette.
+fn base_checks(palette: &HashMap<String, Color>) -> Vec<ContrastCheck> {
+    match (palette.get("base05"), palette.get("base00")) {
+        (Some(foreground), Some(background)) => {
+            vec![ContrastCheck::new(
+                "base05", foreground, "base00", background,
+            )]
+        }
+        _ => vec![],
+    }
+}
@@ -0,0 +1,292 @@
+use palette::{LinSrgb, Srgb};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Color, Scheme, TintedBuilderError};
+
+/// The CIEDE2000 distance below which two colors are reported as indistinguishable by
+/// `Scheme::cvd_report`.
+pub const CVD_MIN_DELTA_E: f64 = 10.0;
+
+/// Base16/Base24 accent keys compared with each other by `Scheme::cvd_report`.
+const BASE_ACCENT_KEYS: [&str; 8] = [
+    "base08", "base09", "base0A", "base0B", "base0C", "base0D", "base0E", "base0F",
+];
+
+/// Tinted8 `ui.status` colors compared with each other by `Scheme::cvd_report`.
+const TINTED8_STATUS_KEYS: [&str; 4] = ["error", "warning", "info", "success"];
+
+/// Dichromatic color vision deficiencies that can be simulated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ColorVisionDeficiency {
+    /// Missing long-wavelength (red) cones.
+    Protanopia,
+    /// Missing medium-wavelength (green) cones.
+    Deuteranopia,
+    /// Missing short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl ColorVisionDeficiency {
+    #[must_use]
+    pub const fn get_list<'a>() -> &'a [Self] {
+        &[Self::Protanopia, Self::Deuteranopia, Self::Tritanopia]
+    }
+
+    /// Returns the Machado et al. (2009) simulation matrix for severity `1.0`, applied to linear
+    /// sRGB channels.
+    const fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Self::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Self::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+}
+
+impl fmt::Display for ColorVisionDeficiency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Protanopia => write!(f, "protanopia"),
+            Self::Deuteranopia => write!(f, "deuteranopia"),
+            Self::Tritanopia => write!(f, "tritanopia"),
+        }
+    }
+}
+
+impl FromStr for ColorVisionDeficiency {
+    type Err = TintedBuilderError;
+
+    /// Parses a string to create a `ColorVisionDeficiency`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TintedBuilderError` if the input string does not match
+    /// any valid color vision deficiency.
+    fn from_str(deficiency_str: &str) -> Result<Self, Self::Err> {
+        match deficiency_str {
+            "protanopia" => Ok(Self::Protanopia),
+            "deuteranopia" => Ok(Self::Deuteranopia),
+            "tritanopia" => Ok(Self::Tritanopia),
+            _ => Err(TintedBuilderError::InvalidColorVisionDeficiency(
+                deficiency_str.to_string(),
+            )),
+        }
+    }
+}
+
+impl Color {
+    /// Simulates how the color is seen with a color vision deficiency.
+    ///
+    /// Uses the Machado et al. (2009) matrices on linear sRGB. The returned color keeps the
+    /// `name` and `variant` of the original.
+    #[must_use]
+    pub fn simulate_cvd(&self, deficiency: ColorVisionDeficiency) -> Self {
+        let linear: LinSrgb = Srgb::new(self.rgb.0, self.rgb.1, self.rgb.2)
+            .into_format::<f32>()
+            .into_linear();
+        let [r, g, b] = deficiency.matrix().map(|row| {
+            row[2]
+                .mul_add(
+                    linear.blue,
+                    row[0].mul_add(linear.red, row[1] * linear.green),
+                )
+                .clamp(0.0, 1.0)
+        });
+        let simulated: Srgb<u8> = Srgb::from_linear(LinSrgb::new(r, g, b));
+
+        Self::from_rgb(
+            (simulated.red, simulated.green, simulated.blue),
+            self.name.clone(),
+            self.variant.clone(),
+        )
+    }
+}
+
+/// A pair of scheme colors that become hard to tell apart with a color vision deficiency.
+#[derive(Debug, Clone)]
+pub struct CvdConflict {
+    pub deficiency: ColorVisionDeficiency,
+    /// The scheme property of the first color, e.g. `base08` or `palette.red.normal`.
+    pub first_key: String,
+    /// The scheme property of the second color, e.g. `base0B` or `palette.green.normal`.
+    pub second_key: String,
+    pub first: Color,
+    pub second: Color,
+    /// The CIEDE2000 distance between the original colors.
+    pub delta_e: f64,
+    /// The CIEDE2000 distance between the simulated colors.
+    pub simulated_delta_e: f64,
+}
+
+impl fmt::Display for CvdConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} ({}) vs {} ({}): ΔE {:.1} → {:.1}",
+            self.deficiency,
+            self.first_key,
+            self.first,
+            self.second_key,
+            self.second,
+            self.delta_e,
+            self.simulated_delta_e
+        )
+    }
+}
+
+/// The color pairs of a scheme that become indistinguishable with a color vision deficiency.
+///
+/// Created with `Scheme::cvd_report`.
+#[derive(Debug, Clone, Default)]
+pub struct CvdReport {
+    pub conflicts: Vec<CvdConflict>,
+}
+
+impl fmt::Display for CvdReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for conflict in &self.conflicts {
+            writeln!(f, "{conflict}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Scheme {
+    /// Checks which color pairs of the scheme become indistinguishable with each
+    /// `ColorVisionDeficiency`.
+    ///
+    /// A pair conflicts when its colors are at least `min_delta_e` (CIEDE2000) apart, but less
+    /// than `min_delta_e` apart once simulated. The pairs are:
+    ///
+    /// - Base16 and Base24: every pair of the `base08`–`base0F` accents
+    /// - Tinted8: every pair of the `normal` variants of `red`, `orange`, `yellow`, `green`,
+    ///   `cyan`, `blue`, `magenta` and `brown`, and every pair of the `ui.status` colors
+    ///
+    /// `CVD_MIN_DELTA_E` is a reasonable default for `min_delta_e`.
+    #[must_use]
+    pub fn cvd_report(&self, min_delta_e: f64) -> CvdReport {
+        let groups: Vec<Vec<(String, Color)>> = match self {
+            Self::Base16(scheme) => vec![base_accents(&scheme.palette)],
+            Self::Base24(scheme) => vec![base_accents(&scheme.palette)],
+            Self::Tinted8(scheme) => {
+                let palette = &scheme.palette;
+                let status = &scheme.ui.status;
+
+                vec![
+                    [
+                        ("red", &palette.red_normal),
+                        ("orange", &palette.orange_normal),
+                        ("yellow", &palette.yellow_normal),
+                        ("green", &palette.green_normal),
+                        ("cyan", &palette.cyan_normal),
+                        ("blue", &palette.blue_normal),
+                        ("magenta", &palette.magenta_normal),
+                        ("brown", &palette.brown_normal),
+                    ]
+                    .into_iter()
+                    .map(|(name, color)| (format!("palette.{name}.normal"), color.clone()))
+                    .collect(),
+                    TINTED8_STATUS_KEYS
+                        .into_iter()
+                        .zip([
+                            &status.error,
+                            &status.warning,
+                            &status.info,
+                            &status.success,
+                        ])
+                        .map(|(name, color)| (format!("ui.status.{name}"), color.clone()))
+                        .collect(),
+                ]
+            }
+        };
+        let mut conflicts: Vec<CvdConflict> = vec![];
+
+        for deficiency in ColorVisionDeficiency::get_list() {
+            for colors in &groups {
+                for (index, (first_key, first)) in colors.iter().enumerate() {
+                    for (second_key, second) in &colors[index + 1..] {
+                        let delta_e = first.delta_e_2000(second);
+                        let simulated_delta_e = first
+                            .simulate_cvd(*deficiency)
+                            .delta_e_2000(&second.simulate_cvd(*deficiency));
+
+                        if delta_e >= min_delta_e && simulated_delta_e < min_delta_e {
+                            conflicts.push(CvdConflict {
+                                deficiency: *deficiency,
+                                first_key: first_key.clone(),
+                                second_key: second_key.clone(),
+                                first: first.clone(),
+                                second: second.clone(),
+                                delta_e,
+                                simulated_delta_e,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        CvdReport { conflicts }
+    }
+}
+
+/// Returns the `base08`–`base0F` accents of a Base16/Base24 palette.
+fn base_accents(palette: &HashMap<String, Color>) -> Vec<(String, Color)> {
+    BASE_ACCENT_KEYS
+        .iter()
+        .filter_map(|key| {
+            palette
+                .get(*key)
+                .map(|color| ((*key).to_string(), color.clone()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulates_color_vision_deficiencies() {
+        let red = Color::new("#cc241d", None, None).expect("unable to create color");
+
+        assert_eq!(
+            red.simulate_cvd(ColorVisionDeficiency::Protanopia).to_hex(),
+            "5c5219"
+        );
+        assert_eq!(
+            red.simulate_cvd(ColorVisionDeficiency::Deuteranopia)
+                .to_hex(),
+            "857611"
+        );
+        assert_eq!(
+            red.simulate_cvd(ColorVisionDeficiency::Tritanopia).to_hex(),
+            "e10025"
+        );
+    }
+
+    #[test]
+    fn simulation_keeps_grays() {
+        let gray = Color::new("#808080", None, None).expect("unable to create color");
+
+        for deficiency in ColorVisionDeficiency::get_list() {
+            assert_eq!(gray.simulate_cvd(*deficiency).to_hex(), "808080");
+        }
+    }
+}
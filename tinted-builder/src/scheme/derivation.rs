@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Color, ColorName, ColorVariant, TintedBuilderError};
+
+/// Color space in which `dim` and `bright` variants are derived from `normal` colors.
+///
+/// `Hsl` is the Tinted8 spec default. `Oklch` changes lightness in the perceptual OKLCH space,
+/// which gives even steps across hues, and reduces chroma where needed to stay within sRGB.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum Derivation {
+    #[default]
+    Hsl,
+    Oklch,
+}
+
+impl fmt::Display for Derivation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hsl => write!(f, "hsl"),
+            Self::Oklch => write!(f, "oklch"),
+        }
+    }
+}
+
+impl FromStr for Derivation {
+    type Err = TintedBuilderError;
+
+    /// Parses a string to create a `Derivation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TintedBuilderError` if the input string does not match
+    /// any valid derivation.
+    fn from_str(derivation_str: &str) -> Result<Self, Self::Err> {
+        match derivation_str {
+            "hsl" => Ok(Self::Hsl),
+            "oklch" => Ok(Self::Oklch),
+            _ => Err(TintedBuilderError::InvalidDerivation(
+                derivation_str.to_string(),
+            )),
+        }
+    }
+}
+
+impl Derivation {
+    #[must_use]
+    pub const fn get_list<'a>() -> &'a [Self] {
+        &[Self::Hsl, Self::Oklch]
+    }
+}
+
+/// Rules used by `Palette::try_from_basic_with` to derive the Tinted8 palette colors a scheme
+/// does not set.
+///
+/// Every method defaults to the Tinted8 builder rules, so an implementation only needs to
+/// override the derivations it changes. `Derivation` implements this trait for the built-in
+/// color spaces.
+pub trait ColorDerivation {
+    /// Derives a `dim` or `bright` variant from a `normal` color.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the variant cannot be derived.
+    fn derive_variant(
+        &self,
+        color: &Color,
+        color_variant: &ColorVariant,
+    ) -> Result<Color, TintedBuilderError> {
+        color.try_to_variant(color_variant)
+    }
+
+    /// Derives a missing supplemental `normal` color (`orange` or `brown`) from the 8 base
+    /// `normal` colors of the palette.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the color cannot be derived.
+    fn derive_color(
+        &self,
+        target_color_name: &ColorName,
+        normal_colors: &[Color],
+    ) -> Result<Color, TintedBuilderError> {
+        normal_colors
+            .iter()
+            .find(|color| matches!(color.name, ColorName::Yellow))
+            .ok_or_else(|| TintedBuilderError::SchemeMissingProperty("palette.yellow".to_string()))?
+            .try_to_color(target_color_name)
+    }
+
+    /// Derives a missing `gray` from the `black` and `white` `normal` colors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the color cannot be derived.
+    fn derive_gray(&self, black: &Color, white: &Color) -> Result<Color, TintedBuilderError> {
+        Color::try_to_gray(black, white, 0.0)
+    }
+}
+
+impl ColorDerivation for Derivation {
+    fn derive_variant(
+        &self,
+        color: &Color,
+        color_variant: &ColorVariant,
+    ) -> Result<Color, TintedBuilderError> {
+        color.try_to_variant_with(color_variant, *self)
+    }
+}
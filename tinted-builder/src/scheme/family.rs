@@ -0,0 +1,143 @@
+use regex::Regex;
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+
+use crate::utils::slugify;
+use crate::{Scheme, SchemeVariant};
+
+/// Schemes that belong to the same family, e.g. the dark and light variants of Gruvbox.
+///
+/// Schemes are grouped by `Scheme::get_scheme_family` across scheme systems and variants. In
+/// list templates a family is written as `name`, `slug` and its `schemes`, together with the
+/// `dark-schemes` and `light-schemes` subsets.
+#[derive(Debug, Clone)]
+pub struct SchemeFamily {
+    /// The display name of the family, taken from its first scheme.
+    pub name: String,
+    /// The slug of the family, which schemes are grouped by.
+    pub slug: String,
+    /// The schemes of the family: dark variants first, then by name and system.
+    pub schemes: Vec<Scheme>,
+}
+
+impl SchemeFamily {
+    /// Groups schemes into families, sorted by family slug.
+    #[must_use]
+    pub fn from_schemes<'a>(schemes: impl IntoIterator<Item = &'a Scheme>) -> Vec<Self> {
+        let mut families: Vec<Self> = vec![];
+
+        for scheme in schemes {
+            let name = scheme.get_scheme_family();
+            let slug = slugify(&name);
+
+            match families.iter_mut().find(|family| family.slug == slug) {
+                Some(family) => family.schemes.push(scheme.clone()),
+                None => families.push(Self {
+                    name,
+                    slug,
+                    schemes: vec![scheme.clone()],
+                }),
+            }
+        }
+
+        for family in &mut families {
+            family.schemes.sort_by_key(|scheme| {
+                (
+                    scheme.get_scheme_variant() != SchemeVariant::Dark,
+                    scheme.get_scheme_name(),
+                    scheme.get_scheme_system().to_string(),
+                )
+            });
+        }
+        families.sort_by(|a, b| a.slug.cmp(&b.slug));
+
+        families
+    }
+
+    /// Returns the dark schemes of the family.
+    #[must_use]
+    pub fn dark_schemes(&self) -> Vec<&Scheme> {
+        self.variant_schemes(&SchemeVariant::Dark)
+    }
+
+    /// Returns the light schemes of the family.
+    #[must_use]
+    pub fn light_schemes(&self) -> Vec<&Scheme> {
+        self.variant_schemes(&SchemeVariant::Light)
+    }
+
+    fn variant_schemes(&self, variant: &SchemeVariant) -> Vec<&Scheme> {
+        self.schemes
+            .iter()
+            .filter(|scheme| &scheme.get_scheme_variant() == variant)
+            .collect()
+    }
+}
+
+impl Serialize for SchemeFamily {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(5))?;
+
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("slug", &self.slug)?;
+        map.serialize_entry("schemes", &self.schemes)?;
+        map.serialize_entry("dark-schemes", &self.dark_schemes())?;
+        map.serialize_entry("light-schemes", &self.light_schemes())?;
+        map.end()
+    }
+}
+
+impl Scheme {
+    /// Returns the family of the scheme.
+    ///
+    /// Tinted8 schemes use their `family` property. Other schemes, and Tinted8 schemes without a
+    /// `family`, use their name without the words "Dark" and "Light", so that "Solarized Dark"
+    /// and "Solarized Light" are both in the "Solarized" family.
+    #[must_use]
+    pub fn get_scheme_family(&self) -> String {
+        match self {
+            Self::Tinted8(scheme) if scheme.scheme.family.is_some() => {
+                scheme.scheme.family.clone().unwrap_or_default()
+            }
+            _ => family_from_name(&self.get_scheme_name()),
+        }
+    }
+}
+
+/// Removes the words "dark" and "light" from a scheme name, e.g. "Default (Dark)" becomes
+/// "Default". Names that consist of nothing else are returned as they are.
+fn family_from_name(name: &str) -> String {
+    let family = strip_variant_words(name);
+
+    if family.is_empty() {
+        name.to_string()
+    } else {
+        family
+    }
+}
+
+/// Removes the words "dark" and "light" from `text`, along with the spaces, commas, dashes and
+/// parentheses around them.
+pub fn strip_variant_words(text: &str) -> String {
+    let re = Regex::new(r"(?i)[\s(,-]*\b(dark|light)\b\)?").expect("Unable to unwrap regex");
+
+    re.replace_all(text, "").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removes_variant_words_from_names() {
+        assert_eq!(family_from_name("Solarized Dark"), "Solarized");
+        assert_eq!(family_from_name("Default (Light)"), "Default");
+        assert_eq!(family_from_name("Gruvbox dark, hard"), "Gruvbox, hard");
+        assert_eq!(family_from_name("Gruvbox light, hard"), "Gruvbox, hard");
+        assert_eq!(family_from_name("Darkviolet"), "Darkviolet");
+        assert_eq!(family_from_name("Dark"), "Dark");
+    }
+}
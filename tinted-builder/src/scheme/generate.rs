@@ -0,0 +1,604 @@
+use palette::{FromColor, OklabHue, Oklch, Srgb};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use crate::scheme::base24;
+use crate::scheme::color::oklch_to_srgb_in_gamut;
+use crate::utils::slugify;
+use crate::{
+    Color, ColorName, ColorVariant, Derivation, Scheme, SchemeSystem, SchemeVariant,
+    TintedBuilderError, WCAG_AA_CONTRAST_RATIO,
+};
+
+/// The number of clusters an image is quantized into by `Scheme::from_pixels`.
+const IMAGE_CLUSTER_COUNT: usize = 16;
+
+/// The maximum number of pixels sampled from an image; larger images are sampled evenly.
+const MAX_SAMPLES: usize = 16_384;
+
+/// The maximum number of k-means iterations.
+const MAX_ITERATIONS: usize = 24;
+
+/// The position of `base00`–`base07` between the background and the opposite end of the ramp.
+const RAMP_STOPS: [f32; 8] = [0.0, 0.07, 0.15, 0.38, 0.55, 0.8, 0.9, 1.0];
+
+/// The accent palette keys with their target OKLCH hue in degrees. `base0F` shares the hue of
+/// `base09` and is told apart by its lower lightness and chroma.
+const ACCENT_HUES: [(&str, f64); 8] = [
+    ("base08", 25.0),
+    ("base09", 55.0),
+    ("base0A", 95.0),
+    ("base0B", 145.0),
+    ("base0C", 200.0),
+    ("base0D", 255.0),
+    ("base0E", 325.0),
+    ("base0F", 55.0),
+];
+
+/// The Base24 bright accent keys and the accent keys they are derived from.
+const BRIGHT_ACCENT_KEYS: [(&str, &str); 6] = [
+    ("base12", "base08"),
+    ("base13", "base0A"),
+    ("base14", "base0B"),
+    ("base15", "base0C"),
+    ("base16", "base0D"),
+    ("base17", "base0E"),
+];
+
+/// The most an accent hue is pulled away from its target hue towards a color of the source.
+const MAX_HUE_SHIFT: f64 = 20.0;
+
+/// The chroma below which a color is considered gray and not used for accents.
+pub const MIN_ACCENT_CHROMA: f64 = 0.04;
+
+/// The minimum contrast ratio of each accent on `base00`, the WCAG 2.x ratio for large text and
+/// user interface components.
+const MIN_ACCENT_CONTRAST_RATIO: f64 = 3.0;
+
+/// Options for generating a scheme with `Scheme::from_pixels` or `Scheme::from_seed_colors`.
+#[derive(Debug, Clone)]
+pub struct GenerateOptions {
+    /// The scheme name; the slug is derived from it.
+    pub name: String,
+    /// The scheme author.
+    pub author: String,
+    /// The scheme system of the generated scheme.
+    pub system: SchemeSystem,
+    /// The scheme variant, or `None` to infer it from the source colors.
+    pub variant: Option<SchemeVariant>,
+}
+
+/// A quantized color and the share of pixels it represents, see `quantize_pixels`.
+#[derive(Debug, Clone)]
+pub struct ColorCluster {
+    /// The mean color of the cluster.
+    pub color: Color,
+    /// The share of sampled pixels in the cluster, in `0.0`–`1.0`.
+    pub weight: f64,
+}
+
+/// The perceptual starting point of a generated palette, in OKLCH.
+struct PaletteSeed {
+    /// The lightness, chroma and hue of the background.
+    background: (f64, f64, f64),
+    /// The chroma and hue of each accent in `ACCENT_HUES`.
+    accents: [(f64, f64); 8],
+    /// Colors used as they are instead of being generated, by palette key: the background
+    /// `base00` and the accents `base08`–`base0E`.
+    pinned: HashMap<&'static str, Color>,
+}
+
+impl Scheme {
+    /// Generates a scheme from the pixels of an image, e.g. a wallpaper.
+    ///
+    /// The pixels are quantized with `quantize_pixels`. The background is taken from the
+    /// heaviest cluster on the side of the variant (dark or light), and its lightness and chroma
+    /// are clamped so text stays readable. `base00`–`base07` run from the background to the
+    /// opposite lightness in its hue. Each of the eight accents `base08`–`base0F` starts at a
+    /// fixed hue that is pulled towards the most colorful matching cluster, so the accents stay
+    /// distinguishable. Base24 bright accents are derived with `Derivation::Oklch`.
+    ///
+    /// The scheme is generated as Base24 and converted to `options.system` with
+    /// `Scheme::to_system`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TintedBuilderError::SchemeGeneration` if `pixels` is empty, or if `base05` or an
+    /// accent is below the minimum contrast ratio on `base00` (4.5:1 and 3:1), which can happen
+    /// with low-contrast images. Returns an error if the palette cannot be converted to
+    /// `options.system`.
+    pub fn from_pixels(
+        pixels: &[[u8; 3]],
+        options: &GenerateOptions,
+    ) -> Result<Self, TintedBuilderError> {
+        let clusters = quantize_pixels(pixels, IMAGE_CLUSTER_COUNT);
+
+        if clusters.is_empty() {
+            return Err(TintedBuilderError::SchemeGeneration(
+                "no pixels to generate a scheme from".to_string(),
+            ));
+        }
+
+        let colors: Vec<((f64, f64, f64), f64)> = clusters
+            .iter()
+            .map(|cluster| (cluster.color.to_oklch(), cluster.weight))
+            .collect();
+        let variant = options.variant.clone().unwrap_or_else(|| {
+            let lightness: f64 = colors.iter().map(|((l, _, _), weight)| l * weight).sum();
+
+            if lightness < 0.5 {
+                SchemeVariant::Dark
+            } else {
+                SchemeVariant::Light
+            }
+        });
+        let is_background_side = |l: f64| match variant {
+            SchemeVariant::Dark => l < 0.5,
+            SchemeVariant::Light => l >= 0.5,
+        };
+        let background = colors
+            .iter()
+            .find(|((l, _, _), _)| is_background_side(*l))
+            .unwrap_or(&colors[0])
+            .0;
+        let colorful: Vec<&((f64, f64, f64), f64)> = colors
+            .iter()
+            .filter(|((_, c, _), _)| *c >= MIN_ACCENT_CHROMA)
+            .collect();
+        let colorful_weight: f64 = colorful.iter().map(|(_, weight)| weight).sum();
+        let fallback_chroma = if colorful_weight > 0.0 {
+            colorful
+                .iter()
+                .map(|((_, c, _), weight)| c * weight)
+                .sum::<f64>()
+                / colorful_weight
+        } else {
+            0.12
+        }
+        .clamp(0.08, 0.16);
+        // Each colorful cluster only steers the accent with the nearest target hue, so that
+        // neighboring accents do not converge on the same color. `base0F` follows `base09`.
+        let nearest_accent = |hue: f64| {
+            (0..ACCENT_HUES.len() - 1).min_by(|a, b| {
+                hue_difference(ACCENT_HUES[*a].1, hue)
+                    .abs()
+                    .total_cmp(&hue_difference(ACCENT_HUES[*b].1, hue).abs())
+            })
+        };
+        let accent = |index: usize| {
+            let target_hue = ACCENT_HUES[index].1;
+
+            colorful
+                .iter()
+                .filter(|((_, _, h), _)| nearest_accent(*h) == Some(index))
+                .map(|((_, c, h), weight)| (*c, hue_difference(target_hue, *h), weight * c))
+                .max_by(|(_, _, a), (_, _, b)| a.total_cmp(b))
+                .map_or((fallback_chroma, target_hue), |(chroma, difference, _)| {
+                    (
+                        chroma.clamp(0.08, 0.2),
+                        target_hue + difference.clamp(-MAX_HUE_SHIFT, MAX_HUE_SHIFT),
+                    )
+                })
+        };
+        let accents = [0, 1, 2, 3, 4, 5, 6, 1].map(accent);
+
+        let seed = PaletteSeed {
+            background,
+            accents,
+            pinned: HashMap::new(),
+        };
+
+        build_scheme(&seed, &variant, options)
+    }
+
+    /// Generates a scheme from a handful of seed colors, e.g. a background and a favorite blue.
+    ///
+    /// The variant is `options.variant`, or the variant of the first seed's lightness. The seed
+    /// nearest to the variant's end of the lightness range is the background `base00`, and
+    /// `base00`–`base07` run from it to the opposite lightness in its hue. Each colorful seed is
+    /// used as is for the accent `base08`–`base0E` with the nearest free target hue. The other
+    /// accents are synthesized by rotating their target hues by the offset of the first accent
+    /// seed (up to 20°), with the average chroma of the accent seeds, and `base0F` is a darker
+    /// `base09`. Base24 bright accents are derived with `Derivation::Oklch`, like Tinted8
+    /// `bright` variants. A background outside the readable lightness range is adjusted; other
+    /// gray seeds are ignored.
+    ///
+    /// The scheme is generated as Base24 and converted to `options.system` with
+    /// `Scheme::to_system`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TintedBuilderError::SchemeGeneration` if `seeds` is empty, or if `base05` or an
+    /// accent is below the minimum contrast ratio on `base00` (4.5:1 and 3:1), which can happen
+    /// with seeds that are too close to the background.
+    pub fn from_seed_colors(
+        seeds: &[Color],
+        options: &GenerateOptions,
+    ) -> Result<Self, TintedBuilderError> {
+        let first = seeds.first().ok_or_else(|| {
+            TintedBuilderError::SchemeGeneration(
+                "no seed colors to generate a scheme from".to_string(),
+            )
+        })?;
+        let variant = options.variant.clone().unwrap_or_else(|| {
+            if first.to_oklch().0 < 0.5 {
+                SchemeVariant::Dark
+            } else {
+                SchemeVariant::Light
+            }
+        });
+        let colors: Vec<(&Color, (f64, f64, f64))> =
+            seeds.iter().map(|seed| (seed, seed.to_oklch())).collect();
+        let (background_index, (background_color, background)) = colors
+            .iter()
+            .enumerate()
+            .min_by(|(_, (_, (a, _, _))), (_, (_, (b, _, _)))| match variant {
+                SchemeVariant::Dark => a.total_cmp(b),
+                SchemeVariant::Light => b.total_cmp(a),
+            })
+            .ok_or_else(|| TintedBuilderError::SchemeGeneration("no background".to_string()))?;
+        let mut pinned: HashMap<&'static str, Color> =
+            HashMap::from([("base00", (*background_color).clone())]);
+        let mut accent_seeds: Vec<(usize, f64, f64)> = vec![];
+
+        for (_, (color, (_, c, h))) in colors
+            .iter()
+            .enumerate()
+            .filter(|(index, (_, (_, c, _)))| *index != background_index && *c >= MIN_ACCENT_CHROMA)
+        {
+            let target = (0..ACCENT_HUES.len() - 1)
+                .filter(|index| !pinned.contains_key(ACCENT_HUES[*index].0))
+                .min_by(|a, b| {
+                    hue_difference(ACCENT_HUES[*a].1, *h)
+                        .abs()
+                        .total_cmp(&hue_difference(ACCENT_HUES[*b].1, *h).abs())
+                });
+
+            if let Some(target) = target {
+                pinned.insert(ACCENT_HUES[target].0, (*color).clone());
+                accent_seeds.push((target, *c, *h));
+            }
+        }
+
+        let offset = accent_seeds.first().map_or(0.0, |(target, _, h)| {
+            hue_difference(ACCENT_HUES[*target].1, *h).clamp(-MAX_HUE_SHIFT, MAX_HUE_SHIFT)
+        });
+        #[allow(clippy::cast_precision_loss)]
+        let chroma = if accent_seeds.is_empty() {
+            0.12
+        } else {
+            accent_seeds.iter().map(|(_, c, _)| c).sum::<f64>() / accent_seeds.len() as f64
+        }
+        .clamp(0.08, 0.2);
+        let accent = |index: usize| {
+            accent_seeds
+                .iter()
+                .find(|(target, _, _)| *target == index)
+                .map_or((chroma, ACCENT_HUES[index].1 + offset), |(_, c, h)| {
+                    (*c, *h)
+                })
+        };
+        let seed = PaletteSeed {
+            background: *background,
+            accents: [0, 1, 2, 3, 4, 5, 6, 1].map(accent),
+            pinned,
+        };
+
+        build_scheme(&seed, &variant, options)
+    }
+}
+
+/// Quantizes pixels into at most `count` colors with k-means clustering in Oklab.
+///
+/// At most 16384 pixels, spread evenly over `pixels`, are sampled. The initial centroids are
+/// chosen by farthest-point sampling from the pixel nearest to the mean, so the result is
+/// deterministic. Clusters are returned from the heaviest to the lightest; fewer than `count`
+/// clusters are returned when the pixels have fewer distinct colors.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn quantize_pixels(pixels: &[[u8; 3]], count: usize) -> Vec<ColorCluster> {
+    let step = (pixels.len() / MAX_SAMPLES).max(1);
+    let samples: Vec<[f64; 3]> = pixels.iter().step_by(step).copied().map(to_oklab).collect();
+
+    if samples.is_empty() || count == 0 {
+        return vec![];
+    }
+
+    let mut centroids = initial_centroids(&samples, count);
+    let mut assignments = vec![0; samples.len()];
+
+    for _ in 0..MAX_ITERATIONS {
+        for (assignment, sample) in assignments.iter_mut().zip(&samples) {
+            *assignment = nearest_centroid(&centroids, sample);
+        }
+
+        let mut sums = vec![([0.0; 3], 0_usize); centroids.len()];
+        for (assignment, sample) in assignments.iter().zip(&samples) {
+            let (sum, size) = &mut sums[*assignment];
+
+            for (total, value) in sum.iter_mut().zip(sample) {
+                *total += value;
+            }
+            *size += 1;
+        }
+
+        let updated: Vec<[f64; 3]> = sums
+            .iter()
+            .zip(&centroids)
+            .map(|((sum, size), centroid)| {
+                if *size == 0 {
+                    *centroid
+                } else {
+                    sum.map(|total| total / *size as f64)
+                }
+            })
+            .collect();
+
+        if updated == centroids {
+            break;
+        }
+        centroids = updated;
+    }
+
+    let mut sizes = vec![0_usize; centroids.len()];
+    for sample in &samples {
+        sizes[nearest_centroid(&centroids, sample)] += 1;
+    }
+
+    let mut clusters: Vec<ColorCluster> = centroids
+        .iter()
+        .zip(sizes)
+        .filter(|(_, size)| *size > 0)
+        .map(|(centroid, size)| ColorCluster {
+            color: from_oklab(centroid),
+            weight: size as f64 / samples.len() as f64,
+        })
+        .collect();
+    clusters.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+
+    clusters
+}
+
+/// Builds the scheme from a seed, as Base24 converted to `options.system`.
+fn build_scheme(
+    seed: &PaletteSeed,
+    variant: &SchemeVariant,
+    options: &GenerateOptions,
+) -> Result<Scheme, TintedBuilderError> {
+    let palette = build_palette(seed, variant)?;
+
+    validate_contrast(&palette)?;
+
+    let scheme = base24::Scheme {
+        system: SchemeSystem::Base24,
+        name: options.name.clone(),
+        slug: slugify(&options.name),
+        author: options.author.clone(),
+        description: None,
+        variant: variant.clone(),
+        pair: None,
+        palette,
+    };
+    let mut scheme = Scheme::Base24(scheme).to_system(&options.system)?;
+
+    if let Scheme::Tinted8(scheme) = &mut scheme {
+        scheme.scheme.converted_from = None;
+    }
+
+    Ok(scheme)
+}
+
+/// Builds a Base24 palette from a seed.
+fn build_palette(
+    seed: &PaletteSeed,
+    variant: &SchemeVariant,
+) -> Result<HashMap<String, Color>, TintedBuilderError> {
+    let (l, c, h) = seed.background;
+    let background_range = background_lightness_range(variant);
+    let (edge_l, accent_l, brown_l) = match variant {
+        SchemeVariant::Dark => (0.95, 0.74, 0.58),
+        SchemeVariant::Light => (0.24, 0.52, 0.42),
+    };
+    let background_l = l.clamp(*background_range.start(), *background_range.end());
+    let background = match seed.pinned.get("base00") {
+        Some(color) if background_range.contains(&l) => color.clone(),
+        _ => from_oklch(background_l, c.min(0.03), h),
+    };
+    let edge = from_oklch(edge_l, c.min(0.015), h);
+    let mut palette: HashMap<String, Color> = (0..8)
+        .zip(RAMP_STOPS)
+        .map(|(index, stop)| (format!("base0{index}"), background.mix(&edge, stop)))
+        .collect();
+
+    palette.insert(
+        "base10".to_string(),
+        from_oklch(background_l - 0.04, c.min(0.03), h),
+    );
+    palette.insert(
+        "base11".to_string(),
+        from_oklch(background_l - 0.08, c.min(0.03), h),
+    );
+
+    for ((key, _), (chroma, hue)) in ACCENT_HUES.iter().zip(seed.accents) {
+        let color = match seed.pinned.get(key) {
+            Some(color) => color.clone(),
+            None if *key == "base0F" => from_oklch(brown_l, chroma * 0.7, hue),
+            None => from_oklch(accent_l, chroma, hue),
+        };
+
+        palette.insert((*key).to_string(), color);
+    }
+
+    for (bright_key, key) in BRIGHT_ACCENT_KEYS {
+        let bright = palette
+            .get(key)
+            .ok_or_else(|| TintedBuilderError::SchemeMissingProperty(format!("palette.{key}")))?
+            .try_to_variant_with(&ColorVariant::Bright, Derivation::Oklch)?;
+
+        palette.insert(bright_key.to_string(), bright);
+    }
+
+    Ok(palette)
+}
+
+/// Returns the OKLCH lightness range of a readable background for `variant`.
+pub const fn background_lightness_range(variant: &SchemeVariant) -> RangeInclusive<f64> {
+    match variant {
+        SchemeVariant::Dark => 0.16..=0.26,
+        SchemeVariant::Light => 0.94..=0.98,
+    }
+}
+
+/// Returns the OKLCH lightness range of accents for `variant`. Generated accents use the
+/// `accent_l` of `build_palette`, which lies inside it.
+pub const fn accent_lightness_range(variant: &SchemeVariant) -> RangeInclusive<f64> {
+    match variant {
+        SchemeVariant::Dark => 0.65..=0.92,
+        SchemeVariant::Light => 0.45..=0.65,
+    }
+}
+
+/// Checks that `base05` and the accents `base08`–`base0F` are readable on `base00`.
+fn validate_contrast(palette: &HashMap<String, Color>) -> Result<(), TintedBuilderError> {
+    let color = |key: &str| {
+        palette
+            .get(key)
+            .ok_or_else(|| TintedBuilderError::SchemeMissingProperty(format!("palette.{key}")))
+    };
+    let background = color("base00")?;
+    let checks = std::iter::once(("base05", WCAG_AA_CONTRAST_RATIO)).chain(
+        ACCENT_HUES
+            .iter()
+            .map(|(key, _)| (*key, MIN_ACCENT_CONTRAST_RATIO)),
+    );
+
+    for (key, min_ratio) in checks {
+        let foreground = color(key)?;
+        let ratio = foreground.contrast_ratio(background);
+
+        if ratio < min_ratio {
+            return Err(TintedBuilderError::SchemeGeneration(format!(
+                "{key} ({foreground}) has a contrast ratio of {ratio:.2}:1 on base00 ({background}), below {min_ratio}:1"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the samples farthest from each other, starting from the sample nearest to the mean.
+#[allow(clippy::cast_precision_loss)]
+fn initial_centroids(samples: &[[f64; 3]], count: usize) -> Vec<[f64; 3]> {
+    let mean = samples
+        .iter()
+        .fold([0.0; 3], |sum, sample| {
+            [sum[0] + sample[0], sum[1] + sample[1], sum[2] + sample[2]]
+        })
+        .map(|total| total / samples.len() as f64);
+    let first = samples[nearest_centroid(samples, &mean)];
+    let mut centroids = vec![first];
+    let mut distances: Vec<f64> = samples
+        .iter()
+        .map(|sample| distance(sample, &first))
+        .collect();
+
+    while centroids.len() < count {
+        let Some((index, farthest)) = distances
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        else {
+            break;
+        };
+
+        if *farthest <= f64::EPSILON {
+            break;
+        }
+
+        let centroid = samples[index];
+        for (distance_to_nearest, sample) in distances.iter_mut().zip(samples) {
+            *distance_to_nearest = distance_to_nearest.min(distance(sample, &centroid));
+        }
+        centroids.push(centroid);
+    }
+
+    centroids
+}
+
+fn nearest_centroid(centroids: &[[f64; 3]], sample: &[f64; 3]) -> usize {
+    centroids
+        .iter()
+        .map(|centroid| distance(sample, centroid))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(index, _)| index)
+}
+
+/// Returns the squared Euclidean distance of two Oklab colors.
+fn distance(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum()
+}
+
+/// Returns the signed difference from hue `from` to hue `to` in degrees, in `-180`–`180`.
+fn hue_difference(from: f64, to: f64) -> f64 {
+    (to - from + 540.0).rem_euclid(360.0) - 180.0
+}
+
+fn to_oklab(pixel: [u8; 3]) -> [f64; 3] {
+    let oklab = palette::Oklab::from_color(Srgb::from(pixel).into_format::<f64>());
+
+    [oklab.l, oklab.a, oklab.b]
+}
+
+fn from_oklab(oklab: &[f64; 3]) -> Color {
+    let oklch = Oklch::from_color(palette::Oklab::new(oklab[0], oklab[1], oklab[2]));
+
+    from_oklch(oklch.l, oklch.chroma, oklch.hue.into_positive_degrees())
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_oklch(l: f64, chroma: f64, hue: f64) -> Color {
+    let srgb = oklch_to_srgb_in_gamut(Oklch::new(
+        l.clamp(0.0, 1.0),
+        chroma.max(0.0),
+        OklabHue::from_degrees(hue),
+    ));
+    let rgb: [u8; 3] =
+        [srgb.red, srgb.green, srgb.blue].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+
+    Color::from_rgb(rgb.into(), ColorName::Other, ColorVariant::Normal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantizes_distinct_colors_by_weight() {
+        let pixels: Vec<[u8; 3]> = [
+            ([30, 30, 46], 6),
+            ([137, 180, 250], 3),
+            ([243, 139, 168], 1),
+        ]
+        .iter()
+        .flat_map(|(pixel, count)| std::iter::repeat(*pixel).take(*count))
+        .collect();
+
+        let clusters = quantize_pixels(&pixels, 16);
+        let summary: Vec<(String, f64)> = clusters
+            .iter()
+            .map(|cluster| (cluster.color.to_hex(), cluster.weight))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("1e1e2e".to_string(), 0.6),
+                ("89b4fa".to_string(), 0.3),
+                ("f38ba8".to_string(), 0.1),
+            ]
+        );
+        assert!(quantize_pixels(&[], 16).is_empty());
+        assert!((hue_difference(350.0, 10.0) - 20.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,310 @@
+use palette::{OklabHue, Oklch};
+use regex::{Captures, Regex};
+use serde_yaml::{Mapping, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::scheme::color::oklch_to_srgb_in_gamut;
+use crate::scheme::generate::{
+    accent_lightness_range, background_lightness_range, MIN_ACCENT_CHROMA,
+};
+use crate::scheme::tinted8::{self, ColorExpression, UiKey};
+use crate::{Color, Scheme, SchemeVariant, TintedBuilderError};
+
+impl Scheme {
+    /// Returns the scheme in the opposite variant, a light scheme for a dark one and vice versa.
+    ///
+    /// Backgrounds, foregrounds and grays are reflected in OKLCH around a lightness pivot chosen
+    /// so that the background (`base00`, or `ui.global.background.normal` for Tinted8) lands in
+    /// the readable range of the new variant, keeping their contrast to the background. Accents
+    /// are moved from the accent lightness range of the old variant to the one of the new
+    /// variant instead, so that a light yellow does not turn into a dark olive. Chroma and hue
+    /// are kept so that red stays red; chroma is only reduced when the new color does not fit
+    /// the sRGB gamut.
+    ///
+    /// The accents are `base08`–`base0F` and `base12`–`base17` for Base16 and Base24, the
+    /// Tinted8 palette colors other than `black`, `white` and `gray`, and the Tinted8 `syntax`
+    /// colors with chroma. Tinted8 `ui` colors are reflected.
+    ///
+    /// Tinted8 palettes also swap `black` with `white` and `dim` with `bright`, since the
+    /// background of a light scheme is `white` and `bright` variants are the lighter ones.
+    ///
+    /// The words "Dark" and "Light" are swapped in the scheme `name`, `slug` and Tinted8 `style`;
+    /// when there is nothing to swap, the new variant is appended, e.g. "Mocha Light".
+    /// Base16 and Base24 schemes are paired with the scheme they were derived from with `pair`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheme is missing its background color or the inverted Tinted8
+    /// scheme cannot be derived.
+    pub fn invert_variant(&self) -> Result<Self, TintedBuilderError> {
+        let variant = self.get_scheme_variant().opposite();
+
+        match self {
+            Self::Base16(scheme) => {
+                let mut scheme = scheme.clone();
+                scheme.palette = invert_palette(&scheme.palette, &variant)?;
+                scheme.name = invert_words(&scheme.name, &variant, " ");
+                scheme.pair = Some(scheme.slug.clone());
+                scheme.slug = invert_words(&scheme.slug, &variant, "-");
+                scheme.variant = variant;
+
+                Ok(Self::Base16(scheme))
+            }
+            Self::Base24(scheme) => {
+                let mut scheme = scheme.clone();
+                scheme.palette = invert_palette(&scheme.palette, &variant)?;
+                scheme.name = invert_words(&scheme.name, &variant, " ");
+                scheme.pair = Some(scheme.slug.clone());
+                scheme.slug = invert_words(&scheme.slug, &variant, "-");
+                scheme.variant = variant;
+
+                Ok(Self::Base24(scheme))
+            }
+            Self::Tinted8(scheme) => Ok(Self::Tinted8(Box::new(invert_tinted8(scheme, &variant)?))),
+        }
+    }
+}
+
+/// Inverts a Base16/Base24 palette around its `base00`, moving the accents into the accent
+/// lightness range of `variant`.
+fn invert_palette(
+    palette: &HashMap<String, Color>,
+    variant: &SchemeVariant,
+) -> Result<HashMap<String, Color>, TintedBuilderError> {
+    let background = palette
+        .get("base00")
+        .ok_or_else(|| TintedBuilderError::SchemeMissingProperty("palette.base00".to_string()))?;
+    let pivot = Pivot::new(background, variant);
+
+    Ok(palette
+        .iter()
+        .map(|(key, color)| {
+            let color = if is_accent_key(key) {
+                pivot.remap_accent(color)
+            } else {
+                pivot.reflect(color)
+            };
+
+            (key.clone(), color)
+        })
+        .collect())
+}
+
+/// Inverts a Tinted8 scheme through its minimal YAML, so that `dim`/`bright` variants, `ui` and
+/// `syntax` values that were derived are derived again for the new variant. `ui` and `syntax`
+/// expressions are resolved against the original palette before they are reflected.
+fn invert_tinted8(
+    scheme: &tinted8::Scheme,
+    variant: &SchemeVariant,
+) -> Result<tinted8::Scheme, TintedBuilderError> {
+    let pivot = Pivot::new(scheme.ui.get_color(&UiKey::GlobalBackgroundNormal), variant);
+    let mut value: Value = serde_yaml::from_str(&scheme.to_yaml_string()?)?;
+
+    if let Value::Mapping(root) = &mut value {
+        root.insert("variant".into(), variant.to_string().into());
+
+        for key in ["palette", "ui", "syntax"] {
+            if let Some(Value::Mapping(colors)) = root.get_mut(key) {
+                *colors = colors
+                    .iter()
+                    .map(|(color_key, color)| {
+                        let color_key = match (key, color_key.as_str()) {
+                            ("palette", Some(color_key)) => invert_palette_key(color_key).into(),
+                            _ => color_key.clone(),
+                        };
+                        let is_accent = match (key, color_key.as_str()) {
+                            ("palette", Some(name)) => {
+                                !matches!(name.split('-').next(), Some("black" | "white" | "gray"))
+                            }
+                            ("syntax", _) => true,
+                            _ => false,
+                        };
+                        let color = color
+                            .as_str()
+                            .map(|color| match key {
+                                "palette" => Color::new(color, None, None),
+                                _ => ColorExpression::from_str(color)?.resolve(&scheme.palette),
+                            })
+                            .transpose()?
+                            .map_or_else(
+                                || color.clone(),
+                                |c| {
+                                    if is_accent && c.to_oklch().1 >= MIN_ACCENT_CHROMA {
+                                        pivot.remap_accent(&c)
+                                    } else {
+                                        pivot.reflect(&c)
+                                    }
+                                    .to_string()
+                                    .into()
+                                },
+                            );
+
+                        Ok((color_key, color))
+                    })
+                    .collect::<Result<Mapping, TintedBuilderError>>()?;
+            }
+        }
+    }
+
+    let mut inverted: tinted8::Scheme = serde_yaml::from_value(value)?;
+    inverted.scheme = scheme.scheme.clone();
+    inverted.scheme.name = invert_words(&scheme.scheme.name, variant, " ");
+    inverted.scheme.slug = invert_words(&scheme.scheme.slug, variant, "-");
+    inverted.scheme.style = scheme
+        .scheme
+        .style
+        .as_ref()
+        .map(|style| invert_words(style, variant, " "));
+
+    Ok(inverted)
+}
+
+/// Returns whether a Base16/Base24 palette key is an accent, `base08`–`base0F` or
+/// `base12`–`base17`.
+fn is_accent_key(key: &str) -> bool {
+    ("base08"..="base0F").contains(&key) || ("base12"..="base17").contains(&key)
+}
+
+/// Swaps `black` with `white` and `dim` with `bright` in a `BasicPalette` key.
+fn invert_palette_key(key: &str) -> String {
+    let (name, color_variant) = key.split_once('-').unwrap_or((key, ""));
+    let name = match name {
+        "black" => "white",
+        "white" => "black",
+        name => name,
+    };
+
+    match color_variant {
+        "dim" => format!("{name}-bright"),
+        "bright" => format!("{name}-dim"),
+        _ => name.to_string(),
+    }
+}
+
+/// Swaps the words "dark"/"light" for the new `variant`, keeping their case, or appends the new
+/// variant with `separator` when the text does not mention the old one.
+fn invert_words(text: &str, variant: &SchemeVariant, separator: &str) -> String {
+    let (from, to) = match variant {
+        SchemeVariant::Dark => ("light", "dark"),
+        SchemeVariant::Light => ("dark", "light"),
+    };
+    let re = Regex::new(&format!(r"(?i)\b{from}\b")).expect("Unable to unwrap regex");
+
+    if !re.is_match(text) {
+        let to = if text.chars().any(char::is_uppercase) {
+            capitalize(to)
+        } else {
+            to.to_string()
+        };
+
+        return format!("{text}{separator}{to}");
+    }
+
+    re.replace_all(text, |captures: &Captures| {
+        let word = &captures[0];
+
+        if word.chars().all(char::is_uppercase) {
+            to.to_uppercase()
+        } else if word.starts_with(char::is_uppercase) {
+            capitalize(to)
+        } else {
+            to.to_string()
+        }
+    })
+    .into_owned()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// The OKLCH lightness reflection of a scheme, `l ↦ sum - l`, and the accent lightness ranges of
+/// the old and new variant.
+struct Pivot {
+    sum: f64,
+    variant: SchemeVariant,
+}
+
+impl Pivot {
+    /// Places the pivot so that `background` is reflected into the background lightness range of
+    /// `variant`, as close to `1 - l` as the range allows.
+    fn new(background: &Color, variant: &SchemeVariant) -> Self {
+        let (l, _, _) = background.to_oklch();
+        let range = background_lightness_range(variant);
+        let target = (1.0 - l).clamp(*range.start(), *range.end());
+
+        Self {
+            sum: l + target,
+            variant: variant.clone(),
+        }
+    }
+
+    /// Reflects the lightness of `color`, keeping its chroma, hue, alpha, `name` and `variant`.
+    fn reflect(&self, color: &Color) -> Color {
+        let (l, _, _) = color.to_oklch();
+
+        with_lightness(color, self.sum - l)
+    }
+
+    /// Moves the lightness of an accent from the accent lightness range of the old variant to the
+    /// same relative position in the one of the new variant, keeping its chroma, hue, alpha,
+    /// `name` and `variant`.
+    fn remap_accent(&self, color: &Color) -> Color {
+        let (l, _, _) = color.to_oklch();
+        let from = accent_lightness_range(&self.variant.opposite());
+        let to = accent_lightness_range(&self.variant);
+        let position = ((l - from.start()) / (from.end() - from.start())).clamp(0.0, 1.0);
+
+        with_lightness(color, to.start() + position * (to.end() - to.start()))
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+/// Returns `color` with the OKLCH lightness `l`, keeping its chroma, hue, alpha, `name` and
+/// `variant`.
+fn with_lightness(color: &Color, l: f64) -> Color {
+    let (_, chroma, hue) = color.to_oklch();
+    let srgb = oklch_to_srgb_in_gamut(Oklch::new(
+        l.clamp(0.0, 1.0),
+        chroma,
+        OklabHue::from_degrees(hue),
+    ));
+    let rgb: [u8; 3] =
+        [srgb.red, srgb.green, srgb.blue].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+
+    Color::from_rgb(rgb.into(), color.name.clone(), color.variant.clone()).with_alpha(color.alpha)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverts_names_and_palette_keys() {
+        assert_eq!(
+            invert_words("Solarized Dark", &SchemeVariant::Light, " "),
+            "Solarized Light"
+        );
+        assert_eq!(
+            invert_words("one-light", &SchemeVariant::Dark, "-"),
+            "one-dark"
+        );
+        assert_eq!(
+            invert_words("Catppuccin Mocha", &SchemeVariant::Light, " "),
+            "Catppuccin Mocha Light"
+        );
+        assert_eq!(
+            invert_words("darkside", &SchemeVariant::Light, "-"),
+            "darkside-light"
+        );
+        assert_eq!(invert_palette_key("black"), "white");
+        assert_eq!(invert_palette_key("white-dim"), "black-bright");
+        assert_eq!(invert_palette_key("red-bright"), "red-dim");
+    }
+}
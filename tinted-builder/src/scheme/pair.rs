@@ -0,0 +1,167 @@
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+
+use crate::scheme::family::strip_variant_words;
+use crate::utils::slugify;
+use crate::{Scheme, SchemeSystem, SchemeVariant};
+
+/// A dark scheme and a light scheme of the same system that belong together, e.g. "Solarized
+/// Dark" and "Solarized Light".
+///
+/// Editors like VS Code and Zed describe both appearances in a single theme file, so a pair is
+/// rendered with `Template::new_pair`. Pairs are declared with the `pair` property of Base16 and
+/// Base24 schemes, or inferred from the scheme family, see `SchemePair::from_schemes`.
+#[derive(Debug, Clone)]
+pub struct SchemePair {
+    /// The display name of the pair: the family of the dark scheme, followed by its Tinted8
+    /// `style` without the words "Dark" and "Light".
+    pub name: String,
+    /// The slug of `name`.
+    pub slug: String,
+    /// The dark scheme of the pair.
+    pub dark: Scheme,
+    /// The light scheme of the pair.
+    pub light: Scheme,
+}
+
+impl SchemePair {
+    /// Pairs the dark and light schemes of the same system, sorted by slug and system.
+    ///
+    /// A Base16 or Base24 scheme whose `pair` is the slug of a scheme of the opposite variant is
+    /// paired with that scheme. The remaining schemes are grouped by system and by family
+    /// (`Scheme::get_scheme_family`) and Tinted8 `style`, ignoring the words "Dark" and "Light",
+    /// and groups of exactly one dark and one light scheme are paired. Schemes without a
+    /// counterpart are left out.
+    #[must_use]
+    pub fn from_schemes<'a>(schemes: impl IntoIterator<Item = &'a Scheme>) -> Vec<Self> {
+        let schemes: Vec<&Scheme> = schemes.into_iter().collect();
+        let mut is_paired = vec![false; schemes.len()];
+        let mut pairs: Vec<Self> = vec![];
+
+        for index in 0..schemes.len() {
+            if is_paired[index] {
+                continue;
+            }
+
+            let other_index = (0..schemes.len()).find(|&other_index| {
+                other_index != index
+                    && !is_paired[other_index]
+                    && is_declared_pair(schemes[index], schemes[other_index])
+            });
+
+            if let Some(other_index) = other_index {
+                is_paired[index] = true;
+                is_paired[other_index] = true;
+                pairs.push(Self::new(schemes[index], schemes[other_index]));
+            }
+        }
+
+        let mut groups: Vec<((SchemeSystem, String), Vec<&Scheme>)> = vec![];
+
+        for (scheme, _) in schemes
+            .iter()
+            .zip(&is_paired)
+            .filter(|(_, is_paired)| !**is_paired)
+        {
+            let key = (scheme.get_scheme_system(), slugify(&pair_name(scheme)));
+
+            if let Some((_, group)) = groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+                group.push(scheme);
+            } else {
+                groups.push((key, vec![scheme]));
+            }
+        }
+
+        for (_, group) in groups {
+            if let [scheme, other] = group.as_slice() {
+                if scheme.get_scheme_variant() != other.get_scheme_variant() {
+                    pairs.push(Self::new(scheme, other));
+                }
+            }
+        }
+
+        pairs.sort_by_key(|pair| (pair.slug.clone(), pair.get_scheme_system().to_string()));
+
+        pairs
+    }
+
+    /// Returns the scheme system of both schemes of the pair.
+    #[must_use]
+    pub const fn get_scheme_system(&self) -> SchemeSystem {
+        self.dark.get_scheme_system()
+    }
+
+    /// Creates a pair from two schemes of opposite variants, in any order.
+    fn new(scheme: &Scheme, other: &Scheme) -> Self {
+        let (dark, light) = if scheme.get_scheme_variant() == SchemeVariant::Dark {
+            (scheme, other)
+        } else {
+            (other, scheme)
+        };
+        let name = pair_name(dark);
+
+        Self {
+            slug: slugify(&name),
+            name,
+            dark: dark.clone(),
+            light: light.clone(),
+        }
+    }
+}
+
+impl Serialize for SchemePair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(5))?;
+
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("slug", &self.slug)?;
+        map.serialize_entry("system", &self.get_scheme_system())?;
+        map.serialize_entry("dark", &self.dark)?;
+        map.serialize_entry("light", &self.light)?;
+        map.end()
+    }
+}
+
+impl Scheme {
+    /// Returns the slug of the scheme this scheme is declared to be paired with, from the `pair`
+    /// property of Base16 and Base24 schemes.
+    #[must_use]
+    pub fn get_scheme_pair(&self) -> Option<String> {
+        match self {
+            Self::Base16(scheme) => scheme.pair.clone(),
+            Self::Base24(scheme) => scheme.pair.clone(),
+            Self::Tinted8(_) => None,
+        }
+    }
+}
+
+fn is_declared_pair(scheme: &Scheme, other: &Scheme) -> bool {
+    scheme.get_scheme_system() == other.get_scheme_system()
+        && scheme.get_scheme_variant() != other.get_scheme_variant()
+        && (scheme.get_scheme_pair() == Some(other.get_scheme_slug())
+            || other.get_scheme_pair() == Some(scheme.get_scheme_slug()))
+}
+
+/// Returns the family of a scheme, followed by its Tinted8 `style` without the words "Dark" and
+/// "Light", e.g. "Ayu Mirage" for the "Mirage Dark" style of the "Ayu" family.
+fn pair_name(scheme: &Scheme) -> String {
+    let family = scheme.get_scheme_family();
+    let style = match scheme {
+        Scheme::Tinted8(scheme) if scheme.scheme.family.is_some() => scheme
+            .scheme
+            .style
+            .as_deref()
+            .map(strip_variant_words)
+            .unwrap_or_default(),
+        _ => String::new(),
+    };
+
+    if style.is_empty() {
+        family
+    } else {
+        format!("{family} {style}")
+    }
+}
@@ -1,6 +1,12 @@
+mod conversion;
+mod expression;
 pub mod structure;
 mod yaml;
 
+pub use crate::scheme::tinted8::conversion::{
+    BASE16_TO_TINTED8_PALETTE_KEYS, BASE24_TO_TINTED8_PALETTE_KEYS,
+};
+pub use crate::scheme::tinted8::expression::ColorExpression;
 pub use crate::scheme::tinted8::structure::{Palette, Scheme, SyntaxKey, UiKey};
 use crate::SchemeSystem;
 
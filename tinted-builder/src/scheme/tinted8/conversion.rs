@@ -0,0 +1,388 @@
+use std::collections::HashMap;
+
+use crate::scheme::tinted8::structure::{Palette, SchemeMeta, Syntax, Ui};
+use crate::scheme::tinted8::yaml::{BasicPalette, BasicSyntax};
+use crate::scheme::tinted8::{Scheme, SUPPORTED_STYLING_SPEC_VERSION};
+use crate::scheme::{base16, base24};
+use crate::{
+    Color, ColorVariant, Derivation, SchemeSupports, SchemeSystem, SchemeVariant,
+    TintedBuilderError,
+};
+
+/// Base16 palette keys mapped onto Tinted8 palette colors.
+///
+/// `base00` and `base07` are the ends of the Base16 background/foreground ramp. A dark scheme
+/// maps them onto `black` and `white` respectively; a light scheme swaps them, since Tinted8
+/// always uses `white` as the light end. The foreground end is the one of `base05`, `base06`
+/// and `base07` with the most contrast on `base00` (`base07` on a tie), since some schemes use
+/// `base07` as a background shade. `base08`–`base0F` map onto the accent hues, including the
+/// supplemental `orange` and `brown`.
+pub const BASE16_TO_TINTED8_PALETTE_KEYS: [(&str, &str); 10] =
+    palette_keys(BASE16_TO_BASIC_PALETTE);
+
+/// Base24 bright accent keys mapped onto Tinted8 `bright` variants.
+///
+/// These are applied on top of `BASE16_TO_TINTED8_PALETTE_KEYS` when converting Base24 schemes.
+pub const BASE24_TO_TINTED8_PALETTE_KEYS: [(&str, &str); 6] = palette_keys(BASE24_TO_BASIC_PALETTE);
+
+const BASE16_TO_BASIC_PALETTE: [(&str, BasicPaletteKey); 10] = [
+    ("base00", BasicPaletteKey::Black),
+    ("base07", BasicPaletteKey::White),
+    ("base08", BasicPaletteKey::Red),
+    ("base09", BasicPaletteKey::Orange),
+    ("base0A", BasicPaletteKey::Yellow),
+    ("base0B", BasicPaletteKey::Green),
+    ("base0C", BasicPaletteKey::Cyan),
+    ("base0D", BasicPaletteKey::Blue),
+    ("base0E", BasicPaletteKey::Magenta),
+    ("base0F", BasicPaletteKey::Brown),
+];
+
+const BASE24_TO_BASIC_PALETTE: [(&str, BasicPaletteKey); 6] = [
+    ("base12", BasicPaletteKey::RedBright),
+    ("base13", BasicPaletteKey::YellowBright),
+    ("base14", BasicPaletteKey::GreenBright),
+    ("base15", BasicPaletteKey::CyanBright),
+    ("base16", BasicPaletteKey::BlueBright),
+    ("base17", BasicPaletteKey::MagentaBright),
+];
+
+/// The `BasicPalette` colors that Base16/Base24 palette keys are converted into.
+#[derive(Clone, Copy)]
+enum BasicPaletteKey {
+    Black,
+    White,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Magenta,
+    Brown,
+    RedBright,
+    YellowBright,
+    GreenBright,
+    CyanBright,
+    BlueBright,
+    MagentaBright,
+}
+
+impl BasicPaletteKey {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Black => "black",
+            Self::White => "white",
+            Self::Red => "red",
+            Self::Orange => "orange",
+            Self::Yellow => "yellow",
+            Self::Green => "green",
+            Self::Cyan => "cyan",
+            Self::Blue => "blue",
+            Self::Magenta => "magenta",
+            Self::Brown => "brown",
+            Self::RedBright => "red-bright",
+            Self::YellowBright => "yellow-bright",
+            Self::GreenBright => "green-bright",
+            Self::CyanBright => "cyan-bright",
+            Self::BlueBright => "blue-bright",
+            Self::MagentaBright => "magenta-bright",
+        }
+    }
+
+    fn set(self, basic_palette: &mut BasicPalette, hex: String) {
+        match self {
+            Self::Black => basic_palette.black = hex,
+            Self::White => basic_palette.white = hex,
+            Self::Red => basic_palette.red = hex,
+            Self::Orange => basic_palette.orange = Some(hex),
+            Self::Yellow => basic_palette.yellow = hex,
+            Self::Green => basic_palette.green = hex,
+            Self::Cyan => basic_palette.cyan = hex,
+            Self::Blue => basic_palette.blue = hex,
+            Self::Magenta => basic_palette.magenta = hex,
+            Self::Brown => basic_palette.brown = Some(hex),
+            Self::RedBright => basic_palette.red_bright = Some(hex),
+            Self::YellowBright => basic_palette.yellow_bright = Some(hex),
+            Self::GreenBright => basic_palette.green_bright = Some(hex),
+            Self::CyanBright => basic_palette.cyan_bright = Some(hex),
+            Self::BlueBright => basic_palette.blue_bright = Some(hex),
+            Self::MagentaBright => basic_palette.magenta_bright = Some(hex),
+        }
+    }
+}
+
+/// Spells out the Tinted8 keys of a Base16/Base24 to `BasicPalette` table.
+const fn palette_keys<const N: usize>(
+    mappings: [(&'static str, BasicPaletteKey); N],
+) -> [(&'static str, &'static str); N] {
+    let mut keys = [("", ""); N];
+    let mut i = 0;
+
+    while i < N {
+        keys[i] = (mappings[i].0, mappings[i].1.as_str());
+        i += 1;
+    }
+
+    keys
+}
+
+impl Scheme {
+    /// Projects the scheme onto the Base16 slot semantics.
+    ///
+    /// The `base00`–`base07` ramp runs from the `ui.global` background to foreground, using
+    /// `gray` for the middle steps:
+    ///
+    /// - `base00`: `ui.global.background.normal`
+    /// - `base01`: the `ui.global.background` shade closest to the foreground (`light` for dark
+    ///   schemes, `dark` for light schemes)
+    /// - `base02`: the `gray` variant closest to the background (`dim` for dark schemes, `bright`
+    ///   for light schemes)
+    /// - `base03`: `gray.normal`
+    /// - `base04`: `ui.global.foreground.dark`
+    /// - `base05`: `ui.global.foreground.normal`
+    /// - `base06`, `base07`: `ui.global.foreground.light`
+    ///
+    /// `base08`–`base0F` are the `normal` variants of `red`, `orange`, `yellow`, `green`, `cyan`,
+    /// `blue`, `magenta` and `brown`.
+    #[must_use]
+    pub fn to_base16(&self) -> base16::Scheme {
+        let palette = &self.palette;
+        let background = &self.ui.global.background;
+        let foreground = &self.ui.global.foreground;
+        let (background_near_foreground, gray_near_background) = match self.variant {
+            SchemeVariant::Light => (&background.dark, &palette.gray_bright),
+            SchemeVariant::Dark => (&background.light, &palette.gray_dim),
+        };
+        let base16_palette: HashMap<String, Color> = [
+            ("base00", &background.normal),
+            ("base01", background_near_foreground),
+            ("base02", gray_near_background),
+            ("base03", &palette.gray_normal),
+            ("base04", &foreground.dark),
+            ("base05", &foreground.normal),
+            ("base06", &foreground.light),
+            ("base07", &foreground.light),
+            ("base08", &palette.red_normal),
+            ("base09", &palette.orange_normal),
+            ("base0A", &palette.yellow_normal),
+            ("base0B", &palette.green_normal),
+            ("base0C", &palette.cyan_normal),
+            ("base0D", &palette.blue_normal),
+            ("base0E", &palette.magenta_normal),
+            ("base0F", &palette.brown_normal),
+        ]
+        .into_iter()
+        .map(|(key, color)| (key.to_string(), color.clone()))
+        .collect();
+
+        base16::Scheme {
+            system: SchemeSystem::Base16,
+            name: self.scheme.name.clone(),
+            slug: self.scheme.slug.clone(),
+            author: self.scheme.author.clone(),
+            description: self.scheme.description.clone(),
+            variant: self.variant.clone(),
+            pair: None,
+            palette: base16_palette,
+        }
+    }
+
+    /// Projects the scheme onto the Base24 slot semantics.
+    ///
+    /// `base00`–`base0F` follow `Scheme::to_base16`. The extra slots are filled as follows:
+    ///
+    /// - `base10`: the `ui.global.background` shade furthest from the foreground (`dark` for dark
+    ///   schemes, `light` for light schemes)
+    /// - `base11`: `base10` derived one step further away from the foreground
+    /// - `base12`–`base17`: the `bright` variants of `red`, `yellow`, `green`, `cyan`, `blue` and
+    ///   `magenta`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `base11` cannot be derived from `base10`.
+    pub fn to_base24(&self) -> Result<base24::Scheme, TintedBuilderError> {
+        let palette = &self.palette;
+        let background = &self.ui.global.background;
+        let (background_far_from_foreground, further_variant) = match self.variant {
+            SchemeVariant::Light => (&background.light, ColorVariant::Bright),
+            SchemeVariant::Dark => (&background.dark, ColorVariant::Dim),
+        };
+        let base11 = background_far_from_foreground.try_to_variant(&further_variant)?;
+        let mut base24_palette = self.to_base16().palette;
+
+        base24_palette.extend(
+            [
+                ("base10", background_far_from_foreground),
+                ("base11", &base11),
+                ("base12", &palette.red_bright),
+                ("base13", &palette.yellow_bright),
+                ("base14", &palette.green_bright),
+                ("base15", &palette.cyan_bright),
+                ("base16", &palette.blue_bright),
+                ("base17", &palette.magenta_bright),
+            ]
+            .into_iter()
+            .map(|(key, color)| (key.to_string(), color.clone())),
+        );
+
+        Ok(base24::Scheme {
+            system: SchemeSystem::Base24,
+            name: self.scheme.name.clone(),
+            slug: self.scheme.slug.clone(),
+            author: self.scheme.author.clone(),
+            description: self.scheme.description.clone(),
+            variant: self.variant.clone(),
+            pair: None,
+            palette: base24_palette,
+        })
+    }
+}
+
+impl TryFrom<base16::Scheme> for Scheme {
+    type Error = TintedBuilderError;
+
+    /// Converts a Base16 scheme into a Tinted8 scheme.
+    ///
+    /// The palette is mapped according to `BASE16_TO_TINTED8_PALETTE_KEYS`; `ui` and `syntax`
+    /// use the Tinted8 defaults for the resulting palette.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required palette key is missing or the palette cannot be expanded.
+    fn try_from(scheme: base16::Scheme) -> Result<Self, Self::Error> {
+        let basic_palette = to_basic_palette(&scheme.palette, &scheme.variant, false)?;
+
+        from_basic_palette(
+            &basic_palette,
+            SchemeMeta {
+                system: SchemeSystem::Tinted8,
+                name: scheme.name,
+                author: scheme.author.clone(),
+                theme_author: scheme.author,
+                slug: scheme.slug,
+                supports: SchemeSupports {
+                    styling_spec: SUPPORTED_STYLING_SPEC_VERSION.to_string(),
+                },
+                family: None,
+                style: None,
+                description: scheme.description,
+                converted_from: Some(SchemeSystem::Base16),
+            },
+            scheme.variant,
+        )
+    }
+}
+
+impl TryFrom<base24::Scheme> for Scheme {
+    type Error = TintedBuilderError;
+
+    /// Converts a Base24 scheme into a Tinted8 scheme.
+    ///
+    /// The palette is mapped according to `BASE16_TO_TINTED8_PALETTE_KEYS` and
+    /// `BASE24_TO_TINTED8_PALETTE_KEYS`; `ui` and `syntax` use the Tinted8 defaults for the
+    /// resulting palette.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required palette key is missing or the palette cannot be expanded.
+    fn try_from(scheme: base24::Scheme) -> Result<Self, Self::Error> {
+        let basic_palette = to_basic_palette(&scheme.palette, &scheme.variant, true)?;
+
+        from_basic_palette(
+            &basic_palette,
+            SchemeMeta {
+                system: SchemeSystem::Tinted8,
+                name: scheme.name,
+                author: scheme.author.clone(),
+                theme_author: scheme.author,
+                slug: scheme.slug,
+                supports: SchemeSupports {
+                    styling_spec: SUPPORTED_STYLING_SPEC_VERSION.to_string(),
+                },
+                family: None,
+                style: None,
+                description: scheme.description,
+                converted_from: Some(SchemeSystem::Base24),
+            },
+            scheme.variant,
+        )
+    }
+}
+
+/// Expands a basic palette and fills `ui`/`syntax` with their defaults.
+fn from_basic_palette(
+    basic_palette: &BasicPalette,
+    meta: SchemeMeta,
+    variant: SchemeVariant,
+) -> Result<Scheme, TintedBuilderError> {
+    let palette = Palette::try_from_basic(basic_palette)
+        .map_err(|err| TintedBuilderError::UnableToConvertFrom(err.to_string()))?;
+    let ui = Ui::new(&palette, &variant);
+    let syntax = Syntax::try_from_basic(&BasicSyntax::default(), &palette, &variant)?;
+
+    Ok(Scheme {
+        scheme: meta,
+        palette,
+        syntax,
+        ui,
+        variant,
+        derivation: Derivation::default(),
+    })
+}
+
+/// Builds a Tinted8 basic palette from a Base16/Base24 palette.
+fn to_basic_palette(
+    palette: &HashMap<String, Color>,
+    variant: &SchemeVariant,
+    include_base24: bool,
+) -> Result<BasicPalette, TintedBuilderError> {
+    let mut basic_palette = BasicPalette::default();
+    let mut mappings = BASE16_TO_BASIC_PALETTE.to_vec();
+
+    if include_base24 {
+        mappings.extend(BASE24_TO_BASIC_PALETTE);
+    }
+
+    let foreground_key = foreground_key(palette)?;
+
+    for (base_key, basic_palette_key) in mappings {
+        let base_key = if base_key == "base07" {
+            foreground_key
+        } else {
+            base_key
+        };
+        let hex = palette
+            .get(base_key)
+            .map(Color::to_hex)
+            .ok_or_else(|| TintedBuilderError::SchemeMissingProperty(base_key.to_string()))?;
+        let basic_palette_key = match (variant, basic_palette_key) {
+            (SchemeVariant::Light, BasicPaletteKey::Black) => BasicPaletteKey::White,
+            (SchemeVariant::Light, BasicPaletteKey::White) => BasicPaletteKey::Black,
+            (_, key) => key,
+        };
+
+        basic_palette_key.set(&mut basic_palette, hex);
+    }
+
+    Ok(basic_palette)
+}
+
+/// Returns whichever of `base05`, `base06` and `base07` has the most contrast on `base00`,
+/// preferring the later key on a tie.
+fn foreground_key(palette: &HashMap<String, Color>) -> Result<&'static str, TintedBuilderError> {
+    let background = palette
+        .get("base00")
+        .ok_or_else(|| TintedBuilderError::SchemeMissingProperty("base00".to_string()))?;
+
+    ["base05", "base06", "base07"]
+        .into_iter()
+        .filter_map(|key| {
+            palette
+                .get(key)
+                .map(|color| (key, color.contrast_ratio(background)))
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(key, _)| key)
+        .ok_or_else(|| TintedBuilderError::SchemeMissingProperty("base07".to_string()))
+}
@@ -0,0 +1,273 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::scheme::tinted8::structure::Palette;
+use crate::{Color, ColorName, ColorType, ColorVariant, TintedBuilderError};
+
+/// A Tinted8 `ui`/`syntax` color value.
+///
+/// An expression is a color or a palette reference, optionally followed by modifiers separated
+/// with `|`, applied from left to right:
+///
+/// ```yaml
+/// syntax:
+///   comment: gray.dim
+/// ui:
+///   selection.background: blue.normal | mix(black.normal, 0.7) | lighten(0.05)
+/// ```
+///
+/// - Palette references are written `<color>.<variant>`, `<color>_<variant>` or `<color>`
+///   (`normal`), e.g. `gray.dim`, `gray_dim` or `gray`. Palette names take precedence over CSS
+///   named colors of the same name.
+/// - Colors are anything `Color::new` accepts, e.g. `#33467c` or `oklch(40% 0.08 265)`.
+/// - Modifiers are `mix(<color or reference>, <weight>)`, `lighten(<amount>)`,
+///   `darken(<amount>)`, `saturate(<amount>)`, `desaturate(<amount>)` and `alpha(<amount>)`,
+///   see `Color::mix`, `Color::lighten`, `Color::darken`, `Color::saturate` and
+///   `Color::desaturate`. `alpha` sets the alpha channel, where `1.0` is opaque.
+#[derive(Debug, Clone)]
+pub struct ColorExpression {
+    term: ColorTerm,
+    modifiers: Vec<ColorModifier>,
+    source: String,
+}
+
+#[derive(Debug, Clone)]
+enum ColorTerm {
+    Color(Color),
+    Palette(ColorType),
+}
+
+#[derive(Debug, Clone)]
+enum ColorModifier {
+    Mix(ColorTerm, f32),
+    Lighten(f32),
+    Darken(f32),
+    Saturate(f32),
+    Desaturate(f32),
+    Alpha(f32),
+}
+
+impl ColorExpression {
+    /// Evaluates the expression, looking up palette references in `palette`.
+    ///
+    /// Unless the expression is a plain color, the resolved color's `source` is the expression as
+    /// it was written, e.g. `gray.dim`, so that it can be written back unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TintedBuilderError::InvalidColorExpression` if a palette reference does not exist
+    /// in `palette`.
+    pub fn resolve(&self, palette: &Palette) -> Result<Color, TintedBuilderError> {
+        let mut color = self.resolve_modifiers(palette)?;
+
+        if matches!(self.term, ColorTerm::Palette(_)) || !self.modifiers.is_empty() {
+            color.source = Some(self.source.clone());
+        }
+
+        Ok(color)
+    }
+
+    fn resolve_modifiers(&self, palette: &Palette) -> Result<Color, TintedBuilderError> {
+        let color = self.term.resolve(palette)?;
+
+        self.modifiers
+            .iter()
+            .try_fold(color, |color, modifier| match modifier {
+                ColorModifier::Mix(other, weight) => {
+                    Ok(color.mix(&other.resolve(palette)?, *weight))
+                }
+                ColorModifier::Lighten(amount) => Ok(color.lighten(*amount)),
+                ColorModifier::Darken(amount) => Ok(color.darken(*amount)),
+                ColorModifier::Saturate(amount) => Ok(color.saturate(*amount)),
+                ColorModifier::Desaturate(amount) => Ok(color.desaturate(*amount)),
+                ColorModifier::Alpha(amount) => Ok(color.with_alpha(to_alpha(*amount))),
+            })
+    }
+}
+
+impl FromStr for ColorExpression {
+    type Err = TintedBuilderError;
+
+    /// Parses a color expression like `blue.normal | mix(black.normal, 0.7)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TintedBuilderError::InvalidColorExpression` if the input is not a valid
+    /// expression.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid = || TintedBuilderError::InvalidColorExpression(input.trim().to_string());
+        let mut parts = input.split('|');
+        let term = parts
+            .next()
+            .and_then(ColorTerm::parse)
+            .ok_or_else(invalid)?;
+        let modifiers = parts
+            .map(ColorModifier::parse)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(invalid)?;
+
+        Ok(Self {
+            term,
+            modifiers,
+            source: input.trim().to_string(),
+        })
+    }
+}
+
+impl ColorTerm {
+    fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+
+        if input.is_empty() {
+            return None;
+        }
+
+        ColorType::from_str(input)
+            .or_else(|_| {
+                ColorName::from_str(&input.to_lowercase())
+                    .map(|name| ColorType(name, ColorVariant::Normal))
+            })
+            .ok()
+            .filter(|ColorType(name, _)| !matches!(name, ColorName::Other))
+            .map(Self::Palette)
+            .or_else(|| Color::new(input, None, None).ok().map(Self::Color))
+    }
+
+    fn resolve(&self, palette: &Palette) -> Result<Color, TintedBuilderError> {
+        match self {
+            Self::Color(color) => Ok(color.clone()),
+            Self::Palette(ColorType(name, variant)) => {
+                palette.get_color(name, variant).cloned().ok_or_else(|| {
+                    TintedBuilderError::InvalidColorExpression(format!("{name}.{variant}"))
+                })
+            }
+        }
+    }
+}
+
+impl ColorModifier {
+    fn parse(input: &str) -> Option<Self> {
+        let (name, arguments) = input.trim().strip_suffix(')')?.split_once('(')?;
+        let arguments = split_arguments(arguments);
+        let amount = || match arguments.as_slice() {
+            [amount] => amount
+                .parse::<f32>()
+                .ok()
+                .filter(|amount| amount.is_finite()),
+            _ => None,
+        };
+
+        match name.trim() {
+            "mix" => match arguments.as_slice() {
+                [other, weight] => Some(Self::Mix(
+                    ColorTerm::parse(other)?,
+                    weight
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|weight| weight.is_finite())?,
+                )),
+                _ => None,
+            },
+            "lighten" => amount().map(Self::Lighten),
+            "darken" => amount().map(Self::Darken),
+            "saturate" => amount().map(Self::Saturate),
+            "desaturate" => amount().map(Self::Desaturate),
+            "alpha" => amount().map(Self::Alpha),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ColorTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Color(color) => write!(f, "{color}"),
+            Self::Palette(ColorType(name, variant)) => write!(f, "{name}.{variant}"),
+        }
+    }
+}
+
+impl fmt::Display for ColorModifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mix(other, weight) => write!(f, "mix({other}, {weight})"),
+            Self::Lighten(amount) => write!(f, "lighten({amount})"),
+            Self::Darken(amount) => write!(f, "darken({amount})"),
+            Self::Saturate(amount) => write!(f, "saturate({amount})"),
+            Self::Desaturate(amount) => write!(f, "desaturate({amount})"),
+            Self::Alpha(amount) => write!(f, "alpha({amount})"),
+        }
+    }
+}
+
+impl fmt::Display for ColorExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.term)?;
+
+        for modifier in &self.modifiers {
+            write!(f, " | {modifier}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits modifier arguments on commas outside of parentheses, e.g. in `mix(rgb(0, 0, 0), 0.5)`.
+fn split_arguments(arguments: &str) -> Vec<&str> {
+    let mut depth = 0_usize;
+    let mut start = 0;
+    let mut result = vec![];
+
+    for (index, c) in arguments.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                result.push(arguments[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    result.push(arguments[start..].trim());
+
+    result
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_alpha(amount: f32) -> u8 {
+    (amount.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_displays_expressions() {
+        let parse = |input: &str| {
+            ColorExpression::from_str(input)
+                .map(|expression| expression.to_string())
+                .ok()
+        };
+
+        assert_eq!(parse("gray.dim").as_deref(), Some("gray.dim"));
+        assert_eq!(parse("gray_dim").as_deref(), Some("gray.dim"));
+        assert_eq!(parse(" Gray ").as_deref(), Some("gray.normal"));
+        assert_eq!(parse("#33467C").as_deref(), Some("#33467c"));
+        assert_eq!(
+            parse("blue.normal | mix(black.normal, 0.7) | lighten(0.05)").as_deref(),
+            Some("blue.normal | mix(black.normal, 0.7) | lighten(0.05)")
+        );
+        assert_eq!(
+            parse("red|mix(rgb(0, 0, 0), 0.5)|alpha(0.5)").as_deref(),
+            Some("red.normal | mix(#000000, 0.5) | alpha(0.5)")
+        );
+        assert_eq!(parse("rebeccapurple").as_deref(), Some("#663399"));
+        assert_eq!(parse("blue.normal |"), None);
+        assert_eq!(parse("blue.normal | lighten()"), None);
+        assert_eq!(parse("blue.normal | mix(black)"), None);
+        assert_eq!(parse("blue.normal | blur(2)"), None);
+        assert_eq!(parse("purple.normal"), None);
+    }
+}
@@ -7,13 +7,14 @@ pub use crate::scheme::tinted8::structure::meta::SchemeMeta;
 pub use crate::scheme::tinted8::structure::palette::Palette;
 pub use crate::scheme::tinted8::structure::syntax::{Syntax, SyntaxKey};
 pub use crate::scheme::tinted8::structure::ui::{Ui, UiKey};
-use crate::scheme::tinted8::yaml::Tinted8Scheme as YamlTinted8Scheme;
+use crate::scheme::tinted8::yaml::{self, Tinted8Scheme as YamlTinted8Scheme};
 use crate::tinted8::SUPPORTED_STYLING_SPEC_VERSION;
 use crate::utils::slugify;
 use crate::utils::titlecasify;
-use crate::{SchemeSupports, SchemeVariant};
+use crate::{ColorDerivation, Derivation, SchemeSupports, SchemeVariant, TintedBuilderError};
 use semver::{Version, VersionReq};
 use serde::{Deserialize, Deserializer, Serialize};
+use serde_yaml::Value;
 use std::fmt;
 
 /// Fully resolved Tinted8 scheme used by templates and downstream tooling.
@@ -27,6 +28,51 @@ pub struct Scheme {
     pub syntax: Syntax,
     pub ui: Ui,
     pub variant: SchemeVariant,
+    /// Color space used to derive `dim`/`bright` palette variants that the scheme does not set.
+    pub derivation: Derivation,
+}
+
+impl Scheme {
+    /// Serializes the scheme into a Tinted8 input scheme YAML string.
+    ///
+    /// The output is the minimal form of the scheme: the 8 base palette colors are always
+    /// written, while other palette variants, `ui` and `syntax` values are only written when they
+    /// differ from what the builder derives. Keys within `palette`, `ui` and `syntax` are sorted,
+    /// `ui` and `syntax` use dotted keys and every color is written as `#rrggbb`, or as the color
+    /// function, named color or color expression it was written as (`Color::source`). Reading
+    /// the output back yields the same colors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheme cannot be re-derived while computing the overrides or the
+    /// YAML cannot be serialized.
+    pub fn to_yaml_string(&self) -> Result<String, TintedBuilderError> {
+        yaml::to_yaml_string(self)
+    }
+
+    /// Returns a copy of the scheme with its `dim`/`bright` palette variants derived in the color
+    /// space of `derivation` instead of the scheme's own.
+    ///
+    /// Palette, `ui` and `syntax` values that differ from what the scheme's own derivation
+    /// produces are kept, everything else is derived again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheme cannot be re-derived.
+    pub fn with_derivation(&self, derivation: Derivation) -> Result<Self, TintedBuilderError> {
+        if self.derivation == derivation {
+            return Ok(self.clone());
+        }
+
+        let mut value: Value = serde_yaml::from_str(&self.to_yaml_string()?)?;
+        if let Value::Mapping(root) = &mut value {
+            root.insert("derivation".into(), derivation.to_string().into());
+        }
+        let mut scheme: Self = serde_yaml::from_value(value)?;
+        scheme.scheme = self.scheme.clone();
+
+        Ok(scheme)
+    }
 }
 
 impl fmt::Display for Scheme {
@@ -48,6 +94,9 @@ impl fmt::Display for Scheme {
         #[allow(clippy::writeln_empty_string)]
         writeln!(f, "")?;
         writeln!(f, "variant: \"{}\"", self.variant)?;
+        if self.derivation != Derivation::default() {
+            writeln!(f, "derivation: \"{}\"", self.derivation)?;
+        }
         if let Some(ref family) = self.scheme.family {
             writeln!(f, "family: \"{family}\"")?;
         }
@@ -81,7 +130,38 @@ impl<'de> Deserialize<'de> for Scheme {
         D: Deserializer<'de>,
     {
         let wrapper = YamlTinted8Scheme::deserialize(deserializer)?;
+        let derivation = wrapper.derivation.unwrap_or_default();
+
+        Self::try_from_yaml_scheme(wrapper, &derivation)
+    }
+}
+
+impl Scheme {
+    /// Reads a Tinted8 scheme from YAML, deriving the palette colors it does not set with the
+    /// rules of `derivation` instead of the scheme's own `derivation`.
+    ///
+    /// The `derivation` field of the returned scheme still reflects the YAML, so
+    /// `Scheme::to_yaml_string` writes every color that differs from it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the YAML is not a valid Tinted8 scheme or a color cannot be derived.
+    pub fn from_yaml_with(
+        yaml: &str,
+        derivation: &dyn ColorDerivation,
+    ) -> Result<Self, TintedBuilderError> {
+        let wrapper: YamlTinted8Scheme = serde_yaml::from_str(yaml)?;
+
+        Ok(Self::try_from_yaml_scheme::<serde_yaml::Error>(
+            wrapper, derivation,
+        )?)
+    }
 
+    /// Resolves a deserialized YAML scheme, deriving missing colors with `derivation`.
+    fn try_from_yaml_scheme<E: serde::de::Error>(
+        wrapper: YamlTinted8Scheme,
+        derivation: &dyn ColorDerivation,
+    ) -> Result<Self, E> {
         let (name, slug): (String, String) = match (
             &wrapper.scheme.name,
             &wrapper.scheme.slug,
@@ -104,29 +184,28 @@ impl<'de> Deserialize<'de> for Scheme {
                 (name, slugify(&family.clone()))
             }
             _ => {
-                return Err(serde::de::Error::custom(
+                return Err(E::custom(
                     "Either 'name', 'slug' or 'family' must exist in yaml scheme",
                 ))
             }
         };
 
         let palette =
-            Palette::try_from_basic(&wrapper.palette).map_err(serde::de::Error::custom)?;
+            Palette::try_from_basic_with(&wrapper.palette, derivation).map_err(E::custom)?;
         let ui = Ui::try_from_basic(&wrapper.ui.unwrap_or_default(), &palette, &wrapper.variant)
-            .map_err(serde::de::Error::custom)?;
+            .map_err(E::custom)?;
         let syntax = Syntax::try_from_basic(
             &wrapper.syntax.unwrap_or_default(),
             &palette,
             &wrapper.variant,
         )
-        .map_err(serde::de::Error::custom)?;
+        .map_err(E::custom)?;
 
-        let styling_spec = VersionReq::parse(&wrapper.scheme.supports.styling_spec)
-            .map_err(serde::de::Error::custom)?;
-        let styling_req =
-            Version::parse(SUPPORTED_STYLING_SPEC_VERSION).map_err(serde::de::Error::custom)?;
+        let styling_spec =
+            VersionReq::parse(&wrapper.scheme.supports.styling_spec).map_err(E::custom)?;
+        let styling_req = Version::parse(SUPPORTED_STYLING_SPEC_VERSION).map_err(E::custom)?;
         if !styling_spec.matches(&styling_req) {
-            return Err(serde::de::Error::custom(
+            return Err(E::custom(
                 format!("E002: Unsupported Tinted8 Styling Spec (requires {styling_req}, supported v{styling_spec})")
             ));
         }
@@ -146,11 +225,13 @@ impl<'de> Deserialize<'de> for Scheme {
             supports: SchemeSupports { styling_spec },
             family: wrapper.scheme.family,
             style: wrapper.scheme.style,
+            converted_from: None,
         };
 
         Ok(Self {
             scheme: scheme_meta,
             variant: wrapper.variant,
+            derivation: wrapper.derivation.unwrap_or_default(),
             syntax,
             ui,
             palette,
@@ -14,4 +14,6 @@ pub struct SchemeMeta {
     pub family: Option<String>,
     pub style: Option<String>,
     pub description: Option<String>,
+    /// Scheme system this scheme was converted from, when it was not authored as Tinted8.
+    pub converted_from: Option<SchemeSystem>,
 }
@@ -1,12 +1,9 @@
 use crate::scheme::tinted8::yaml::BasicPalette;
 use crate::Color;
+use crate::ColorDerivation;
 use crate::ColorName;
 use crate::ColorVariant;
-use crate::TintedBuilderError;
-use palette::rgb::Rgb;
-use palette::GetHue;
-use palette::IntoColor;
-use palette::{FromColor, Hsl, RgbHue};
+use crate::Derivation;
 use serde::ser::SerializeMap;
 use serde::Serialize;
 use std::fmt;
@@ -67,25 +64,40 @@ impl Palette {
     /// # Errors
     ///
     /// Returns `PaletteError` if any color hex value is invalid or a color variant conversion fails.
-    #[allow(clippy::too_many_lines)]
     pub fn try_from_basic(basic_palette: &BasicPalette) -> Result<Self, PaletteError> {
+        Self::try_from_basic_with(basic_palette, &Derivation::default())
+    }
+
+    /// Same as `Palette::try_from_basic`, but derives missing variants, supplemental colors and
+    /// `gray` with the rules of `derivation`, such as a `Derivation` or a custom
+    /// `ColorDerivation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaletteError` if any color hex value is invalid or a color variant conversion fails.
+    #[allow(clippy::too_many_lines)]
+    pub fn try_from_basic_with(
+        basic_palette: &BasicPalette,
+        derivation: &dyn ColorDerivation,
+    ) -> Result<Self, PaletteError> {
         let variant_bright = &ColorVariant::Bright;
         let variant_dim = &ColorVariant::Dim;
-        let generated_gray = color_black_and_white_to_gray(
-            &Color::new(
-                &basic_palette.black,
-                Some(ColorName::Black),
-                Some(ColorVariant::Normal),
-            )
-            .map_err(|_| PaletteError::UnableToConvertFrom("Color".to_string()))?,
-            &Color::new(
-                &basic_palette.white,
-                Some(ColorName::White),
-                Some(ColorVariant::Normal),
+        let generated_gray = derivation
+            .derive_gray(
+                &Color::new(
+                    &basic_palette.black,
+                    Some(ColorName::Black),
+                    Some(ColorVariant::Normal),
+                )
+                .map_err(|_| PaletteError::UnableToConvertFrom("Color".to_string()))?,
+                &Color::new(
+                    &basic_palette.white,
+                    Some(ColorName::White),
+                    Some(ColorVariant::Normal),
+                )
+                .map_err(|_| PaletteError::UnableToConvertFrom("Color".to_string()))?,
             )
-            .map_err(|_| PaletteError::UnableToConvertFrom("Color".to_string()))?,
-        )
-        .map_err(|_| PaletteError::UnableToConvertFrom("Generated Gray".to_string()))?;
+            .map_err(|_| PaletteError::UnableToConvertFrom("Generated Gray".to_string()))?;
         let generated_gray_hex = generated_gray.to_hex();
         let black_normal = Color::new(
             &basic_palette.black,
@@ -135,11 +147,21 @@ impl Palette {
             Some(ColorVariant::Normal),
         )
         .map_err(|err| PaletteError::UnableToCreateColor(err.to_string()))?;
+        let normal_colors = [
+            black_normal.clone(),
+            red_normal.clone(),
+            green_normal.clone(),
+            yellow_normal.clone(),
+            blue_normal.clone(),
+            magenta_normal.clone(),
+            cyan_normal.clone(),
+            white_normal.clone(),
+        ];
         let orange_normal = basic_palette
             .orange
             .as_ref()
             .map_or_else(
-                || yellow_normal.clone().try_to_color(&ColorName::Orange),
+                || derivation.derive_color(&ColorName::Orange, &normal_colors),
                 |orange_hex| {
                     Color::new(
                         orange_hex,
@@ -153,7 +175,7 @@ impl Palette {
             .brown
             .as_ref()
             .map_or_else(
-                || yellow_normal.clone().try_to_color(&ColorName::Brown),
+                || derivation.derive_color(&ColorName::Brown, &normal_colors),
                 |brown_hex| {
                     Color::new(
                         brown_hex,
@@ -175,7 +197,7 @@ impl Palette {
                 .black_dim
                 .as_ref()
                 .map_or_else(
-                    || black_normal.clone().try_to_variant(&ColorVariant::Dim),
+                    || derivation.derive_variant(&black_normal, &ColorVariant::Dim),
                     |hex| Color::new(hex, Some(ColorName::Black), Some(ColorVariant::Dim)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -183,7 +205,7 @@ impl Palette {
                 .black_bright
                 .as_ref()
                 .map_or_else(
-                    || black_normal.clone().try_to_variant(&ColorVariant::Bright),
+                    || derivation.derive_variant(&black_normal, &ColorVariant::Bright),
                     |hex| Color::new(hex, Some(ColorName::Black), Some(ColorVariant::Bright)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -192,7 +214,7 @@ impl Palette {
                 .red_dim
                 .as_ref()
                 .map_or_else(
-                    || red_normal.clone().try_to_variant(&ColorVariant::Dim),
+                    || derivation.derive_variant(&red_normal, &ColorVariant::Dim),
                     |hex| Color::new(hex, Some(ColorName::Red), Some(ColorVariant::Dim)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -200,7 +222,7 @@ impl Palette {
                 .red_bright
                 .as_ref()
                 .map_or_else(
-                    || red_normal.clone().try_to_variant(&ColorVariant::Bright),
+                    || derivation.derive_variant(&red_normal, &ColorVariant::Bright),
                     |hex| Color::new(hex, Some(ColorName::Red), Some(ColorVariant::Bright)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -210,7 +232,7 @@ impl Palette {
                 .green_dim
                 .as_ref()
                 .map_or_else(
-                    || green_normal.clone().try_to_variant(&ColorVariant::Dim),
+                    || derivation.derive_variant(&green_normal, &ColorVariant::Dim),
                     |hex| Color::new(hex, Some(ColorName::Green), Some(ColorVariant::Dim)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -218,7 +240,7 @@ impl Palette {
                 .green_bright
                 .as_ref()
                 .map_or_else(
-                    || green_normal.clone().try_to_variant(&ColorVariant::Bright),
+                    || derivation.derive_variant(&green_normal, &ColorVariant::Bright),
                     |hex| Color::new(hex, Some(ColorName::Green), Some(ColorVariant::Bright)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -229,7 +251,7 @@ impl Palette {
                 .yellow_dim
                 .as_ref()
                 .map_or_else(
-                    || yellow_normal.clone().try_to_variant(&ColorVariant::Dim),
+                    || derivation.derive_variant(&yellow_normal, &ColorVariant::Dim),
                     |hex| Color::new(hex, Some(ColorName::Yellow), Some(ColorVariant::Dim)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -237,7 +259,7 @@ impl Palette {
                 .yellow_bright
                 .as_ref()
                 .map_or_else(
-                    || yellow_normal.clone().try_to_variant(&ColorVariant::Bright),
+                    || derivation.derive_variant(&yellow_normal, &ColorVariant::Bright),
                     |hex| Color::new(hex, Some(ColorName::Yellow), Some(ColorVariant::Bright)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -247,7 +269,7 @@ impl Palette {
                 .blue_dim
                 .as_ref()
                 .map_or_else(
-                    || blue_normal.clone().try_to_variant(&ColorVariant::Dim),
+                    || derivation.derive_variant(&blue_normal, &ColorVariant::Dim),
                     |hex| Color::new(hex, Some(ColorName::Blue), Some(ColorVariant::Dim)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -255,7 +277,7 @@ impl Palette {
                 .blue_bright
                 .as_ref()
                 .map_or_else(
-                    || blue_normal.clone().try_to_variant(&ColorVariant::Bright),
+                    || derivation.derive_variant(&blue_normal, &ColorVariant::Bright),
                     |hex| Color::new(hex, Some(ColorName::Blue), Some(ColorVariant::Bright)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -265,7 +287,7 @@ impl Palette {
                 .magenta_dim
                 .as_ref()
                 .map_or_else(
-                    || magenta_normal.clone().try_to_variant(&ColorVariant::Dim),
+                    || derivation.derive_variant(&magenta_normal, &ColorVariant::Dim),
                     |hex| Color::new(hex, Some(ColorName::Magenta), Some(ColorVariant::Dim)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -273,7 +295,7 @@ impl Palette {
                 .magenta_bright
                 .as_ref()
                 .map_or_else(
-                    || magenta_normal.clone().try_to_variant(&ColorVariant::Bright),
+                    || derivation.derive_variant(&magenta_normal, &ColorVariant::Bright),
                     |hex| Color::new(hex, Some(ColorName::Magenta), Some(ColorVariant::Bright)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -283,7 +305,7 @@ impl Palette {
                 .cyan_dim
                 .as_ref()
                 .map_or_else(
-                    || cyan_normal.clone().try_to_variant(&ColorVariant::Dim),
+                    || derivation.derive_variant(&cyan_normal, &ColorVariant::Dim),
                     |hex| Color::new(hex, Some(ColorName::Cyan), Some(ColorVariant::Dim)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -291,7 +313,7 @@ impl Palette {
                 .cyan_bright
                 .as_ref()
                 .map_or_else(
-                    || cyan_normal.clone().try_to_variant(&ColorVariant::Bright),
+                    || derivation.derive_variant(&cyan_normal, &ColorVariant::Bright),
                     |hex| Color::new(hex, Some(ColorName::Cyan), Some(ColorVariant::Bright)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -301,7 +323,7 @@ impl Palette {
                 .white_dim
                 .as_ref()
                 .map_or_else(
-                    || white_normal.clone().try_to_variant(&ColorVariant::Dim),
+                    || derivation.derive_variant(&white_normal, &ColorVariant::Dim),
                     |hex| Color::new(hex, Some(ColorName::White), Some(ColorVariant::Dim)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -309,7 +331,7 @@ impl Palette {
                 .white_bright
                 .as_ref()
                 .map_or_else(
-                    || white_normal.clone().try_to_variant(&ColorVariant::Bright),
+                    || derivation.derive_variant(&white_normal, &ColorVariant::Bright),
                     |hex| Color::new(hex, Some(ColorName::White), Some(ColorVariant::Bright)),
                 )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
@@ -320,7 +342,7 @@ impl Palette {
                 .orange_bright
                 .as_ref()
                 .map_or_else(
-                    || orange_normal.try_to_variant(&ColorVariant::Bright),
+                    || derivation.derive_variant(&orange_normal, &ColorVariant::Bright),
                     |orange_hex| {
                         Color::new(
                             orange_hex,
@@ -331,16 +353,12 @@ impl Palette {
                 )
                 .map_err(|err| PaletteError::UnableToCreateColor(err.to_string()))?,
             orange_dim: basic_palette
-                .orange_bright
+                .orange_dim
                 .as_ref()
                 .map_or_else(
-                    || orange_normal.try_to_variant(&ColorVariant::Bright),
+                    || derivation.derive_variant(&orange_normal, &ColorVariant::Dim),
                     |orange_hex| {
-                        Color::new(
-                            orange_hex,
-                            Some(ColorName::Orange),
-                            Some(ColorVariant::Bright),
-                        )
+                        Color::new(orange_hex, Some(ColorName::Orange), Some(ColorVariant::Dim))
                     },
                 )
                 .map_err(|err| PaletteError::UnableToCreateColor(err.to_string()))?,
@@ -351,29 +369,54 @@ impl Palette {
                 Some(ColorVariant::Normal),
             )
             .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
-            gray_bright: Color::new(
-                basic_palette.gray.as_ref().unwrap_or(&generated_gray_hex),
-                Some(ColorName::Gray),
-                Some(ColorVariant::Bright),
-            )
-            .and_then(|c| c.try_to_variant(variant_bright))
-            .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
-            gray_dim: Color::new(
-                basic_palette.gray.as_ref().unwrap_or(&generated_gray_hex),
-                Some(ColorName::Gray),
-                Some(ColorVariant::Dim),
-            )
-            .and_then(|c| c.try_to_variant(variant_dim))
-            .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
+            gray_bright: basic_palette
+                .gray_bright
+                .as_ref()
+                .map_or_else(
+                    || {
+                        Color::new(
+                            basic_palette.gray.as_ref().unwrap_or(&generated_gray_hex),
+                            Some(ColorName::Gray),
+                            Some(ColorVariant::Bright),
+                        )
+                        .and_then(|c| derivation.derive_variant(&c, variant_bright))
+                    },
+                    |hex| Color::new(hex, Some(ColorName::Gray), Some(ColorVariant::Bright)),
+                )
+                .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
+            gray_dim: basic_palette
+                .gray_dim
+                .as_ref()
+                .map_or_else(
+                    || {
+                        Color::new(
+                            basic_palette.gray.as_ref().unwrap_or(&generated_gray_hex),
+                            Some(ColorName::Gray),
+                            Some(ColorVariant::Dim),
+                        )
+                        .and_then(|c| derivation.derive_variant(&c, variant_dim))
+                    },
+                    |hex| Color::new(hex, Some(ColorName::Gray), Some(ColorVariant::Dim)),
+                )
+                .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
 
-            brown_normal: brown_normal.clone(),
-            brown_bright: brown_normal
-                .clone()
-                .try_to_variant(&ColorVariant::Bright)
+            brown_bright: basic_palette
+                .brown_bright
+                .as_ref()
+                .map_or_else(
+                    || derivation.derive_variant(&brown_normal, &ColorVariant::Bright),
+                    |hex| Color::new(hex, Some(ColorName::Brown), Some(ColorVariant::Bright)),
+                )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
-            brown_dim: brown_normal
-                .try_to_variant(&ColorVariant::Dim)
+            brown_dim: basic_palette
+                .brown_dim
+                .as_ref()
+                .map_or_else(
+                    || derivation.derive_variant(&brown_normal, &ColorVariant::Dim),
+                    |hex| Color::new(hex, Some(ColorName::Brown), Some(ColorVariant::Dim)),
+                )
                 .map_err(|err| PaletteError::UnableToConvertFrom(err.to_string()))?,
+            brown_normal,
         };
 
         Ok(palette)
@@ -633,36 +676,6 @@ pub enum PaletteError {
     InvalidYaml,
 }
 
-#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-/// Derives a neutral gray by averaging black and white in HSL and forcing `S=0`.
-fn color_black_and_white_to_gray(
-    black_color: &Color,
-    white_color: &Color,
-) -> Result<Color, TintedBuilderError> {
-    let black_rgb = Rgb::new(black_color.rgb.0, black_color.rgb.1, black_color.rgb.2);
-    let black_hsl: Hsl = Hsl::from_color(black_rgb.into_format::<f32>());
-    let white_rgb = Rgb::new(white_color.rgb.0, white_color.rgb.1, white_color.rgb.2);
-    let white_hsl: Hsl = Hsl::from_color(white_rgb.into_format::<f32>());
-
-    // Compute midpoint hue (wrap-aware); hue is irrelevant for gray but keep it stable.
-    let h1 = white_hsl.get_hue().into_degrees();
-    let h2 = black_hsl.get_hue().into_degrees();
-    let d = ((h2 - h1 + 540.0) % 360.0) - 180.0;
-    let gray_hsl_h = (0.5_f32.mul_add(d, h1) + 360.0) % 360.0;
-    // For a neutral gray, force saturation to 0 and average the lightness.
-    let gray_hsl_s = 0.0;
-    let gray_hsl_l = 0.5 * (white_hsl.lightness + black_hsl.lightness);
-
-    let gray_hsl = Hsl::new(RgbHue::from_degrees(gray_hsl_h), gray_hsl_s, gray_hsl_l);
-    let gray_rgb: Rgb = gray_hsl.into_color();
-    let gray_rgb_r: u8 = (gray_rgb.red.clamp(0.0, 1.0) * 255.0).round() as u8;
-    let gray_rgb_g: u8 = (gray_rgb.green.clamp(0.0, 1.0) * 255.0).round() as u8;
-    let gray_rgb_b: u8 = (gray_rgb.blue.clamp(0.0, 1.0) * 255.0).round() as u8;
-    let gray_hex = format!("{gray_rgb_r:02X}{gray_rgb_g:02X}{gray_rgb_b:02X}");
-
-    Color::new(&gray_hex, Some(ColorName::Gray), Some(ColorVariant::Normal))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,7 +134,7 @@ impl Syntax {
 impl fmt::Display for Syntax {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for key in SyntaxKey::variants() {
-            writeln!(f, "  {key}: #{}", &self.get_color(key).to_hex())?;
+            writeln!(f, "  {key}: {}", self.get_color(key))?;
         }
 
         Ok(())
@@ -313,14 +313,17 @@ impl Ui {
             normal: parse_or_inherit(
                 &[basic.global_background_normal.as_deref()],
                 &default.global.background.normal,
+                palette,
             )?,
             dark: parse_or_inherit(
                 &[basic.global_background_dark.as_deref()],
                 &default.global.background.dark,
+                palette,
             )?,
             light: parse_or_inherit(
                 &[basic.global_background_light.as_deref()],
                 &default.global.background.light,
+                palette,
             )?,
         };
 
@@ -328,14 +331,17 @@ impl Ui {
             normal: parse_or_inherit(
                 &[basic.global_foreground_normal.as_deref()],
                 &default.global.foreground.normal,
+                palette,
             )?,
             dark: parse_or_inherit(
                 &[basic.global_foreground_dark.as_deref()],
                 &default.global.foreground.dark,
+                palette,
             )?,
             light: parse_or_inherit(
                 &[basic.global_foreground_light.as_deref()],
                 &default.global.foreground.light,
+                palette,
             )?,
         };
         let global = UiGlobal {
@@ -347,10 +353,12 @@ impl Ui {
             background: parse_or_inherit(
                 &[basic.gutter_background.as_deref()],
                 &default.gutter.background,
+                palette,
             )?,
             foreground: parse_or_inherit(
                 &[basic.gutter_foreground.as_deref()],
                 &default.gutter.foreground,
+                palette,
             )?,
         };
 
@@ -358,48 +366,58 @@ impl Ui {
             background: parse_or_inherit(
                 &[basic.highlight_button_background.as_deref()],
                 &default.highlight.button.background,
+                palette,
             )?,
             foreground: parse_or_inherit(
                 &[basic.highlight_button_foreground.as_deref()],
                 &default.highlight.button.foreground,
+                palette,
             )?,
         };
         let highlight_text = UiHighlightText {
             background: parse_or_inherit(
                 &[basic.highlight_text_background.as_deref()],
                 &default.highlight.text.background,
+                palette,
             )?,
             foreground: parse_or_inherit(
                 &[basic.highlight_text_foreground.as_deref()],
                 &default.highlight.text.foreground,
+                palette,
             )?,
             active_background: parse_or_inherit(
                 &[basic.highlight_text_active_background.as_deref()],
                 &default.highlight.text.active_background,
+                palette,
             )?,
             active_foreground: parse_or_inherit(
                 &[basic.highlight_text_active_foreground.as_deref()],
                 &default.highlight.text.active_foreground,
+                palette,
             )?,
         };
         let highlight_line = UiBgFg {
             background: parse_or_inherit(
                 &[basic.highlight_line_background.as_deref()],
                 &default.highlight.line.background,
+                palette,
             )?,
             foreground: parse_or_inherit(
                 &[basic.highlight_line_foreground.as_deref()],
                 &default.highlight.line.foreground,
+                palette,
             )?,
         };
         let highlight_search = UiBgFg {
             background: parse_or_inherit(
                 &[basic.highlight_search_background.as_deref()],
                 &default.highlight.search.background,
+                palette,
             )?,
             foreground: parse_or_inherit(
                 &[basic.highlight_search_foreground.as_deref()],
                 &default.highlight.search.foreground,
+                palette,
             )?,
         };
 
@@ -414,10 +432,12 @@ impl Ui {
             background: parse_or_inherit(
                 &[basic.indent_guide_background.as_deref()],
                 &default.indent_guide.background,
+                palette,
             )?,
             active_background: parse_or_inherit(
                 &[basic.indent_guide_active_background.as_deref()],
                 &default.indent_guide.active_background,
+                palette,
             )?,
         };
 
@@ -425,14 +445,17 @@ impl Ui {
             background: parse_or_inherit(
                 &[basic.selection_background.as_deref()],
                 &default.selection.background,
+                palette,
             )?,
             foreground: parse_or_inherit(
                 &[basic.selection_foreground.as_deref()],
                 &default.selection.foreground,
+                palette,
             )?,
             inactive_background: parse_or_inherit(
                 &[basic.selection_inactive_background.as_deref()],
                 &default.selection.inactive_background,
+                palette,
             )?,
         };
 
@@ -441,45 +464,57 @@ impl Ui {
                 normal: parse_or_inherit(
                     &[basic.chrome_background_normal.as_deref()],
                     &default.chrome.background.normal,
+                    palette,
                 )?,
                 dark: parse_or_inherit(
                     &[basic.chrome_background_dark.as_deref()],
                     &default.chrome.background.dark,
+                    palette,
                 )?,
                 light: parse_or_inherit(
                     &[basic.chrome_background_light.as_deref()],
                     &default.chrome.background.light,
+                    palette,
                 )?,
             },
             foreground: UiChromeForeground {
                 normal: parse_or_inherit(
                     &[basic.chrome_foreground_normal.as_deref()],
                     &default.chrome.foreground.normal,
+                    palette,
                 )?,
                 dark: parse_or_inherit(
                     &[basic.chrome_foreground_dark.as_deref()],
                     &default.chrome.foreground.dark,
+                    palette,
                 )?,
                 light: parse_or_inherit(
                     &[basic.chrome_foreground_light.as_deref()],
                     &default.chrome.foreground.light,
+                    palette,
                 )?,
             },
         };
 
         Ok(Self {
             global,
-            deprecated: parse_or_inherit(&[basic.deprecated.as_deref()], &default.deprecated)?,
+            deprecated: parse_or_inherit(
+                &[basic.deprecated.as_deref()],
+                &default.deprecated,
+                palette,
+            )?,
             accent: UiAccent {
                 normal: parse_or_inherit(
                     &[basic.accent_normal.as_deref()],
                     &default.accent.normal,
+                    palette,
                 )?,
             },
             border: UiBorder {
                 normal: parse_or_inherit(
                     &[basic.border_normal.as_deref()],
                     &default.border.normal,
+                    palette,
                 )?,
             },
             chrome,
@@ -488,20 +523,24 @@ impl Ui {
                     background: parse_or_inherit(
                         &[basic.cursor_normal_background.as_deref()],
                         &default.cursor.normal.background,
+                        palette,
                     )?,
                     foreground: parse_or_inherit(
                         &[basic.cursor_normal_foreground.as_deref()],
                         &default.cursor.normal.foreground,
+                        palette,
                     )?,
                 },
                 muted: UiBgFg {
                     background: parse_or_inherit(
                         &[basic.cursor_muted_background.as_deref()],
                         &default.cursor.muted.background,
+                        palette,
                     )?,
                     foreground: parse_or_inherit(
                         &[basic.cursor_muted_foreground.as_deref()],
                         &default.cursor.muted.foreground,
+                        palette,
                     )?,
                 },
             },
@@ -513,40 +552,55 @@ impl Ui {
                     background: parse_or_inherit(
                         &[basic.link_normal_background.as_deref()],
                         &default.link.normal.background,
+                        palette,
                     )?,
                     foreground: parse_or_inherit(
                         &[basic.link_normal_foreground.as_deref()],
                         &default.link.normal.foreground,
+                        palette,
                     )?,
                 },
             },
             selection,
             status: UiStatus {
-                error: parse_or_inherit(&[basic.status_error.as_deref()], &default.status.error)?,
-                info: parse_or_inherit(&[basic.status_info.as_deref()], &default.status.info)?,
+                error: parse_or_inherit(
+                    &[basic.status_error.as_deref()],
+                    &default.status.error,
+                    palette,
+                )?,
+                info: parse_or_inherit(
+                    &[basic.status_info.as_deref()],
+                    &default.status.info,
+                    palette,
+                )?,
                 success: parse_or_inherit(
                     &[basic.status_success.as_deref()],
                     &default.status.success,
+                    palette,
                 )?,
                 warning: parse_or_inherit(
                     &[basic.status_warning.as_deref()],
                     &default.status.warning,
+                    palette,
                 )?,
             },
             tooltip: UiBgFg {
                 background: parse_or_inherit(
                     &[basic.tooltip_background.as_deref()],
                     &default.tooltip.background,
+                    palette,
                 )?,
                 foreground: parse_or_inherit(
                     &[basic.tooltip_foreground.as_deref()],
                     &default.tooltip.foreground,
+                    palette,
                 )?,
             },
             whitespace: UiWhitespace {
                 foreground: parse_or_inherit(
                     &[basic.whitespace_foreground.as_deref()],
                     &default.whitespace.foreground,
+                    palette,
                 )?,
             },
         })
@@ -606,7 +660,7 @@ impl Ui {
 impl fmt::Display for Ui {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for key in UiKey::variants() {
-            writeln!(f, "  {key}: #{}", self.get_color(key).to_hex())?;
+            writeln!(f, "  {key}: {}", self.get_color(key))?;
         }
 
         Ok(())
@@ -1,5 +1,8 @@
+use crate::scheme::tinted8::structure::{Palette, Scheme, Syntax, SyntaxKey, Ui, UiKey};
 use crate::scheme::SchemeVariant;
 use crate::{scheme::tinted8::SchemeSystem, SchemeSupports};
+use crate::{Color, ColorName, ColorVariant, Derivation, TintedBuilderError};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_yaml::{Mapping, Value};
 use std::fmt;
@@ -11,6 +14,7 @@ pub struct Tinted8Scheme {
     pub syntax: Option<BasicSyntax>,
     pub ui: Option<BasicUi>,
     pub variant: SchemeVariant,
+    pub derivation: Option<Derivation>,
 }
 
 // Helper type that mirrors `Tinted8Scheme` for inner deserialization.
@@ -21,6 +25,7 @@ struct Tinted8SchemeHelper {
     pub syntax: Option<BasicSyntax>,
     pub ui: Option<BasicUi>,
     pub variant: SchemeVariant,
+    pub derivation: Option<Derivation>,
 }
 
 impl From<Tinted8SchemeHelper> for Tinted8Scheme {
@@ -31,6 +36,7 @@ impl From<Tinted8SchemeHelper> for Tinted8Scheme {
             syntax: h.syntax,
             ui: h.ui,
             variant: h.variant,
+            derivation: h.derivation,
         }
     }
 }
@@ -209,7 +215,7 @@ pub struct BasicUi {
     pub whitespace_foreground: Option<String>,
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, Default)]
 #[serde(deny_unknown_fields)]
 pub struct BasicPalette {
     pub black: String,
@@ -324,3 +330,228 @@ pub struct Meta {
     pub family: Option<String>,
     pub style: Option<String>,
 }
+
+/// Serializes a resolved scheme into its minimal Tinted8 input YAML.
+///
+/// Only the 8 base palette colors are always written; every other palette, `ui` and `syntax`
+/// value is written only when it differs from what the builder would derive without it.
+pub fn to_yaml_string(scheme: &Scheme) -> Result<String, TintedBuilderError> {
+    let palette = minimal_palette(&scheme.palette, scheme.derivation)?;
+    let basic_palette: BasicPalette = serde_yaml::from_value(Value::Mapping(palette.clone()))?;
+    let expanded_palette = Palette::try_from_basic_with(&basic_palette, &scheme.derivation)
+        .map_err(|err| TintedBuilderError::UnableToConvertFrom(err.to_string()))?;
+    let ui_keys: Vec<&UiKey> = UiKey::variants()
+        .iter()
+        .filter(|key| key.to_string().starts_with("global."))
+        .chain(
+            UiKey::variants()
+                .iter()
+                .filter(|key| !key.to_string().starts_with("global.")),
+        )
+        .collect();
+    let ui = minimal_overrides(
+        Mapping::new(),
+        &ui_keys,
+        |key| scheme.ui.get_color(key),
+        |basic: &BasicUi| Ui::try_from_basic(basic, &expanded_palette, &scheme.variant),
+        |ui, key| ui.get_color(key).to_string(),
+    )?;
+    let syntax_keys: Vec<&SyntaxKey> = Syntax::get_property_list().iter().collect();
+    let syntax = minimal_overrides(
+        Mapping::new(),
+        &syntax_keys,
+        |key| scheme.syntax.get_color(key),
+        |basic: &BasicSyntax| Syntax::try_from_basic(basic, &expanded_palette, &scheme.variant),
+        |syntax, key| syntax.get_color(key).to_string(),
+    )?;
+
+    let mut meta = Mapping::new();
+    meta.insert("system".into(), scheme.scheme.system.to_string().into());
+    meta.insert(
+        "supports".into(),
+        serde_yaml::to_value(&scheme.scheme.supports).map_err(TintedBuilderError::YamlSerialize)?,
+    );
+    meta.insert("name".into(), scheme.scheme.name.clone().into());
+    meta.insert("slug".into(), scheme.scheme.slug.clone().into());
+    meta.insert("author".into(), scheme.scheme.author.clone().into());
+    if scheme.scheme.theme_author != scheme.scheme.author {
+        meta.insert(
+            "theme-author".into(),
+            scheme.scheme.theme_author.clone().into(),
+        );
+    }
+    for (key, value) in [
+        ("description", &scheme.scheme.description),
+        ("family", &scheme.scheme.family),
+        ("style", &scheme.scheme.style),
+    ] {
+        if let Some(value) = value {
+            meta.insert(key.into(), value.clone().into());
+        }
+    }
+
+    let mut root = Mapping::new();
+    root.insert("scheme".into(), Value::Mapping(meta));
+    root.insert("variant".into(), scheme.variant.to_string().into());
+    if scheme.derivation != Derivation::default() {
+        root.insert("derivation".into(), scheme.derivation.to_string().into());
+    }
+    root.insert("palette".into(), Value::Mapping(sorted_mapping(palette)));
+    for (key, overrides) in [("syntax", syntax), ("ui", ui)] {
+        if !overrides.is_empty() {
+            root.insert(key.into(), Value::Mapping(sorted_mapping(overrides)));
+        }
+    }
+
+    serde_yaml::to_string(&Value::Mapping(root)).map_err(TintedBuilderError::YamlSerialize)
+}
+
+/// Returns the palette entries needed to reproduce `palette`, keyed as in `BasicPalette`.
+///
+/// `normal` variants are resolved before `dim`/`bright`, since the latter are derived from them.
+fn minimal_palette(
+    palette: &Palette,
+    derivation: Derivation,
+) -> Result<Mapping, TintedBuilderError> {
+    let mut color_list = Palette::get_color_list();
+    color_list.sort_by_key(|(_, variant)| !matches!(variant, ColorVariant::Normal));
+    let keys: Vec<PaletteKey> = color_list
+        .into_iter()
+        .filter_map(|(name, variant)| {
+            palette.get_color(&name, &variant).map(|color| PaletteKey {
+                name,
+                variant,
+                color,
+            })
+        })
+        .collect();
+    let base_colors: Mapping = keys
+        .iter()
+        .filter(|key| {
+            matches!(key.variant, ColorVariant::Normal)
+                && BASE_PALETTE_COLOR_NAMES.contains(&key.name.to_string().as_str())
+        })
+        .map(|key| (key.to_string().into(), key.color.to_source_string().into()))
+        .collect();
+
+    minimal_overrides(
+        base_colors,
+        &keys,
+        |key| key.color,
+        |basic: &BasicPalette| {
+            Palette::try_from_basic_with(basic, &derivation)
+                .map_err(|err| TintedBuilderError::UnableToConvertFrom(err.to_string()))
+        },
+        |derived, key| {
+            derived
+                .get_color(&key.name, &key.variant)
+                .map(Color::to_string)
+                .unwrap_or_default()
+        },
+    )
+}
+
+/// Colors that are required in every Tinted8 palette.
+const BASE_PALETTE_COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// A resolved palette color together with the `BasicPalette` key it is written under.
+struct PaletteKey<'a> {
+    name: ColorName,
+    variant: ColorVariant,
+    color: &'a Color,
+}
+
+impl fmt::Display for PaletteKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.variant {
+            ColorVariant::Normal => write!(f, "{}", self.name),
+            _ => write!(f, "{}-{}", self.name, self.variant),
+        }
+    }
+}
+
+/// Adds overrides one key at a time, in `keys` order, until the derived values match `actual`.
+///
+/// Adding a parent before its children lets children keep inheriting from it instead of being
+/// written out explicitly. An override is written as the `source` of its color, e.g. the color
+/// expression `gray.dim`, and only as hex when the source does not derive the same color.
+///
+/// Returns `TintedBuilderError::UnreproducibleColor` when writing a key as hex does not change
+/// the overrides, since the derived value would then never match.
+fn minimal_overrides<K, B, T>(
+    initial: Mapping,
+    keys: &[K],
+    actual: impl Fn(&K) -> &Color,
+    derive: impl Fn(&B) -> Result<T, TintedBuilderError>,
+    derived_color: impl Fn(&T, &K) -> String,
+) -> Result<Mapping, TintedBuilderError>
+where
+    K: fmt::Display,
+    B: DeserializeOwned,
+{
+    let mut overrides = initial;
+
+    loop {
+        let basic: B = serde_yaml::from_value(Value::Mapping(overrides.clone()))?;
+        let derived = derive(&basic)?;
+        let next_override = keys
+            .iter()
+            .find(|key| derived_color(&derived, key) != actual(key).to_string());
+
+        match next_override {
+            Some(key) => {
+                let key_value = Value::from(key.to_string());
+                let color = actual(key);
+                // Prefer the string the color was written as, unless it did not reproduce it.
+                let value: Value = match &color.source {
+                    Some(source) if !overrides.contains_key(&key_value) => source.clone(),
+                    _ => color.to_string(),
+                }
+                .into();
+
+                if overrides.get(&key_value) == Some(&value) {
+                    return Err(TintedBuilderError::UnreproducibleColor(
+                        key.to_string(),
+                        color.to_string(),
+                    ));
+                }
+
+                overrides.insert(key_value, value);
+            }
+            None => return Ok(overrides),
+        }
+    }
+}
+
+fn sorted_mapping(mapping: Mapping) -> Mapping {
+    let mut entries: Vec<(Value, Value)> = mapping.into_iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.as_str().cmp(&b.as_str()));
+
+    entries.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimal_overrides_stops_when_a_color_is_not_reproduced() -> Result<(), TintedBuilderError> {
+        let color = Color::new("#ff0000", None, None)?;
+        let result = minimal_overrides(
+            Mapping::new(),
+            &["red-dim"],
+            |_| &color,
+            |_: &Mapping| Ok(()),
+            |(), _| "#000000".to_string(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(TintedBuilderError::UnreproducibleColor(key, hex)) if key == "red-dim" && hex == "#ff0000"
+        ));
+
+        Ok(())
+    }
+}
@@ -1,16 +1,26 @@
 mod base16;
+mod filters;
+mod pair;
 mod tinted8;
 
-use crate::{error::TintedBuilderError, scheme::Scheme};
+use crate::{error::TintedBuilderError, scheme::Scheme, SchemePair};
 
 /// A struct representing a template that can be rendered with the provided color scheme.
 ///
 /// The `Template` struct holds the content of the template and the scheme used to render it. It
 /// provides methods to create a new template and render it into a `String` using the specified
 /// color scheme.
+///
+/// A template can also be rendered with a `SchemePair`, see `Template::new_pair`.
 pub struct Template {
     content: String,
-    scheme: Scheme,
+    scheme: TemplateScheme,
+}
+
+/// The scheme, or pair of schemes, a `Template` is rendered with.
+enum TemplateScheme {
+    Single(Scheme),
+    Pair(Box<SchemePair>),
 }
 
 impl Template {
@@ -26,7 +36,29 @@ impl Template {
     /// A new `Template` instance with the provided content and scheme.
     #[must_use]
     pub const fn new(content: String, scheme: Scheme) -> Self {
-        Self { content, scheme }
+        Self {
+            content,
+            scheme: TemplateScheme::Single(scheme),
+        }
+    }
+
+    /// Creates a new `Template` instance rendered with the dark and light schemes of a pair.
+    ///
+    /// The context has `pair` (`name`, `slug`, `slug-underscored` and `system`) and the context
+    /// of each scheme under `dark` and `light`: the flat Base16/Base24 variables, e.g.
+    /// `{{dark.base00-hex}}`, or the nested Tinted8 ones, e.g. `{{light.palette.red.normal.hex}}`.
+    /// Color filters take the same paths, e.g. `{{ dark.base00 | mix dark.base05 0.1 }}`.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - A `String` representing the content of the template.
+    /// * `pair` - The `SchemePair` to render the template with.
+    #[must_use]
+    pub fn new_pair(content: String, pair: SchemePair) -> Self {
+        Self {
+            content,
+            scheme: TemplateScheme::Pair(Box::new(pair)),
+        }
     }
 
     /// Renders the template into a `String` using the provided color scheme.
@@ -79,18 +111,27 @@ impl Template {
     /// );
     /// ```
     pub fn render(&self) -> Result<String, TintedBuilderError> {
-        match self.scheme {
-            Scheme::Base16(ref scheme) => {
+        let scheme = match self.scheme {
+            TemplateScheme::Single(ref scheme) => scheme,
+            TemplateScheme::Pair(ref pair) => {
+                let ctx = pair::to_template_context(pair)?;
+                let rendered = tinted8::render(&self.content, &ctx)?;
+                return Ok(rendered);
+            }
+        };
+
+        match scheme {
+            Scheme::Base16(scheme) => {
                 let ctx = base16::to_template_context(&scheme.into());
                 let rendered = base16::render(&self.content, &ctx)?;
                 Ok(rendered)
             }
-            Scheme::Base24(ref scheme) => {
+            Scheme::Base24(scheme) => {
                 let ctx = base16::to_template_context(&scheme.into());
                 let rendered = base16::render(&self.content, &ctx)?;
                 Ok(rendered)
             }
-            Scheme::Tinted8(ref scheme) => {
+            Scheme::Tinted8(scheme) => {
                 let ctx = tinted8::to_template_context(scheme)?;
                 let rendered = tinted8::render(&self.content, &ctx)?;
                 Ok(rendered)
@@ -1,12 +1,15 @@
 use std::collections::HashMap;
 
 use crate::error::TintedBuilderError;
-use crate::scheme::{Color, SchemeSystem, SchemeVariant};
+use crate::scheme::{ansi16_from_keys, Color, SchemeSystem, SchemeVariant};
+use crate::template::filters;
 
 /// Renders a mustache template using a flat string context (Base16/Base24 variables).
 pub fn render(content: &str, ctx: &HashMap<String, String>) -> Result<String, TintedBuilderError> {
-    let ctx = serde_yaml::to_string(&ctx)?;
-    let rendered = ribboncurls::render(content, &ctx, None)?;
+    let ctx_value = serde_yaml::to_value(ctx)?;
+    let ctx = serde_yaml::to_string(&ctx_value)?;
+    let content = filters::expand(content, &ctx_value, &ctx)?;
+    let rendered = ribboncurls::render(&content, &ctx, None)?;
 
     Ok(rendered)
 }
@@ -52,7 +55,10 @@ impl<'a> From<&'a crate::scheme::base24::Scheme> for SchemeContext<'a> {
 
 /// Builds the flat Base16/Base24 variable context expected by templates.
 ///
-/// Provides keys like `scheme-name`, `base0A-hex`, `base0A-hex-bgr`, `base0A-rgb-r`, etc.
+/// Provides keys like `scheme-name`, `base0A-hex`, `base0A-hex-bgr`, `base0A-rgb-r`,
+/// `base0A-hex-a`, `base0A-hsl-h`, `base0A-oklch-l`, `base0A-ansi256`, `base0A-xterm`, etc.
+/// `base0A-ansi16` is the nearest color in the scheme's ANSI-16 table, see `Scheme::ansi16`. The
+/// table itself is available as `ansi0`–`ansi15` with the same fields, e.g. `ansi1-hex`.
 pub fn to_template_context(scheme: &SchemeContext<'_>) -> HashMap<String, String> {
     let mut context = HashMap::new();
 
@@ -77,49 +83,95 @@ pub fn to_template_context(scheme: &SchemeContext<'_>) -> HashMap<String, String
         "true".to_string(),
     );
 
+    let ansi16 = ansi16_from_keys(scheme.palette, scheme.system).ok();
+
     for (name, color) in scheme.palette {
-        let hex = color.hex.clone();
-        let rgb = color.rgb;
+        insert_color_fields(&mut context, name, color, ansi16.as_ref());
+    }
 
-        context.insert(
-            format!("{name}-hex"),
-            format!("{}{}{}", color.hex.0, color.hex.1, color.hex.2),
-        );
-        context.insert(
-            format!("{name}-hex-bgr"),
-            format!("{}{}{}", color.hex.2, color.hex.1, color.hex.0),
-        );
-        context.insert(format!("{name}-hex-r"), hex.0);
-        context.insert(format!("{name}-hex-g"), hex.1);
-        context.insert(format!("{name}-hex-b"), hex.2);
-        context.insert(format!("{name}-rgb-r"), rgb.0.to_string());
-        context.insert(format!("{name}-rgb-g"), rgb.1.to_string());
-        context.insert(format!("{name}-rgb-b"), rgb.2.to_string());
-        context.insert(
-            format!("{name}-rgb16-r"),
-            (u16::from(rgb.0) * 257_u16).to_string(),
-        );
-        context.insert(
-            format!("{name}-rgb16-g"),
-            (u16::from(rgb.1) * 257_u16).to_string(),
-        );
-        context.insert(
-            format!("{name}-rgb16-b"),
-            (u16::from(rgb.2) * 257_u16).to_string(),
-        );
-        context.insert(
-            format!("{name}-dec-r"),
-            format!("{:.8}", f64::from(rgb.0) / 255.),
-        );
-        context.insert(
-            format!("{name}-dec-g"),
-            format!("{:.8}", f64::from(rgb.1) / 255.),
-        );
-        context.insert(
-            format!("{name}-dec-b"),
-            format!("{:.8}", f64::from(rgb.2) / 255.),
-        );
+    if let Some(ansi16) = &ansi16 {
+        for (index, color) in ansi16.iter().enumerate() {
+            insert_color_fields(&mut context, &format!("ansi{index}"), color, Some(ansi16));
+        }
     }
 
     context
 }
+
+/// Adds the `<name>-<field>` variables of a color, e.g. `base0A-hex` or `ansi1-rgb-r`.
+fn insert_color_fields(
+    context: &mut HashMap<String, String>,
+    name: &str,
+    color: &Color,
+    ansi16: Option<&[Color; 16]>,
+) {
+    let hex = color.hex.clone();
+    let rgb = color.rgb;
+
+    context.insert(
+        format!("{name}-hex"),
+        format!("{}{}{}", color.hex.0, color.hex.1, color.hex.2),
+    );
+    context.insert(
+        format!("{name}-hex-bgr"),
+        format!("{}{}{}", color.hex.2, color.hex.1, color.hex.0),
+    );
+    context.insert(format!("{name}-hex-rgba"), color.to_hex_rgba());
+    context.insert(format!("{name}-hex-upper"), color.to_hex().to_uppercase());
+    context.insert(format!("{name}-hex16"), color.to_hex16());
+    context.insert(format!("{name}-hex-r"), hex.0);
+    context.insert(format!("{name}-hex-g"), hex.1);
+    context.insert(format!("{name}-hex-b"), hex.2);
+    context.insert(format!("{name}-hex-a"), color.hex_alpha());
+    context.insert(format!("{name}-rgb-r"), rgb.0.to_string());
+    context.insert(format!("{name}-rgb-g"), rgb.1.to_string());
+    context.insert(format!("{name}-rgb-b"), rgb.2.to_string());
+    context.insert(format!("{name}-rgb-a"), color.alpha.to_string());
+    context.insert(
+        format!("{name}-rgb16-r"),
+        (u16::from(rgb.0) * 257_u16).to_string(),
+    );
+    context.insert(
+        format!("{name}-rgb16-g"),
+        (u16::from(rgb.1) * 257_u16).to_string(),
+    );
+    context.insert(
+        format!("{name}-rgb16-b"),
+        (u16::from(rgb.2) * 257_u16).to_string(),
+    );
+    context.insert(
+        format!("{name}-dec-r"),
+        format!("{:.8}", f64::from(rgb.0) / 255.),
+    );
+    context.insert(
+        format!("{name}-dec-g"),
+        format!("{:.8}", f64::from(rgb.1) / 255.),
+    );
+    context.insert(
+        format!("{name}-dec-b"),
+        format!("{:.8}", f64::from(rgb.2) / 255.),
+    );
+    context.insert(
+        format!("{name}-dec-a"),
+        format!("{:.8}", f64::from(color.alpha) / 255.),
+    );
+
+    let (h, s, l) = color.to_hsl();
+    context.insert(format!("{name}-hsl-h"), format!("{h:.2}"));
+    context.insert(format!("{name}-hsl-s"), format!("{:.2}", s * 100.0));
+    context.insert(format!("{name}-hsl-l"), format!("{:.2}", l * 100.0));
+
+    let (l, c, h) = color.to_oklch();
+    context.insert(format!("{name}-oklch-l"), format!("{l:.4}"));
+    context.insert(format!("{name}-oklch-c"), format!("{c:.4}"));
+    context.insert(format!("{name}-oklch-h"), format!("{h:.2}"));
+
+    context.insert(format!("{name}-ansi256"), color.to_ansi256().to_string());
+    if let Some(ansi16) = ansi16 {
+        context.insert(
+            format!("{name}-ansi16"),
+            color.nearest_ansi16(ansi16).to_string(),
+        );
+    }
+    context.insert(format!("{name}-xterm"), color.to_xterm());
+}
@@ -0,0 +1,382 @@
+use serde_yaml::Value;
+
+use crate::{error::TintedBuilderError, Color};
+
+/// Color filters that take a color and return a color.
+const COLOR_FILTERS: [&str; 7] = [
+    "darken",
+    "lighten",
+    "saturate",
+    "desaturate",
+    "mix",
+    "alpha",
+    "blend",
+];
+
+/// Expands color filters in a template before it is rendered with `ribboncurls`.
+///
+/// Two forms are supported, both resolved against the top-level template context `ctx`:
+///
+/// - Pipes: `{{ base00 | mix base05 0.2 | hex }}` starts with a color and applies each filter
+///   from left to right. The last filter may be an output format; the default is `hex`. Pipes
+///   can also be written unescaped, as `{{{ base00 | hex }}}` or `{{& base00 | hex }}`.
+/// - Sections: `{{#darken 10%}}{{base00-hex}}{{/darken}}` renders the section content and
+///   applies the filter to the resulting color, keeping a leading `#`.
+///
+/// Colors are written as a context key (`base00`, `palette.blue.normal`, `ui.background`) or
+/// as any color `Color::new` accepts. See `apply_filter` and `format_color` for the filters.
+/// Since filters do not see the context of mustache sections, filters inside a section like
+/// `{{#palette}}` are rejected.
+pub fn expand(content: &str, ctx: &Value, ctx_yaml: &str) -> Result<String, TintedBuilderError> {
+    let mut output = String::new();
+    let mut open_sections: Vec<OpenSection> = vec![];
+    let mut index = 0;
+
+    for tag in parse_tags(content) {
+        let source = &content[tag.start..tag.end];
+        output.push_str(&content[index..tag.start]);
+        index = tag.end;
+
+        match tag.kind {
+            TagKind::Variable if tag.body.contains('|') => {
+                check_top_level(source, &open_sections)?;
+                output.push_str(&expand_pipe(tag.body, ctx)?);
+            }
+            TagKind::Section if is_filter_section(tag.body) => {
+                check_top_level(source, &open_sections)?;
+                open_sections.push(OpenSection::Filter {
+                    tag: source,
+                    filter: tag.body,
+                    start: output.len(),
+                });
+            }
+            TagKind::Section | TagKind::InvertedSection => {
+                open_sections.push(OpenSection::Mustache(tag.body));
+                output.push_str(source);
+            }
+            TagKind::Close => match open_sections.pop() {
+                Some(OpenSection::Filter {
+                    tag: open_tag,
+                    filter,
+                    start,
+                }) => {
+                    if tag_name(filter) != tag_name(tag.body) {
+                        return Err(invalid_filter(open_tag));
+                    }
+
+                    let inner = output.split_off(start);
+                    output.push_str(&expand_section(filter, &inner, ctx, ctx_yaml)?);
+                }
+                Some(OpenSection::Mustache(_)) | None => output.push_str(source),
+            },
+            TagKind::Variable | TagKind::Other => output.push_str(source),
+        }
+    }
+    output.push_str(&content[index..]);
+
+    match open_sections
+        .iter()
+        .find(|section| matches!(section, OpenSection::Filter { .. }))
+    {
+        Some(OpenSection::Filter { tag, .. }) => Err(invalid_filter(tag)),
+        _ => Ok(output),
+    }
+}
+
+/// A mustache tag, with `body` being its content without the delimiters and sigil.
+struct Tag<'a> {
+    start: usize,
+    end: usize,
+    kind: TagKind,
+    body: &'a str,
+}
+
+enum TagKind {
+    Variable,
+    Section,
+    InvertedSection,
+    Close,
+    Other,
+}
+
+/// A section that is open while expanding filters.
+enum OpenSection<'a> {
+    /// A filter section like `{{#darken 10%}}`, whose content starts at `start` in the output.
+    Filter {
+        tag: &'a str,
+        filter: &'a str,
+        start: usize,
+    },
+    /// Any other mustache section, e.g. `{{#palette}}`.
+    Mustache(&'a str),
+}
+
+/// Finds the mustache tags of a template, including `{{{ }}}` tags.
+///
+/// Tags after a set delimiter tag (`{{=<% %>=}}`) are not searched for, since `{{` no longer
+/// starts a tag there.
+fn parse_tags(content: &str) -> Vec<Tag<'_>> {
+    let mut tags = vec![];
+    let mut index = 0;
+
+    while let Some(offset) = content[index..].find("{{") {
+        let start = index + offset;
+        let (open, close) = if content[start..].starts_with("{{{") {
+            ("{{{", "}}}")
+        } else {
+            ("{{", "}}")
+        };
+        let body_start = start + open.len();
+        let Some(length) = content[body_start..].find(close) else {
+            break;
+        };
+        let end = body_start + length + close.len();
+        let inner = content[body_start..body_start + length].trim();
+        let (kind, body) = if open == "{{{" {
+            (TagKind::Variable, inner)
+        } else if let Some(body) = inner.strip_prefix('&') {
+            (TagKind::Variable, body.trim())
+        } else if let Some(body) = inner.strip_prefix('#') {
+            (TagKind::Section, body.trim())
+        } else if let Some(body) = inner.strip_prefix('^') {
+            (TagKind::InvertedSection, body.trim())
+        } else if let Some(body) = inner.strip_prefix('/') {
+            (TagKind::Close, body.trim())
+        } else if inner.starts_with('=') {
+            break;
+        } else if inner.starts_with(['!', '>']) {
+            (TagKind::Other, inner)
+        } else {
+            (TagKind::Variable, inner)
+        };
+
+        tags.push(Tag {
+            start,
+            end,
+            kind,
+            body,
+        });
+        index = end;
+    }
+
+    tags
+}
+
+/// Returns an error if a filter tag is inside a mustache section, where filters would resolve
+/// colors against the top-level context instead of the section's.
+fn check_top_level(tag: &str, open_sections: &[OpenSection]) -> Result<(), TintedBuilderError> {
+    let section = open_sections
+        .iter()
+        .rev()
+        .find_map(|section| match section {
+            OpenSection::Mustache(name) => Some(name),
+            OpenSection::Filter { .. } => None,
+        });
+
+    section.map_or(Ok(()), |section| {
+        Err(TintedBuilderError::TemplateFilterInSection(
+            tag.to_string(),
+            tag_name(section).to_string(),
+        ))
+    })
+}
+
+fn tag_name(body: &str) -> &str {
+    body.split_whitespace().next().unwrap_or_default()
+}
+
+fn is_filter_section(body: &str) -> bool {
+    body.split_once(char::is_whitespace)
+        .is_some_and(|(name, _)| COLOR_FILTERS.contains(&name))
+}
+
+/// Expands the body of a `{{ <color> | <filter> ... }}` tag.
+fn expand_pipe(body: &str, ctx: &Value) -> Result<String, TintedBuilderError> {
+    let mut parts = body.split('|').map(str::trim);
+    let color = parts
+        .next()
+        .ok_or_else(|| invalid_filter(body))
+        .and_then(|reference| resolve_color(reference, ctx))?;
+    let mut filters: Vec<&str> = parts.collect();
+    let format = match filters.last() {
+        Some(last) if !is_color_filter(last) => filters.pop().unwrap_or("hex"),
+        _ => "hex",
+    };
+    let color = filters
+        .iter()
+        .try_fold(color, |color, filter| apply_filter(color, filter, ctx))?;
+
+    format_color(&color, format)
+}
+
+/// Renders the content of a filter section and applies `filter` to the resulting color.
+fn expand_section(
+    filter: &str,
+    content: &str,
+    ctx: &Value,
+    ctx_yaml: &str,
+) -> Result<String, TintedBuilderError> {
+    let inner = ribboncurls::render(content, ctx_yaml, None)?;
+    let inner = inner.trim();
+    let color = Color::new(inner, None, None).map_err(|_| invalid_filter(inner))?;
+    let filtered = apply_filter(color, filter, ctx)?;
+    let hex = format_color(&filtered, "hex")?;
+    let prefix = if inner.starts_with('#') { "#" } else { "" };
+
+    Ok(format!("{prefix}{hex}"))
+}
+
+fn is_color_filter(filter: &str) -> bool {
+    filter
+        .split_whitespace()
+        .next()
+        .is_some_and(|name| COLOR_FILTERS.contains(&name))
+}
+
+/// Applies a color filter written as `<name> <arguments>`:
+///
+/// - `darken <amount>`, `lighten <amount>`: `Color::darken`, `Color::lighten`
+/// - `saturate <amount>`, `desaturate <amount>`: `Color::saturate`, `Color::desaturate`
+/// - `mix <color> <weight>`: `Color::mix`, where `weight` is the share of `<color>`
+/// - `alpha <amount>`: sets the alpha channel
+/// - `blend <color>`: `Color::blend_over`, composites the color over `<color>`
+///
+/// Amounts and weights are fractions (`0.1`) or percentages (`10%`). Numbers above `1` are
+/// percentages without a `%` sign too, so `darken 10` is `darken 10%`.
+fn apply_filter(color: Color, filter: &str, ctx: &Value) -> Result<Color, TintedBuilderError> {
+    let arguments: Vec<&str> = filter.split_whitespace().collect();
+    let invalid = || invalid_filter(filter);
+    let amount = |value: &str| parse_amount(value).ok_or_else(invalid);
+
+    match arguments.as_slice() {
+        ["darken", value] => Ok(color.darken(amount(value)?)),
+        ["lighten", value] => Ok(color.lighten(amount(value)?)),
+        ["saturate", value] => Ok(color.saturate(amount(value)?)),
+        ["desaturate", value] => Ok(color.desaturate(amount(value)?)),
+        ["mix", other, weight] => Ok(color.mix(&resolve_color(other, ctx)?, amount(weight)?)),
+        ["alpha", value] => Ok(color.with_alpha(to_alpha(amount(value)?))),
+        ["blend", background] => Ok(color.blend_over(&resolve_color(background, ctx)?)),
+        _ => Err(invalid()),
+    }
+}
+
+/// Formats a color with an output format: `hex`, `hex-upper`, `hex-rgba`, `hex-bgr`, `hex16`,
+/// `xterm`, `ansi256`, `rgb`, `rgba`, `hsl` or `oklch`. The last four are CSS color functions.
+/// `hex` and `hex-upper` add the alpha channel of colors that are not opaque, like `hex-rgba`.
+fn format_color(color: &Color, format: &str) -> Result<String, TintedBuilderError> {
+    let output = match format {
+        "hex" if color.is_opaque() => color.to_hex(),
+        "hex" | "hex-rgba" => color.to_hex_rgba(),
+        "hex-upper" => format_color(color, "hex")?.to_uppercase(),
+        "hex-bgr" => format!("{}{}{}", color.hex.2, color.hex.1, color.hex.0),
+        "hex16" => color.to_hex16(),
+        "xterm" => color.to_xterm(),
+        "ansi256" => color.to_ansi256().to_string(),
+        "rgb" => format!("rgb({}, {}, {})", color.rgb.0, color.rgb.1, color.rgb.2),
+        "rgba" => format!(
+            "rgba({}, {}, {}, {:.2})",
+            color.rgb.0,
+            color.rgb.1,
+            color.rgb.2,
+            f32::from(color.alpha) / 255.0
+        ),
+        "hsl" => {
+            let (h, s, l) = color.to_hsl();
+
+            format!("hsl({h:.2}, {:.2}%, {:.2}%)", s * 100.0, l * 100.0)
+        }
+        "oklch" => {
+            let (l, c, h) = color.to_oklch();
+
+            format!("oklch({l:.4} {c:.4} {h:.2})")
+        }
+        _ => return Err(invalid_filter(format)),
+    };
+
+    Ok(output)
+}
+
+/// Resolves a color from a context key or a color literal.
+///
+/// Flat (Base16/Base24) keys like `base00` resolve through `base00-hex-rgba`, also under a path
+/// like `dark.base00`; dotted keys like `palette.blue.normal` resolve to the color object at that
+/// path.
+fn resolve_color(reference: &str, ctx: &Value) -> Result<Color, TintedBuilderError> {
+    let (parent, key) = reference
+        .rsplit_once('.')
+        .map_or((None, reference), |(parent, key)| (Some(parent), key));
+    let flat = parent
+        .map_or(Some(ctx), |parent| {
+            parent.split('.').try_fold(ctx, |value, key| value.get(key))
+        })
+        .and_then(|value| value.get(format!("{key}-hex-rgba")));
+    let nested = reference
+        .split('.')
+        .try_fold(ctx, |value, key| value.get(key))
+        .and_then(|value| value.get("hex-rgba"));
+
+    let input = flat.or(nested).and_then(Value::as_str).unwrap_or(reference);
+
+    Color::new(input, None, None).map_err(|_| invalid_filter(reference))
+}
+
+fn parse_amount(value: &str) -> Option<f32> {
+    let (number, is_percentage) = value
+        .strip_suffix('%')
+        .map_or((value, false), |number| (number, true));
+    let number = number.parse::<f32>().ok().filter(|n| n.is_finite())?;
+
+    if is_percentage || number.abs() > 1.0 {
+        Some(number / 100.0)
+    } else {
+        Some(number)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_alpha(amount: f32) -> u8 {
+    (amount.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn invalid_filter(filter: &str) -> TintedBuilderError {
+    TintedBuilderError::InvalidTemplateFilter(filter.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_amounts_and_rejects_unbalanced_sections() {
+        let ctx = Value::Null;
+
+        assert_eq!(parse_amount("0.2"), Some(0.2));
+        assert_eq!(parse_amount("20%"), Some(0.2));
+        assert_eq!(parse_amount("1"), Some(1.0));
+        assert_eq!(parse_amount("20"), Some(0.2));
+        assert_eq!(parse_amount("a lot"), None);
+        assert_eq!(
+            expand("{{ ff0000 | darken 10 }}", &ctx, "").ok(),
+            expand("{{ ff0000 | darken 10% }}", &ctx, "").ok()
+        );
+        assert_eq!(
+            expand(
+                "{{ ff0000 | alpha 50 }} {{#alpha 50}}ff0000{{/alpha}}",
+                &ctx,
+                ""
+            )
+            .ok()
+            .as_deref(),
+            Some("ff000080 ff000080")
+        );
+        assert!(expand("{{#darken 10%}}ff0000", &ctx, "").is_err());
+        assert!(expand("{{#darken 10%}}ff0000{{/lighten}}", &ctx, "").is_err());
+        assert!(expand("{{ ff0000 | mix 0.5 }}", &ctx, "").is_err());
+        assert_eq!(
+            expand("{{ ff0000 | darken 1% | xterm }}", &ctx, "")
+                .ok()
+                .as_deref(),
+            Some("rgb:fafa/0000/0000")
+        );
+    }
+}
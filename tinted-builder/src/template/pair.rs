@@ -0,0 +1,49 @@
+use serde::Serialize;
+use serde_yaml::Value;
+
+use crate::{error::TintedBuilderError, template::base16, template::tinted8, Scheme, SchemePair};
+
+#[derive(Serialize)]
+struct PairMetaCtx {
+    name: String,
+    slug: String,
+    #[serde(rename = "slug-underscored")]
+    slug_underscored: String,
+    system: String,
+}
+
+#[derive(Serialize)]
+struct TemplateCtx {
+    pair: PairMetaCtx,
+    dark: Value,
+    light: Value,
+}
+
+/// Builds the template context of a scheme pair: the `pair` metadata and the context of the
+/// dark and light schemes, as they are built for templates rendered with a single scheme.
+pub fn to_template_context(pair: &SchemePair) -> Result<Value, TintedBuilderError> {
+    let ctx = TemplateCtx {
+        pair: PairMetaCtx {
+            name: pair.name.clone(),
+            slug: pair.slug.clone(),
+            slug_underscored: pair.slug.replace('-', "_"),
+            system: pair.get_scheme_system().to_string(),
+        },
+        dark: scheme_context(&pair.dark)?,
+        light: scheme_context(&pair.light)?,
+    };
+
+    Ok(serde_yaml::to_value(&ctx)?)
+}
+
+fn scheme_context(scheme: &Scheme) -> Result<Value, TintedBuilderError> {
+    match scheme {
+        Scheme::Base16(scheme) => Ok(serde_yaml::to_value(base16::to_template_context(
+            &scheme.into(),
+        ))?),
+        Scheme::Base24(scheme) => Ok(serde_yaml::to_value(base16::to_template_context(
+            &scheme.into(),
+        ))?),
+        Scheme::Tinted8(scheme) => tinted8::to_template_context(scheme),
+    }
+}
@@ -1,15 +1,19 @@
 use crate::{
-    error::TintedBuilderError, tinted8::Scheme as Tinted8Scheme, SchemeSupports, SchemeVariant,
+    error::TintedBuilderError, template::filters, tinted8::Scheme as Tinted8Scheme, Color,
+    SchemeSupports, SchemeVariant,
 };
 use serde::Serialize;
+use serde_yaml::Value;
 
 /// Render a template with any serializable context.
 ///
 /// This allows passing structured (YAML) objects so templates can
 /// access nested fields like `blue-bright.hex`.
 pub fn render<T: serde::Serialize>(content: &str, ctx: &T) -> Result<String, TintedBuilderError> {
-    let ctx = serde_yaml::to_string(&ctx)?;
-    let rendered = ribboncurls::render(content, &ctx, None)?;
+    let ctx_value = serde_yaml::to_value(ctx)?;
+    let ctx = serde_yaml::to_string(&ctx_value)?;
+    let content = filters::expand(content, &ctx_value, &ctx)?;
+    let rendered = ribboncurls::render(&content, &ctx, None)?;
 
     Ok(rendered)
 }
@@ -44,6 +48,7 @@ struct TemplateCtx<'a> {
     palette: &'a crate::scheme::tinted8::structure::Palette,
     syntax: &'a crate::scheme::tinted8::structure::Syntax,
     ui: &'a crate::scheme::tinted8::structure::Ui,
+    ansi: &'a [Color; 16],
     variant: &'a SchemeVariant,
     option: &'a OptionCtx,
 }
@@ -51,8 +56,11 @@ struct TemplateCtx<'a> {
 /// Builds a structured YAML context for Tinted8 templates.
 ///
 /// The context exposes nested objects under `scheme`, `palette`, `ui`, and `syntax` matching
-/// the Tinted8 builder specification. Color objects include `hex`, `hex-r/g/b`, `hex-bgr`,
-/// `rgb`, `rgb16`, and `dec` fields.
+/// the Tinted8 builder specification. Color objects include `hex`, `hex-r/g/b/a`, `hex-bgr`,
+/// `hex-rgba`, `rgb`, `rgb16`, and `dec` fields, plus a `blended` color object: the color
+/// composited over `ui.global.background.normal`, for templates that cannot use alpha, and
+/// `ansi16`: the index of the nearest color in `Palette::ansi16`. The ANSI-16 table itself is
+/// the `ansi` list of color objects.
 pub fn to_template_context(
     scheme: &Tinted8Scheme,
 ) -> Result<serde_yaml::Value, TintedBuilderError> {
@@ -75,16 +83,66 @@ pub fn to_template_context(
         is_dark_variant: *variant == SchemeVariant::Dark,
     };
 
+    let ansi16 = scheme.palette.ansi16();
     let ctx = TemplateCtx {
         scheme: scheme_ctx,
         palette: &scheme.palette,
         syntax: &scheme.syntax,
         ui: &scheme.ui,
+        ansi: &ansi16,
         variant,
         option,
     };
 
-    Ok(serde_yaml::to_value(&ctx)?)
+    let mut value = serde_yaml::to_value(&ctx)?;
+    let background = &scheme.ui.global.background.normal;
+
+    if let Value::Mapping(root) = &mut value {
+        for key in ["palette", "syntax", "ui", "ansi"] {
+            if let Some(colors) = root.get_mut(key) {
+                insert_scheme_color_fields(colors, background, &ansi16)?;
+            }
+        }
+    }
+
+    Ok(value)
+}
+
+/// Adds the fields that depend on the scheme, `blended` and `ansi16`, to every serialized color
+/// found in `value`.
+fn insert_scheme_color_fields(
+    value: &mut Value,
+    background: &Color,
+    ansi16: &[Color; 16],
+) -> Result<(), TintedBuilderError> {
+    let mapping = match value {
+        Value::Mapping(mapping) => mapping,
+        Value::Sequence(items) => {
+            for item in items {
+                insert_scheme_color_fields(item, background, ansi16)?;
+            }
+
+            return Ok(());
+        }
+        _ => return Ok(()),
+    };
+
+    if let Some(hex_rgba) = mapping.get("hex-rgba").and_then(Value::as_str) {
+        let color = Color::new(hex_rgba, None, None)?;
+        mapping.insert("ansi16".into(), color.nearest_ansi16(ansi16).into());
+        mapping.insert(
+            "blended".into(),
+            serde_yaml::to_value(color.blend_over(background))?,
+        );
+
+        return Ok(());
+    }
+
+    for child in mapping.values_mut() {
+        insert_scheme_color_fields(child, background, ansi16)?;
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -132,4 +190,44 @@ palette:
             .expect("unable to get mapping");
         assert!(normal.contains_key(serde_yaml::Value::String("hex-bgr".into())));
     }
+
+    #[test]
+    fn context_includes_alpha_and_blended_colors() {
+        let yml = r##"
+scheme:
+  system: "tinted8"
+  supports:
+    styling-spec: "0.2.0"
+  author: "User <user@example.com>"
+  name: "Test"
+  slug: "test"
+variant: "dark"
+palette:
+  black:   "#000000"
+  red:     "#ff0000"
+  green:   "#00ff00"
+  yellow:  "#ffff00"
+  blue:    "#0000ff"
+  magenta: "#ff00ff"
+  cyan:    "#00ffff"
+  white:   "#ffffff"
+ui:
+  global.background.normal: "#1a1b26"
+  selection.background: "#33467c80"
+"##;
+        let s: Tinted8Scheme = serde_yaml::from_str(yml).expect("unable to deserialize");
+        let val = to_template_context(&s).expect("Unable to get template context");
+        let selection = &val["ui"]["selection"]["background"];
+
+        assert_eq!(selection["hex"].as_str(), Some("33467c"));
+        assert_eq!(selection["hex-a"].as_str(), Some("80"));
+        assert_eq!(selection["hex-rgba"].as_str(), Some("33467c80"));
+        assert_eq!(selection["rgb"]["a"].as_u64(), Some(128));
+        assert_eq!(selection["dec"]["a"].as_str(), Some("0.50196078"));
+        assert_eq!(selection["blended"]["hex"].as_str(), Some("273151"));
+        assert_eq!(
+            val["palette"]["red"]["normal"]["blended"]["hex-a"].as_str(),
+            Some("ff")
+        );
+    }
 }
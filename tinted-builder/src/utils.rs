@@ -1,7 +1,10 @@
 use regex::Regex;
+use serde_yaml::Value;
 use std::collections::HashMap;
 
+use crate::scheme::tinted8::{structure::Palette, ColorExpression};
 use crate::{Color, TintedBuilderError};
+use std::str::FromStr;
 
 /// Slugifies a string using ASCII-only, kebab-case output.
 ///
@@ -101,8 +104,68 @@ pub fn titlecasify(input: &str) -> String {
         .join(" ")
 }
 
+/// Normalizes the casing of a Base16/Base24 palette key.
+///
+/// Examples:
+/// - `"base0a"` -> `"base0A"`
+/// - `"BASE1f"` -> `"base1F"`
+/// - `"other"` -> `"other"`
+pub fn normalize_palette_key(key: &str) -> String {
+    match (key.get(..4), key.get(4..)) {
+        (Some(prefix), Some(suffix))
+            if prefix.eq_ignore_ascii_case("base")
+                && suffix.len() == 2
+                && suffix.chars().all(|c| c.is_ascii_hexdigit()) =>
+        {
+            format!("base{}", suffix.to_ascii_uppercase())
+        }
+        _ => key.to_string(),
+    }
+}
+
+/// Normalizes the casing of every key of a Base16/Base24 palette with `normalize_palette_key`.
+///
+/// Returns an error message naming the keys when two keys normalize to the same key, e.g.
+/// `base0a` and `base0A`, instead of keeping either value.
+pub fn normalize_palette_keys(
+    palette: HashMap<String, String>,
+) -> Result<HashMap<String, String>, String> {
+    let mut entries: Vec<(String, String)> = palette.into_iter().collect();
+    entries.sort();
+
+    let mut normalized_palette: HashMap<String, String> = HashMap::new();
+    let mut original_keys: HashMap<String, String> = HashMap::new();
+
+    for (key, value) in entries {
+        let normalized_key = normalize_palette_key(&key);
+
+        if let Some(original_key) = original_keys.get(&normalized_key) {
+            return Err(format!(
+                "palette keys \"{original_key}\" and \"{key}\" are both \"{normalized_key}\""
+            ));
+        }
+
+        original_keys.insert(normalized_key.clone(), key);
+        normalized_palette.insert(normalized_key, value);
+    }
+
+    Ok(normalized_palette)
+}
+
+/// Replaces the `palette` colors of a serialized Base16/Base24 scheme with the strings they were
+/// written as, see `Color::to_source_string`.
+pub fn write_palette_sources(scheme: &mut Value, palette: &HashMap<String, Color>) {
+    if let Some(Value::Mapping(palette_value)) = scheme.get_mut("palette") {
+        for (key, color) in palette {
+            palette_value.insert(key.as_str().into(), color.to_source_string().into());
+        }
+    }
+}
+
 /// Parse a color with parent inheritance semantics.
 ///
+/// Values are parsed as a `ColorExpression`, so they can reference and modify `palette` colors.
+///
 /// Resolution order:
 /// 1. Use and parse `value` if provided.
 /// 2. Otherwise, use `parent` if provided (parsed via `parse_or_inherit`).
@@ -117,6 +180,7 @@ pub fn titlecasify(input: &str) -> String {
 pub fn parse_or_inherit(
     value_list: &[Option<&str>],
     default: &Color,
+    palette: &Palette,
 ) -> Result<Color, TintedBuilderError> {
     let value_list: Vec<String> = value_list
         .iter()
@@ -126,7 +190,8 @@ pub fn parse_or_inherit(
     value_list.first().map_or_else(
         || Ok(default.clone()),
         |val| {
-            Color::new(val, None, None)
+            ColorExpression::from_str(val)
+                .and_then(|expression| expression.resolve(palette))
                 .map_err(|e| TintedBuilderError::UnableToConvertFrom(e.to_string()))
         },
     )
@@ -134,7 +199,8 @@ pub fn parse_or_inherit(
 
 #[cfg(test)]
 mod tests {
-    use super::{slugify, titlecasify};
+    use super::{normalize_palette_key, normalize_palette_keys, slugify, titlecasify};
+    use std::collections::HashMap;
 
     #[test]
     fn slugify_basic_and_unicode() {
@@ -150,4 +216,26 @@ mod tests {
         assert_eq!(titlecasify("rose-pine"), "Rose Pine");
         assert_eq!(titlecasify("default-dark"), "Default Dark");
     }
+
+    #[test]
+    fn normalize_palette_key_casing() {
+        assert_eq!(normalize_palette_key("base0a"), "base0A");
+        assert_eq!(normalize_palette_key("BASE1f"), "base1F");
+        assert_eq!(normalize_palette_key("base0A"), "base0A");
+        assert_eq!(normalize_palette_key("base0g"), "base0g");
+        assert_eq!(normalize_palette_key("other"), "other");
+    }
+
+    #[test]
+    fn normalize_palette_keys_rejects_colliding_keys() {
+        let palette = HashMap::from([
+            ("base0a".to_string(), "ff0000".to_string()),
+            ("base0A".to_string(), "00ff00".to_string()),
+        ]);
+
+        assert_eq!(
+            normalize_palette_keys(palette),
+            Err(r#"palette keys "base0A" and "base0a" are both "base0A""#.to_string())
+        );
+    }
 }
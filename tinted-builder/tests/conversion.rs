@@ -0,0 +1,294 @@
+use tinted_builder::{
+    base16::Scheme as Base16Scheme, base24::Scheme as Base24Scheme,
+    tinted8::Scheme as Tinted8Scheme, Scheme, SchemeSystem, Template, TintedBuilderError,
+};
+
+#[test]
+fn base16_upconverts_to_base24() -> Result<(), TintedBuilderError> {
+    let base16: Base16Scheme = serde_yaml::from_str(SCHEME_SILK_LIGHT)?;
+    let base24 = Base24Scheme::try_from(base16.clone())?;
+
+    assert_eq!(base24.system, SchemeSystem::Base24);
+    assert_eq!(base24.name, base16.name);
+    assert_eq!(base24.slug, base16.slug);
+    assert_eq!(base24.variant, base16.variant);
+    assert_eq!(base24.palette.len(), 24);
+
+    for (key, color) in &base16.palette {
+        assert_eq!(
+            base24.palette[key].to_hex(),
+            color.to_hex(),
+            "{key} changed"
+        );
+    }
+
+    assert_eq!(base24.palette["base10"].to_hex(), "e9f1ef");
+    assert_eq!(base24.palette["base11"].to_hex(), "e9f1ef");
+    assert_eq!(base24.palette["base12"].to_hex(), "cf432e");
+    assert_eq!(base24.palette["base13"].to_hex(), "cfad25");
+    assert_eq!(base24.palette["base14"].to_hex(), "6ca38c");
+    assert_eq!(base24.palette["base15"].to_hex(), "329ca2");
+    assert_eq!(base24.palette["base16"].to_hex(), "39aac9");
+    assert_eq!(base24.palette["base17"].to_hex(), "6e6582");
+
+    Ok(())
+}
+
+#[test]
+fn scheme_to_system_renders_base24_template() -> Result<(), TintedBuilderError> {
+    let scheme = Scheme::from_yaml(SCHEME_SILK_LIGHT)?.to_system(&SchemeSystem::Base24)?;
+    let template = Template::new(
+        "{{scheme-system}} #{{base12-hex}} #{{base17-hex}}".to_string(),
+        scheme,
+    );
+
+    assert_eq!(template.render()?, "base24 #cf432e #6e6582");
+
+    Ok(())
+}
+
+#[test]
+fn base24_downconverts_to_base16() -> Result<(), TintedBuilderError> {
+    let base24: Base24Scheme = serde_yaml::from_str(SCHEME_BASE24_DARK)?;
+    let base16 = Base16Scheme::from(base24.clone());
+
+    assert_eq!(base16.system, SchemeSystem::Base16);
+    assert_eq!(base16.slug, base24.slug);
+    assert_eq!(base16.palette.len(), 16);
+    assert_eq!(base16.palette["base00"].to_hex(), "000000");
+    assert_eq!(base16.palette["base0F"].to_hex(), "ffffff");
+    assert!(!base16.palette.contains_key("base10"));
+
+    Ok(())
+}
+
+#[test]
+fn scheme_to_system_round_trips_base16_through_base24() -> Result<(), TintedBuilderError> {
+    let scheme = Scheme::from_yaml(SCHEME_SILK_LIGHT)?
+        .to_system(&SchemeSystem::Base24)?
+        .to_system(&SchemeSystem::Base16)?;
+    let Scheme::Base16(base16) = scheme else {
+        panic!("expected a base16 scheme");
+    };
+    let original: Base16Scheme = serde_yaml::from_str(SCHEME_SILK_LIGHT)?;
+
+    assert_eq!(base16.to_string(), original.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn tinted8_to_yaml_string_round_trips() -> Result<(), TintedBuilderError> {
+    let base24: Base24Scheme = serde_yaml::from_str(SCHEME_BASE24_DARK)?;
+    let tinted8 = Tinted8Scheme::try_from(base24)?;
+    let yaml = tinted8.to_yaml_string()?;
+    let round_tripped: Tinted8Scheme = serde_yaml::from_str(&yaml)?;
+
+    assert!(yaml.contains("red-bright: '#121212'"));
+    assert!(!yaml.contains("red-dim"));
+    assert!(!yaml.contains("ui:"));
+    assert_eq!(round_tripped.scheme.name, tinted8.scheme.name);
+    assert_eq!(
+        round_tripped.palette.to_string(),
+        tinted8.palette.to_string()
+    );
+    assert_eq!(round_tripped.ui.to_string(), tinted8.ui.to_string());
+    assert_eq!(round_tripped.syntax.to_string(), tinted8.syntax.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn base16_light_converts_to_tinted8() -> Result<(), TintedBuilderError> {
+    let base16: Base16Scheme = serde_yaml::from_str(SCHEME_SILK_LIGHT)?;
+    let tinted8 = Tinted8Scheme::try_from(base16)?;
+
+    assert_eq!(tinted8.scheme.system, SchemeSystem::Tinted8);
+    assert_eq!(tinted8.scheme.name, "Silk Light");
+    assert_eq!(tinted8.scheme.slug, "silk-light");
+    assert_eq!(tinted8.scheme.converted_from, Some(SchemeSystem::Base16));
+    assert_eq!(tinted8.palette.white_normal.to_hex(), "e9f1ef");
+    assert_eq!(tinted8.palette.black_normal.to_hex(), "0e3c46");
+    assert_eq!(tinted8.palette.red_normal.to_hex(), "cf432e");
+    assert_eq!(tinted8.palette.orange_normal.to_hex(), "d27f46");
+    assert_eq!(tinted8.palette.yellow_normal.to_hex(), "cfad25");
+    assert_eq!(tinted8.palette.green_normal.to_hex(), "6ca38c");
+    assert_eq!(tinted8.palette.cyan_normal.to_hex(), "329ca2");
+    assert_eq!(tinted8.palette.blue_normal.to_hex(), "39aac9");
+    assert_eq!(tinted8.palette.magenta_normal.to_hex(), "6e6582");
+    assert_eq!(tinted8.palette.brown_normal.to_hex(), "865369");
+    assert_eq!(tinted8.ui.global.background.normal.to_hex(), "e9f1ef");
+
+    Ok(())
+}
+
+#[test]
+fn base24_converts_to_tinted8_with_brights() -> Result<(), TintedBuilderError> {
+    let base24: Base24Scheme = serde_yaml::from_str(SCHEME_BASE24_DARK)?;
+    let tinted8 = Tinted8Scheme::try_from(base24)?;
+
+    assert_eq!(tinted8.scheme.converted_from, Some(SchemeSystem::Base24));
+    assert_eq!(tinted8.palette.black_normal.to_hex(), "000000");
+    assert_eq!(tinted8.palette.white_normal.to_hex(), "777777");
+    assert_eq!(tinted8.palette.red_bright.to_hex(), "121212");
+    assert_eq!(tinted8.palette.yellow_bright.to_hex(), "131313");
+    assert_eq!(tinted8.palette.green_bright.to_hex(), "141414");
+    assert_eq!(tinted8.palette.cyan_bright.to_hex(), "151515");
+    assert_eq!(tinted8.palette.blue_bright.to_hex(), "161616");
+    assert_eq!(tinted8.palette.magenta_bright.to_hex(), "171717");
+    assert_eq!(tinted8.ui.global.background.normal.to_hex(), "000000");
+
+    Ok(())
+}
+
+#[test]
+fn tinted8_palette_keys_name_the_converted_colors() {
+    use tinted_builder::tinted8::{BASE16_TO_TINTED8_PALETTE_KEYS, BASE24_TO_TINTED8_PALETTE_KEYS};
+
+    assert_eq!(BASE16_TO_TINTED8_PALETTE_KEYS[1], ("base07", "white"));
+    assert_eq!(BASE16_TO_TINTED8_PALETTE_KEYS[9], ("base0F", "brown"));
+    assert_eq!(BASE24_TO_TINTED8_PALETTE_KEYS[0], ("base12", "red-bright"));
+    assert_eq!(
+        BASE24_TO_TINTED8_PALETTE_KEYS[5],
+        ("base17", "magenta-bright")
+    );
+}
+
+#[test]
+fn scheme_to_system_renders_tinted8_template() -> Result<(), TintedBuilderError> {
+    let scheme = Scheme::from_yaml(SCHEME_SILK_LIGHT)?.to_system(&SchemeSystem::Tinted8)?;
+    let template = Template::new(
+        "{{scheme.system}} #{{palette.blue.normal.hex}}".to_string(),
+        scheme,
+    );
+
+    assert_eq!(template.render()?, "tinted8 #39aac9");
+
+    Ok(())
+}
+
+#[test]
+fn tinted8_projects_to_base16() -> Result<(), TintedBuilderError> {
+    let tinted8: Tinted8Scheme = serde_yaml::from_str(SCHEME_TINTED8_GRUVBOX_DARK)?;
+    let base16 = tinted8.to_base16();
+
+    assert_eq!(base16.system, SchemeSystem::Base16);
+    assert_eq!(base16.slug, "gruvbox-dark");
+    assert_eq!(base16.palette.len(), 16);
+    assert_eq!(base16.palette["base00"].to_hex(), "282828");
+    assert_eq!(
+        base16.palette["base03"].to_hex(),
+        tinted8.palette.gray_normal.to_hex()
+    );
+    assert_eq!(base16.palette["base05"].to_hex(), "ebdbb2");
+    assert_eq!(base16.palette["base08"].to_hex(), "cc241d");
+    assert_eq!(
+        base16.palette["base09"].to_hex(),
+        tinted8.palette.orange_normal.to_hex()
+    );
+    assert_eq!(base16.palette["base0D"].to_hex(), "458588");
+    assert_eq!(
+        base16.palette["base0F"].to_hex(),
+        tinted8.palette.brown_normal.to_hex()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn tinted8_projects_to_base24() -> Result<(), TintedBuilderError> {
+    let tinted8: Tinted8Scheme = serde_yaml::from_str(SCHEME_TINTED8_GRUVBOX_DARK)?;
+    let base24 = tinted8.to_base24()?;
+
+    assert_eq!(base24.system, SchemeSystem::Base24);
+    assert_eq!(base24.palette.len(), 24);
+    assert_eq!(
+        base24.palette["base10"].to_hex(),
+        tinted8.ui.global.background.dark.to_hex()
+    );
+    assert_eq!(
+        base24.palette["base12"].to_hex(),
+        tinted8.palette.red_bright.to_hex()
+    );
+    assert_eq!(
+        base24.palette["base17"].to_hex(),
+        tinted8.palette.magenta_bright.to_hex()
+    );
+
+    Ok(())
+}
+
+const SCHEME_SILK_LIGHT: &str = r##"
+system: "base16"
+name: "Silk Light"
+author: "Gabriel Fontes (https://github.com/Misterio77)"
+variant: "light"
+palette:
+  base00: "#E9F1EF"
+  base01: "#CCD4D3"
+  base02: "#90B7B6"
+  base03: "#5C787B"
+  base04: "#4B5B5F"
+  base05: "#385156"
+  base06: "#0e3c46"
+  base07: "#D2FAFF"
+  base08: "#CF432E"
+  base09: "#D27F46"
+  base0A: "#CFAD25"
+  base0B: "#6CA38C"
+  base0C: "#329CA2"
+  base0D: "#39AAC9"
+  base0E: "#6E6582"
+  base0F: "#865369"
+"##;
+
+const SCHEME_BASE24_DARK: &str = r##"
+system: "base24"
+name: "Base24 Dark"
+author: "Test"
+variant: "dark"
+palette:
+  base00: "#000000"
+  base01: "#111111"
+  base02: "#222222"
+  base03: "#333333"
+  base04: "#444444"
+  base05: "#555555"
+  base06: "#666666"
+  base07: "#777777"
+  base08: "#888888"
+  base09: "#999999"
+  base0A: "#aaaaaa"
+  base0B: "#bbbbbb"
+  base0C: "#cccccc"
+  base0D: "#dddddd"
+  base0E: "#eeeeee"
+  base0F: "#ffffff"
+  base10: "#101010"
+  base11: "#111111"
+  base12: "#121212"
+  base13: "#131313"
+  base14: "#141414"
+  base15: "#151515"
+  base16: "#161616"
+  base17: "#171717"
+"##;
+
+const SCHEME_TINTED8_GRUVBOX_DARK: &str = r##"
+scheme:
+  system: "tinted8"
+  supports:
+    styling-spec: "0.2.0"
+  name: "Gruvbox Dark"
+  author: "morhetz (https://github.com/morhetz/gruvbox)"
+variant: "dark"
+palette:
+  black: "#282828"
+  white: "#ebdbb2"
+  red: "#cc241d"
+  yellow: "#d79921"
+  green: "#98971a"
+  cyan: "#689d6a"
+  blue: "#458588"
+  magenta: "#b16286"
+"##;
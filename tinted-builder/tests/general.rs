@@ -1,5 +1,8 @@
 use anyhow::Result;
-use tinted_builder::{Scheme, Template, TintedBuilderError};
+use tinted_builder::{
+    Color, GenerateOptions, Scheme, SchemeFamily, SchemePair, SchemeSystem, SchemeVariant,
+    Template, TintedBuilderError, WCAG_AA_CONTRAST_RATIO,
+};
 
 #[test]
 fn render_without_content() -> Result<(), TintedBuilderError> {
@@ -114,6 +117,167 @@ fn render_dec() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn render_hsl_and_oklch() -> Result<()> {
+    let template_source = "{{base0A-hsl-h}} {{base0A-hsl-s}} {{base0A-hsl-l}} \
+        {{base0A-oklch-l}} {{base0A-oklch-c}} {{base0A-oklch-h}}";
+    let scheme = Scheme::Base16(serde_yaml::from_str(SCHEME_SILK_LIGHT)?);
+    let template = Template::new(template_source.to_string(), scheme);
+
+    let output = template.render()?;
+
+    assert_eq!(output, "48.00 69.67 47.84 0.7564 0.1458 93.59");
+    Ok(())
+}
+
+#[test]
+fn render_terminal_formats() -> Result<()> {
+    let template_source =
+        "{{base0A-hex-upper}} {{base0A-hex16}} {{base0A-xterm}} {{base0A-ansi256}}";
+    let scheme = Scheme::Base16(serde_yaml::from_str(SCHEME_SILK_LIGHT)?);
+    let template = Template::new(template_source.to_string(), scheme);
+
+    let output = template.render()?;
+
+    assert_eq!(output, "CFAD25 cfcfadad2525 rgb:cfcf/adad/2525 178");
+    Ok(())
+}
+
+#[test]
+fn render_tinted8_terminal_formats() -> Result<()> {
+    let template_source = "{{palette.blue.normal.hex-upper}} {{palette.blue.normal.xterm}} \
+        {{palette.blue.normal.ansi256}} {{palette.blue.normal.hsl.h}} \
+        {{palette.blue.normal.oklch.l}}";
+    let scheme = Scheme::Tinted8(serde_yaml::from_str(SCHEME_TINTED_CATPPUCCIN_MOCHA)?);
+    let template = Template::new(template_source.to_string(), scheme);
+
+    let output = template.render()?;
+
+    assert_eq!(output, "89B4FA rgb:8989/b4b4/fafa 111 217.17 0.7664");
+    Ok(())
+}
+
+#[test]
+fn render_ansi16() -> Result<()> {
+    let template_source =
+        "{{base00-ansi16}} {{base0D-ansi16}} {{base07-ansi16}} {{base0F-ansi16}} \
+        {{ansi1-hex}} {{ansi15-hex}} {{ansi9-ansi16}}";
+    let scheme = Scheme::Base16(serde_yaml::from_str(SCHEME_SILK_LIGHT)?);
+    let template = Template::new(template_source.to_string(), scheme);
+
+    let output = template.render()?;
+
+    assert_eq!(output, "0 4 15 5 cf432e d2faff 1");
+    Ok(())
+}
+
+#[test]
+fn render_tinted8_ansi16() -> Result<()> {
+    let template_source = "{{palette.blue.normal.ansi16}} {{palette.blue.bright.ansi16}} \
+        {{palette.orange.normal.ansi16}} {{ui.global.background.normal.ansi16}} \
+        {{#ansi}}{{hex}},{{/ansi}} {{#ansi}}{{ansi16}},{{/ansi}}";
+    let scheme = Scheme::Tinted8(serde_yaml::from_str(SCHEME_TINTED_CATPPUCCIN_MOCHA)?);
+    let template = Template::new(template_source.to_string(), scheme);
+
+    let output = template.render()?;
+
+    assert_eq!(
+        output,
+        "4 12 3 0 1e1e2e,f38ba8,a6e3a1,f9e2af,89b4fa,cba6f7,94e2d5,cdd6f4,353554,f9c2d2,d3f1d0,\
+         fcf6e9,c4d9fc,ece0fb,c4efe8,ffffff, 0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,"
+    );
+    Ok(())
+}
+
+#[test]
+fn render_color_filters() -> Result<()> {
+    let template_source = "{{ base00 | mix base05 0.2 }} {{ base0D | darken 10% | hex-upper }} \
+        {{#darken 10}}#{{base00-hex}}{{/darken}} \
+        {{#alpha 0.5}}{{#darken 0.1}}{{base08-hex}}{{/darken}}{{/alpha}}";
+    let scheme = Scheme::Base16(serde_yaml::from_str(SCHEME_SILK_LIGHT)?);
+    let template = Template::new(template_source.to_string(), scheme);
+
+    let output = template.render()?;
+
+    assert_eq!(output, "c3cfce 2C89A3 #caddd8 a5352580");
+    Ok(())
+}
+
+#[test]
+fn render_tinted8_color_filters() -> Result<()> {
+    let template_source =
+        "{{ palette.blue.normal | alpha 50% | blend ui.global.background.normal | rgb }} \
+        {{ ff0000 | desaturate 0.5 | hsl }}";
+    let scheme = Scheme::Tinted8(serde_yaml::from_str(SCHEME_TINTED_CATPPUCCIN_MOCHA)?);
+    let template = Template::new(template_source.to_string(), scheme);
+
+    let output = template.render()?;
+
+    assert_eq!(output, "rgb(84, 105, 148) hsl(0.00, 49.80%, 50.00%)");
+    Ok(())
+}
+
+#[test]
+fn render_invalid_color_filter() -> Result<()> {
+    let scheme = Scheme::Base16(serde_yaml::from_str(SCHEME_SILK_LIGHT)?);
+    let template = Template::new("{{ base00 | blur 2 }}".to_string(), scheme);
+
+    let err = template.render().expect_err("expected an invalid filter");
+
+    assert_eq!(err.to_string(), "invalid template filter: blur 2");
+    Ok(())
+}
+
+#[test]
+fn render_unescaped_color_filters() -> Result<()> {
+    let template_source = "{{{ base00 | hex }}} {{& base0D | darken 10% | rgb }} \
+        {{! base00 | hex }}{{{base00-hex}}}";
+    let scheme = Scheme::Base16(serde_yaml::from_str(SCHEME_SILK_LIGHT)?);
+    let template = Template::new(template_source.to_string(), scheme);
+
+    let output = template.render()?;
+
+    assert_eq!(output, "e9f1ef rgb(44, 137, 163) e9f1ef");
+    Ok(())
+}
+
+#[test]
+fn render_color_filters_in_sections() -> Result<()> {
+    let scheme = Scheme::Tinted8(serde_yaml::from_str(SCHEME_TINTED_CATPPUCCIN_MOCHA)?);
+    let pipe_template = Template::new(
+        "{{#palette}}{{ blue.normal | hex }}{{/palette}}".to_string(),
+        scheme.clone(),
+    );
+    let section_template = Template::new(
+        "{{#darken 10%}}{{#palette}}{{/palette}}{{^ui}}{{#lighten 10%}}ff0000{{/lighten}}{{/ui}}{{/darken}}"
+            .to_string(),
+        scheme.clone(),
+    );
+    let top_level_template = Template::new(
+        "{{#palette}}{{blue.normal.hex}}{{/palette}} {{ palette.blue.normal | hex }}".to_string(),
+        scheme,
+    );
+
+    let pipe_err = pipe_template
+        .render()
+        .expect_err("expected a filter in a section");
+    let section_err = section_template
+        .render()
+        .expect_err("expected a filter in a section");
+    let output = top_level_template.render()?;
+
+    assert_eq!(
+        pipe_err.to_string(),
+        r#"template filter {{ blue.normal | hex }} cannot be used inside the "palette" section"#
+    );
+    assert_eq!(
+        section_err.to_string(),
+        r#"template filter {{#lighten 10%}} cannot be used inside the "ui" section"#
+    );
+    assert_eq!(output, "89b4fa 89b4fa");
+    Ok(())
+}
+
 #[test]
 fn render_is_dark_variant() -> Result<()> {
     let template_source =
@@ -127,6 +291,403 @@ fn render_is_dark_variant() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn generate_scheme_from_pixels_round_trips() -> Result<()> {
+    let pixels: Vec<[u8; 3]> = (0..1000_u16)
+        .map(|index| match index % 10 {
+            0..=6 => [245, 240, 230],
+            7 => [200, 60, 50],
+            8 => [60, 150, 90],
+            _ => [50, 90, 200],
+        })
+        .collect();
+
+    for system in [
+        SchemeSystem::Base16,
+        SchemeSystem::Base24,
+        SchemeSystem::Tinted8,
+    ] {
+        let options = GenerateOptions {
+            name: "Paper Wall".to_string(),
+            author: "Some author".to_string(),
+            system: system.clone(),
+            variant: None,
+        };
+
+        let scheme = Scheme::from_pixels(&pixels, &options)?;
+        let yaml = scheme.to_yaml_string()?;
+        let ansi16 = scheme.ansi16()?;
+
+        assert_eq!(scheme.get_scheme_system(), system);
+        assert_eq!(scheme.get_scheme_slug(), "paper-wall");
+        assert_eq!(scheme.get_scheme_variant(), SchemeVariant::Light);
+        assert!(ansi16[0].contrast_ratio(&ansi16[15]) >= WCAG_AA_CONTRAST_RATIO);
+        assert_eq!(Scheme::from_yaml(&yaml)?.to_yaml_string()?, yaml);
+    }
+
+    let err = Scheme::from_pixels(
+        &[],
+        &GenerateOptions {
+            name: "Empty".to_string(),
+            author: "Some author".to_string(),
+            system: SchemeSystem::Base16,
+            variant: Some(SchemeVariant::Dark),
+        },
+    )
+    .expect_err("expected no pixels to fail");
+
+    assert_eq!(
+        err.to_string(),
+        "unable to generate scheme: no pixels to generate a scheme from"
+    );
+    Ok(())
+}
+
+#[test]
+fn generate_scheme_from_seed_colors() -> Result<()> {
+    let seeds = ["#1e1e2e", "#89b4fa", "oklch(70% 0.15 150)"]
+        .map(|seed| Color::new(seed, None, None))
+        .into_iter()
+        .collect::<Result<Vec<Color>, TintedBuilderError>>()?;
+    let options = GenerateOptions {
+        name: "Seeded".to_string(),
+        author: "Some author".to_string(),
+        system: SchemeSystem::Base24,
+        variant: None,
+    };
+
+    let scheme = Scheme::from_seed_colors(&seeds, &options)?;
+    let Scheme::Base24(scheme) = scheme else {
+        panic!("expected a base24 scheme");
+    };
+    let hex = |key: &str| scheme.palette[key].to_hex();
+
+    assert_eq!(scheme.variant, SchemeVariant::Dark);
+    assert_eq!(hex("base00"), "1e1e2e");
+    assert_eq!(hex("base0D"), "89b4fa");
+    assert_eq!(hex("base0B"), "4cb86a");
+    assert_eq!(hex("base08"), "f28979");
+    assert_eq!(hex("base16"), "b0ceff");
+
+    let err = Scheme::from_seed_colors(
+        &[
+            Color::new("#ffffff", None, None)?,
+            Color::new("#ffee00", None, None)?,
+        ],
+        &options,
+    )
+    .expect_err("expected a low contrast seed to fail");
+
+    assert_eq!(
+        err.to_string(),
+        "unable to generate scheme: base0A (#ffee00) has a contrast ratio of 1.13:1 on base00 (#f8f8f8), below 3:1"
+    );
+    Ok(())
+}
+
+#[test]
+fn base16_to_yaml_string_is_canonical() -> Result<()> {
+    let yaml = Scheme::from_yaml(SCHEME_CRAZY)?.to_yaml_string()?;
+
+    assert_eq!(
+        yaml,
+        r#"system: base16
+name: Silk Light
+slug: silk-light
+author: <a href="https://github.com/Misterio77">Gabriel Fontes</a>
+variant: light
+palette:
+  base00: '#e9f1ef'
+  base01: '#ccd4d3'
+  base02: '#90b7b6'
+  base03: '#5c787b'
+  base04: '#4b5b5f'
+  base05: '#385156'
+  base06: '#0e3c46'
+  base07: '#d2faff'
+  base08: '#cf432e'
+  base09: '#d27f46'
+  base0A: '#cfad25'
+  base0B: '#6ca38c'
+  base0C: '#329ca2'
+  base0D: '#39aac9'
+  base0E: '#6e6582'
+  base0F: '#865369'
+"#
+    );
+    assert_eq!(Scheme::from_yaml(&yaml)?.to_yaml_string()?, yaml);
+
+    Ok(())
+}
+
+#[test]
+fn base16_contrast_report_checks_base05_on_base00() -> Result<()> {
+    let report = Scheme::from_yaml(SCHEME_SILK_LIGHT)?.contrast_report();
+
+    assert_eq!(report.checks.len(), 1);
+    assert_eq!(
+        report.to_string(),
+        "base05 (#385156) on base00 (#e9f1ef): 7.38:1, APCA Lc 79.7\n"
+    );
+    assert!(report.passes(WCAG_AA_CONTRAST_RATIO));
+    assert!(report.failing(7.5).len() == 1);
+
+    Ok(())
+}
+
+#[test]
+fn invert_variant_reflects_lightness() -> Result<()> {
+    let scheme = Scheme::from_yaml(SCHEME_SILK_LIGHT)?;
+    let Scheme::Base16(original) = &scheme else {
+        panic!("expected a base16 scheme");
+    };
+    let Scheme::Base16(inverted) = scheme.invert_variant()? else {
+        panic!("expected a base16 scheme");
+    };
+    let hex = |key: &str| inverted.palette[key].to_hex();
+    let contrast = |palette: &std::collections::HashMap<String, Color>| {
+        palette["base05"].contrast_ratio(&palette["base00"])
+    };
+
+    assert_eq!(inverted.variant, SchemeVariant::Dark);
+    assert_eq!(inverted.name, "Silk Dark");
+    assert_eq!(inverted.slug, "silk-dark");
+    assert_eq!(hex("base00"), "090f0d");
+    assert_eq!(hex("base05"), "87a2a8");
+    assert_eq!(hex("base08"), "ffae9e");
+    assert!(contrast(&inverted.palette) > WCAG_AA_CONTRAST_RATIO);
+    assert!(
+        (original.palette["base08"].to_oklch().2 - inverted.palette["base08"].to_oklch().2).abs()
+            < 2.0
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invert_variant_keeps_accents_recognisable() -> Result<()> {
+    let scheme = Scheme::from_yaml(SCHEME_TINTED_CATPPUCCIN_MOCHA)?;
+    let Scheme::Tinted8(original) = &scheme else {
+        panic!("expected a tinted8 scheme");
+    };
+    let Scheme::Tinted8(inverted) = scheme.invert_variant()? else {
+        panic!("expected a tinted8 scheme");
+    };
+
+    for (name, original, inverted) in [
+        (
+            "red",
+            &original.palette.red_normal,
+            &inverted.palette.red_normal,
+        ),
+        (
+            "yellow",
+            &original.palette.yellow_normal,
+            &inverted.palette.yellow_normal,
+        ),
+        (
+            "green",
+            &original.palette.green_normal,
+            &inverted.palette.green_normal,
+        ),
+        (
+            "cyan",
+            &original.palette.cyan_normal,
+            &inverted.palette.cyan_normal,
+        ),
+        (
+            "blue",
+            &original.palette.blue_normal,
+            &inverted.palette.blue_normal,
+        ),
+    ] {
+        let (original_l, original_c, _) = original.to_oklch();
+        let (l, c, _) = inverted.to_oklch();
+
+        assert!(l >= 0.45, "{name} is too dark: {inverted}");
+        assert!(l < original_l, "{name} is not darker: {inverted}");
+        assert!(c >= original_c * 0.8, "{name} lost its chroma: {inverted}");
+    }
+
+    assert_eq!(inverted.palette.yellow_normal.to_hex(), "a28c5c");
+    assert_eq!(inverted.palette.green_normal.to_hex(), "589254");
+    assert_eq!(inverted.palette.cyan_normal.to_hex(), "439186");
+
+    Ok(())
+}
+
+#[test]
+fn scheme_family_groups_variants_across_systems() -> Result<()> {
+    let silk_light = Scheme::from_yaml(SCHEME_SILK_LIGHT)?;
+    let silk_dark = silk_light
+        .invert_variant()?
+        .to_system(&SchemeSystem::Base24)?;
+    let catppuccin = Scheme::from_yaml(SCHEME_TINTED_CATPPUCCIN_MOCHA)?;
+
+    let families = SchemeFamily::from_schemes(&[silk_light, catppuccin, silk_dark]);
+    let summary: Vec<(String, Vec<String>)> = families
+        .iter()
+        .map(|family| {
+            (
+                family.name.clone(),
+                family
+                    .schemes
+                    .iter()
+                    .map(|scheme| {
+                        format!(
+                            "{} ({})",
+                            scheme.get_scheme_name(),
+                            scheme.get_scheme_system()
+                        )
+                    })
+                    .collect(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "Catppuccin Mocha".to_string(),
+                vec!["Catppuccin Mocha (tinted8)".to_string()]
+            ),
+            (
+                "Silk".to_string(),
+                vec![
+                    "Silk Dark (base24)".to_string(),
+                    "Silk Light (base16)".to_string()
+                ]
+            ),
+        ]
+    );
+    assert_eq!(families[1].slug, "silk");
+    assert_eq!(families[1].dark_schemes().len(), 1);
+    assert_eq!(families[1].light_schemes().len(), 1);
+
+    let value = serde_yaml::to_value(&families[1])?;
+
+    assert_eq!(
+        value["light-schemes"][0]["slug"].as_str(),
+        Some("silk-light")
+    );
+    assert_eq!(
+        value["schemes"][0]["palette"]["base12"].as_str(),
+        Some("#ffae9e")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn scheme_pair_from_declared_and_inferred_pairs() -> Result<()> {
+    let silk_light = Scheme::from_yaml(SCHEME_SILK_LIGHT)?;
+    let silk_dark = silk_light.invert_variant()?;
+    let silk_light_base24 = silk_light.to_system(&SchemeSystem::Base24)?;
+    let catppuccin = Scheme::from_yaml(SCHEME_TINTED_CATPPUCCIN_MOCHA)?;
+    let catppuccin_light = catppuccin.invert_variant()?;
+
+    let pairs = SchemePair::from_schemes(&[
+        silk_light,
+        catppuccin_light,
+        silk_light_base24,
+        silk_dark,
+        catppuccin,
+    ]);
+    let summary: Vec<(String, String, String, String)> = pairs
+        .iter()
+        .map(|pair| {
+            (
+                pair.slug.clone(),
+                pair.get_scheme_system().to_string(),
+                pair.dark.get_scheme_name(),
+                pair.light.get_scheme_name(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "catppuccin-mocha".to_string(),
+                "tinted8".to_string(),
+                "Catppuccin Mocha".to_string(),
+                "Catppuccin Mocha Light".to_string()
+            ),
+            (
+                "silk".to_string(),
+                "base16".to_string(),
+                "Silk Dark".to_string(),
+                "Silk Light".to_string()
+            ),
+        ]
+    );
+    assert_eq!(
+        pairs[1].dark.get_scheme_pair().as_deref(),
+        Some("silk-light")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn scheme_pair_declared_with_different_names() -> Result<()> {
+    let day = Scheme::from_yaml(
+        &SCHEME_SILK_LIGHT
+            .replace(
+                "name: \"Silk Light\"",
+                "name: \"Day Owl\"\npair: \"Night Owl\"",
+            )
+            .replace("slug: \"siłk light\"", "slug: \"day-owl\""),
+    )?;
+    let night = Scheme::from_yaml(
+        &day.invert_variant()?
+            .to_yaml_string()?
+            .replace("Day Owl Dark", "Night Owl")
+            .replace("day-owl-dark", "night-owl")
+            .replace("pair: day-owl\n", ""),
+    )?;
+
+    assert_eq!(day.get_scheme_pair().as_deref(), Some("night-owl"));
+    assert_eq!(night.get_scheme_pair(), None);
+
+    let pairs = SchemePair::from_schemes(&[day, night]);
+
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].name, "Night Owl");
+    assert_eq!(pairs[0].light.get_scheme_name(), "Day Owl");
+
+    Ok(())
+}
+
+#[test]
+fn render_scheme_pair() -> Result<()> {
+    let silk_light = Scheme::from_yaml(SCHEME_SILK_LIGHT)?;
+    let silk_pair = SchemePair::from_schemes(&[silk_light.invert_variant()?, silk_light]);
+    let catppuccin = Scheme::from_yaml(SCHEME_TINTED_CATPPUCCIN_MOCHA)?;
+    let catppuccin_pair = SchemePair::from_schemes(&[catppuccin.invert_variant()?, catppuccin]);
+
+    let silk_output = Template::new_pair(
+        "{{pair.name}} ({{pair.system}}): #{{dark.base00-hex}} #{{light.base00-hex}} {{ light.base00 | mix dark.base00 0.5 }}".to_string(),
+        silk_pair[0].clone(),
+    )
+    .render()?;
+    let catppuccin_output = Template::new_pair(
+        "{{pair.slug-underscored}}: #{{dark.palette.red.normal.hex}} #{{light.palette.red.normal.hex}} #{{light.ui.global.background.normal.hex}}".to_string(),
+        catppuccin_pair[0].clone(),
+    )
+    .render()?;
+
+    assert_eq!(silk_output, "Silk (base16): #090f0d #e9f1ef 6e7573");
+    assert_eq!(
+        catppuccin_output,
+        "catppuccin_mocha: #f38ba8 #a64664 #e8e9ff"
+    );
+
+    Ok(())
+}
+
 const SCHEME_SILK_LIGHT: &str = r##"
 system: "base16"
 name: "Silk Light"
@@ -1,4 +1,8 @@
-use tinted_builder::{tinted8::Scheme as Tinted8Scheme, Scheme, TintedBuilderError};
+use tinted_builder::{
+    tinted8::{Scheme as Tinted8Scheme, SyntaxKey},
+    Color, ColorDerivation, ColorName, ColorVariant, Derivation, Scheme, TintedBuilderError,
+    CVD_MIN_DELTA_E,
+};
 
 #[test]
 fn deserialize_minimal_scheme() -> Result<(), TintedBuilderError> {
@@ -36,6 +40,100 @@ fn deserialize_ui_overrides() -> Result<(), TintedBuilderError> {
     Ok(())
 }
 
+#[test]
+fn deserialize_non_hex_color_inputs() -> Result<(), TintedBuilderError> {
+    let scheme: Tinted8Scheme = serde_yaml::from_str(
+        r##"
+scheme:
+  system: "tinted8"
+  author: "Test Author"
+  name: "Non Hex"
+  supports:
+    styling-spec: "0.2.0"
+variant: "dark"
+palette:
+  black:   "rgb(0 0 0)"
+  red:     "hsl(0 100% 50%)"
+  green:   "oklch(86.64% 0.2948 142.5)"
+  yellow:  "yellow"
+  blue:    "#0000ff"
+  magenta: "rgba(255, 0, 255, 1)"
+  cyan:    "Cyan"
+  white:   "white"
+ui:
+  selection.background: "rgb(51 70 124 / 50%)"
+syntax:
+  comment: "hsl(0, 0%, 53.3%)"
+"##,
+    )?;
+
+    assert_eq!(scheme.palette.black_normal.to_hex(), "000000");
+    assert_eq!(scheme.palette.red_normal.to_hex(), "ff0000");
+    assert_eq!(scheme.palette.green_normal.to_hex(), "00ff00");
+    assert_eq!(scheme.palette.yellow_normal.to_hex(), "ffff00");
+    assert_eq!(scheme.palette.magenta_normal.to_hex(), "ff00ff");
+    assert_eq!(scheme.palette.cyan_normal.to_hex(), "00ffff");
+    assert_eq!(scheme.palette.white_normal.to_hex(), "ffffff");
+    assert_eq!(scheme.ui.selection.background.to_string(), "#33467c80");
+    assert_eq!(
+        scheme.syntax.get_color(&SyntaxKey::Comment).to_hex(),
+        "888888"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_color_expressions() -> Result<(), TintedBuilderError> {
+    let scheme: Tinted8Scheme = serde_yaml::from_str(
+        r##"
+scheme:
+  system: "tinted8"
+  author: "Test Author"
+  name: "Expressions"
+  supports:
+    styling-spec: "0.2.0"
+variant: "dark"
+palette:
+  black:   "#1a1b26"
+  red:     "#f7768e"
+  green:   "#9ece6a"
+  yellow:  "#e0af68"
+  blue:    "#7aa2f7"
+  magenta: "#bb9af7"
+  cyan:    "#7dcfff"
+  white:   "#c0caf5"
+ui:
+  selection.background: "blue.normal | mix(black.normal, 0.7) | lighten(0.05)"
+  cursor.normal.background: "red | alpha(0.5)"
+syntax:
+  comment: "gray.dim"
+  string: "green_bright | desaturate(0.2)"
+"##,
+    )?;
+
+    assert_eq!(scheme.ui.selection.background.to_hex(), "3e4b6e");
+    assert_eq!(scheme.ui.cursor.normal.background.to_string(), "#f7768e80");
+    assert_eq!(
+        scheme.syntax.get_color(&SyntaxKey::Comment).to_hex(),
+        scheme.palette.gray_dim.to_hex()
+    );
+    assert_eq!(
+        scheme.syntax.get_color(&SyntaxKey::String).to_hex(),
+        "bbcfa6"
+    );
+
+    let err = serde_yaml::from_str::<Tinted8Scheme>(
+        &SCHEME_WITH_UI.replace("\"#111111\"", "\"blue.normal | lighten(x)\""),
+    )
+    .expect_err("expected an invalid color expression");
+    assert!(err
+        .to_string()
+        .contains("invalid color expression: blue.normal | lighten(x)"));
+
+    Ok(())
+}
+
 #[test]
 fn deserialize_ui_nested_overrides() -> Result<(), TintedBuilderError> {
     let scheme: Tinted8Scheme = serde_yaml::from_str(
@@ -209,6 +307,332 @@ fn palette_has_all_variants() -> Result<(), TintedBuilderError> {
     Ok(())
 }
 
+#[test]
+fn palette_keeps_dim_and_bright_overrides() -> Result<(), TintedBuilderError> {
+    let scheme: Tinted8Scheme = serde_yaml::from_str(&SCHEME_MINIMAL.replace(
+        "  white:   \"#e6e1cf\"\n",
+        r##"  white:   "#e6e1cf"
+  orange-dim: "#123456"
+  gray-dim: "#654321"
+  gray-bright: "#abcdef"
+  brown-dim: "#112233"
+  brown-bright: "#fedcba"
+"##,
+    ))?;
+
+    assert_eq!(scheme.palette.orange_dim.to_hex(), "123456");
+    assert_eq!(scheme.palette.gray_dim.to_hex(), "654321");
+    assert_eq!(scheme.palette.gray_bright.to_hex(), "abcdef");
+    assert_eq!(scheme.palette.brown_dim.to_hex(), "112233");
+    assert_eq!(scheme.palette.brown_bright.to_hex(), "fedcba");
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_oklch_derivation() -> Result<(), TintedBuilderError> {
+    let hsl: Tinted8Scheme = serde_yaml::from_str(SCHEME_MINIMAL)?;
+    let oklch: Tinted8Scheme =
+        serde_yaml::from_str(&format!("{SCHEME_MINIMAL}derivation: \"oklch\"\n"))?;
+
+    assert_eq!(hsl.derivation, Derivation::Hsl);
+    assert_eq!(oklch.derivation, Derivation::Oklch);
+    assert_eq!(oklch.palette.blue_normal.to_hex(), "59c2ff");
+    assert_ne!(
+        oklch.palette.blue_bright.to_hex(),
+        hsl.palette.blue_bright.to_hex()
+    );
+    assert_ne!(
+        oklch.palette.yellow_dim.to_hex(),
+        hsl.palette.yellow_dim.to_hex()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn with_derivation_rederives_palette() -> Result<(), TintedBuilderError> {
+    let hsl: Tinted8Scheme = serde_yaml::from_str(SCHEME_MINIMAL)?;
+    let oklch: Tinted8Scheme =
+        serde_yaml::from_str(&format!("{SCHEME_MINIMAL}derivation: \"oklch\"\n"))?;
+    let converted = hsl.with_derivation(Derivation::Oklch)?;
+    let yaml = converted.to_yaml_string()?;
+    let round_tripped: Tinted8Scheme = serde_yaml::from_str(&yaml)?;
+
+    assert_eq!(converted.derivation, Derivation::Oklch);
+    assert_eq!(converted.scheme.name, hsl.scheme.name);
+    assert_eq!(converted.palette.to_string(), oklch.palette.to_string());
+    assert!(yaml.contains("derivation: oklch"));
+    assert!(!yaml.contains("blue-bright"));
+    assert_eq!(
+        round_tripped.palette.to_string(),
+        converted.palette.to_string()
+    );
+    assert_eq!(
+        converted
+            .with_derivation(Derivation::Hsl)?
+            .palette
+            .to_string(),
+        hsl.palette.to_string()
+    );
+
+    Ok(())
+}
+
+/// Derives orange as the midpoint of red and yellow, keeping the other Tinted8 rules.
+struct MixedOrange;
+
+impl ColorDerivation for MixedOrange {
+    fn derive_color(
+        &self,
+        target_color_name: &ColorName,
+        normal_colors: &[Color],
+    ) -> Result<Color, TintedBuilderError> {
+        let find = |name: &str| {
+            normal_colors
+                .iter()
+                .find(|color| color.name.to_string() == name)
+                .ok_or_else(|| TintedBuilderError::SchemeMissingProperty(name.to_string()))
+        };
+
+        match target_color_name {
+            ColorName::Orange => {
+                let (red, yellow) = (find("red")?, find("yellow")?);
+                let mix = |a: u8, b: u8| (u16::from(a) + u16::from(b)) / 2;
+
+                Color::new(
+                    &format!(
+                        "{:02x}{:02x}{:02x}",
+                        mix(red.rgb.0, yellow.rgb.0),
+                        mix(red.rgb.1, yellow.rgb.1),
+                        mix(red.rgb.2, yellow.rgb.2)
+                    ),
+                    Some(ColorName::Orange),
+                    Some(ColorVariant::Normal),
+                )
+            }
+            _ => Derivation::Hsl.derive_color(target_color_name, normal_colors),
+        }
+    }
+}
+
+#[test]
+fn from_yaml_with_custom_color_derivation() -> Result<(), TintedBuilderError> {
+    let default: Tinted8Scheme = serde_yaml::from_str(SCHEME_MINIMAL)?;
+    let mixed = Tinted8Scheme::from_yaml_with(SCHEME_MINIMAL, &MixedOrange)?;
+
+    // Midpoint of #f07178 and #ffb454
+    assert_eq!(mixed.palette.orange_normal.to_hex(), "f79266");
+    assert_ne!(
+        mixed.palette.orange_bright.to_hex(),
+        default.palette.orange_bright.to_hex()
+    );
+    assert_eq!(
+        mixed.palette.brown_normal.to_hex(),
+        default.palette.brown_normal.to_hex()
+    );
+    assert_eq!(
+        mixed.palette.blue_bright.to_hex(),
+        default.palette.blue_bright.to_hex()
+    );
+    assert_eq!(mixed.derivation, Derivation::Hsl);
+    assert!(mixed.to_yaml_string()?.contains("orange: '#f79266'"));
+
+    Ok(())
+}
+
+#[test]
+fn to_yaml_string_round_trips_full_scheme() -> Result<(), TintedBuilderError> {
+    let ts: Tinted8Scheme = serde_yaml::from_str(SCHEME_FULL)?;
+    let yaml = ts.to_yaml_string()?;
+    let round_tripped: Tinted8Scheme = serde_yaml::from_str(&yaml)?;
+
+    assert_eq!(round_tripped.scheme.name, ts.scheme.name);
+    assert_eq!(round_tripped.scheme.slug, ts.scheme.slug);
+    assert_eq!(round_tripped.scheme.author, ts.scheme.author);
+    assert_eq!(round_tripped.scheme.theme_author, ts.scheme.theme_author);
+    assert_eq!(round_tripped.scheme.description, ts.scheme.description);
+    assert_eq!(round_tripped.palette.to_string(), ts.palette.to_string());
+    assert_eq!(round_tripped.ui.to_string(), ts.ui.to_string());
+    assert_eq!(round_tripped.syntax.to_string(), ts.syntax.to_string());
+    assert_eq!(round_tripped.to_yaml_string()?, yaml);
+
+    Ok(())
+}
+
+#[test]
+fn to_yaml_string_writes_only_overrides() -> Result<(), TintedBuilderError> {
+    let ts: Tinted8Scheme = serde_yaml::from_str(SCHEME_FULL)?;
+    let yaml = ts.to_yaml_string()?;
+
+    // Derived palette variants and values inherited from overridden parents are omitted
+    assert!(!yaml.contains("black-dim"));
+    assert!(!yaml.contains("gutter.background"));
+    // Overrides matching the derived value are omitted
+    assert!(!yaml.contains("entity.name:"));
+    assert!(yaml.contains("global.background.normal: '#ff0000'"));
+    assert!(yaml.contains("selection.background: '#33467c'"));
+    assert!(yaml.contains("entity.other.attribute-name: '#e0af68'"));
+    assert!(yaml.contains("theme-author: Original Theme Author"));
+
+    Ok(())
+}
+
+#[test]
+fn scheme_enum_to_yaml_string_is_canonical() -> Result<(), TintedBuilderError> {
+    let yaml = Scheme::from_yaml(SCHEME_MINIMAL)?.to_yaml_string()?;
+
+    assert_eq!(
+        yaml,
+        r"scheme:
+  system: tinted8
+  supports:
+    styling-spec: 0.2.0
+  name: Test Scheme
+  slug: test-scheme
+  author: Test Author <test@example.com>
+variant: dark
+palette:
+  black: '#131721'
+  blue: '#59c2ff'
+  cyan: '#95e6cb'
+  green: '#b8cc52'
+  magenta: '#d2a6ff'
+  red: '#f07178'
+  white: '#e6e1cf'
+  yellow: '#ffb454'
+"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn contrast_report_checks_foreground_and_syntax_on_background() -> Result<(), TintedBuilderError> {
+    let scheme = Scheme::from_yaml(SCHEME_MINIMAL)?;
+    let report = scheme.contrast_report();
+    let Scheme::Tinted8(tinted8) = scheme else {
+        panic!("expected a tinted8 scheme");
+    };
+
+    assert_eq!(report.checks.len(), 1 + SyntaxKey::variants().len());
+    assert!(report
+        .checks
+        .iter()
+        .all(|check| check.background_key == "ui.global.background.normal"));
+    assert_eq!(
+        report.checks[0].to_string(),
+        "ui.global.foreground.normal (#e6e1cf) on ui.global.background.normal (#131721): 13.68:1, APCA Lc -87.4"
+    );
+
+    let comment = report
+        .checks
+        .iter()
+        .find(|check| check.foreground_key == "syntax.comment")
+        .expect("missing syntax.comment check");
+
+    assert_eq!(
+        comment.foreground.to_hex(),
+        tinted8.syntax.comment.default.to_hex()
+    );
+    assert!(report
+        .lowest()
+        .is_some_and(|lowest| lowest.contrast_ratio <= comment.contrast_ratio));
+
+    Ok(())
+}
+
+#[test]
+fn cvd_report_checks_palette_and_status_pairs() -> Result<(), TintedBuilderError> {
+    let report = Scheme::from_yaml(SCHEME_MINIMAL)?.cvd_report(CVD_MIN_DELTA_E);
+    let conflicts: Vec<String> = report.conflicts.iter().map(ToString::to_string).collect();
+
+    assert!(conflicts.contains(
+        &"deuteranopia: palette.yellow.normal (#ffb454) vs palette.green.normal (#b8cc52): ΔE 26.5 → 2.2"
+            .to_string()
+    ));
+    assert!(conflicts.contains(
+        &"tritanopia: ui.status.error (#f07178) vs ui.status.info (#ff9754): ΔE 22.5 → 6.9"
+            .to_string()
+    ));
+    assert!(report.conflicts.iter().all(|conflict| {
+        conflict.delta_e >= CVD_MIN_DELTA_E && conflict.simulated_delta_e < CVD_MIN_DELTA_E
+    }));
+    assert!(!conflicts
+        .iter()
+        .any(|conflict| conflict.contains("palette.red.normal (#f07178) vs palette.green.normal")));
+
+    Ok(())
+}
+
+#[test]
+fn invert_variant_swaps_black_and_white() -> Result<(), TintedBuilderError> {
+    let scheme = Scheme::from_yaml(SCHEME_MINIMAL)?;
+    let Scheme::Tinted8(inverted) = scheme.invert_variant()? else {
+        panic!("expected a tinted8 scheme");
+    };
+
+    assert_eq!(inverted.variant.to_string(), "light");
+    assert_eq!(inverted.scheme.name, "Test Scheme Light");
+    assert_eq!(inverted.scheme.slug, "test-scheme-light");
+    assert_eq!(inverted.palette.white_normal.to_hex(), "e5ebfa");
+    assert_eq!(inverted.palette.black_normal.to_hex(), "221e11");
+    assert_eq!(inverted.palette.red_normal.to_hex(), "a62d3b");
+    assert_eq!(
+        inverted.ui.global.background.normal.to_hex(),
+        inverted.palette.white_normal.to_hex()
+    );
+    assert!(
+        inverted
+            .palette
+            .white_bright
+            .contrast_ratio(&inverted.palette.black_normal)
+            > inverted
+                .palette
+                .white_normal
+                .contrast_ratio(&inverted.palette.black_normal)
+    );
+
+    let Scheme::Tinted8(restored) = Scheme::Tinted8(inverted).invert_variant()? else {
+        panic!("expected a tinted8 scheme");
+    };
+
+    assert_eq!(restored.scheme.name, "Test Scheme Dark");
+    assert_eq!(restored.palette.black_normal.to_hex(), "090d16");
+
+    Ok(())
+}
+
+#[test]
+fn invert_variant_resolves_color_expressions() -> Result<(), TintedBuilderError> {
+    let scheme = Scheme::from_yaml(SCHEME_WITH_EXPRESSIONS)?;
+    let Scheme::Tinted8(inverted) = scheme.invert_variant()? else {
+        panic!("expected a tinted8 scheme");
+    };
+    let background = inverted.ui.global.background.normal.to_oklch().0;
+
+    assert_eq!(inverted.variant.to_string(), "light");
+    assert!(inverted.ui.selection.background.to_oklch().0 < background);
+    assert!(inverted.syntax.get_color(&SyntaxKey::String).to_oklch().0 < background);
+
+    Ok(())
+}
+
+#[test]
+fn invert_variant_updates_style() -> Result<(), TintedBuilderError> {
+    let scheme: Tinted8Scheme = serde_yaml::from_str(SCHEME_WITH_FAMILY_STYLE)?;
+    let Scheme::Tinted8(inverted) = Scheme::Tinted8(Box::new(scheme)).invert_variant()? else {
+        panic!("expected a tinted8 scheme");
+    };
+
+    assert_eq!(inverted.variant.to_string(), "dark");
+    assert_eq!(inverted.scheme.name, "Ayu Mirage Dark");
+    assert_eq!(inverted.scheme.style, Some("Mirage Dark".to_string()));
+    assert_eq!(inverted.scheme.family, Some("Ayu".to_string()));
+
+    Ok(())
+}
+
 const SCHEME_MINIMAL: &str = r##"
 scheme:
   name: "Test Scheme"
@@ -274,6 +698,30 @@ ui:
   global.background.dark: "#000000"
 "##;
 
+const SCHEME_WITH_EXPRESSIONS: &str = r##"
+scheme:
+  system: "tinted8"
+  author: "Test Author"
+  name: "Expressions"
+  supports:
+    styling-spec: "0.2.0"
+variant: "dark"
+palette:
+  black:   "#1a1b26"
+  red:     "#f7768e"
+  green:   "#9ece6a"
+  yellow:  "#e0af68"
+  blue:    "#7aa2f7"
+  magenta: "#bb9af7"
+  cyan:    "#7dcfff"
+  white:   "#c0caf5"
+ui:
+  selection.background: "blue.normal | mix(black.normal, 0.7) | lighten(0.05)"
+syntax:
+  keyword: "magenta_bright"
+  string: "green_bright | desaturate(0.2)"
+"##;
+
 const SCHEME_WITH_SYNTAX: &str = r##"
 scheme:
   system: "tinted8"